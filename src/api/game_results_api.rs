@@ -1,25 +1,50 @@
+use crate::error::{CfbBettingError, Result};
+use crate::utils::monitoring::record_fetch;
+use chrono::{DateTime, Utc};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+use tracing::{info, instrument, warn};
 
 const BASE_URL: &str = "https://api.collegefootballdata.com";
 const FIRST_WEEK: u8 = 34;
 
+/// Service name used in errors
+const CFBD: &str = "College Football Data API";
+
+/// Pause between the weekly requests of a range, to stay under CFBD's rate limit
+const RANGE_REQUEST_DELAY: Duration = Duration::from_millis(500);
+
+/// A CFBD game
+/// Only the id and team names are required. Everything else falls back to a default
+/// (a missing `completed` means the game isn't graded), so a field CFBD drops or renames
+/// doesn't fail the whole response.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[allow(non_snake_case)]
 #[serde(rename_all = "camelCase")]
 pub struct GameResult {
     pub id: i32,
+    #[serde(default)]
     pub season: i32,
+    #[serde(default)]
     pub week: i32,
+    #[serde(default)]
     pub season_type: SeasonType,
+    #[serde(default)]
     pub start_date: String,
+    #[serde(default)]
     pub start_time_TBD: bool,
+    #[serde(default)]
     pub completed: bool,
+    #[serde(default)]
     pub neutral_site: bool,
+    #[serde(default)]
     pub conference_game: bool,
     pub attendance: Option<i32>,
     pub venue_id: Option<i32>,
     pub venue: Option<String>,
+    #[serde(default)]
     pub home_id: i32,
     pub home_team: String,
     pub home_conference: Option<String>,
@@ -29,6 +54,7 @@ pub struct GameResult {
     pub home_postgame_win_probability: Option<f64>,
     pub home_pregame_elo: Option<i32>,
     pub home_postgame_elo: Option<i32>,
+    #[serde(default)]
     pub away_id: i32,
     pub away_team: String,
     pub away_conference: Option<String>,
@@ -43,15 +69,28 @@ pub struct GameResult {
     pub notes: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+impl GameResult {
+    /// Kickoff time, or `None` if `start_date` isn't a readable timestamp
+    pub fn kickoff(&self) -> Option<DateTime<Utc>> {
+        DateTime::parse_from_rfc3339(&self.start_date)
+            .ok()
+            .map(|start| start.with_timezone(&Utc))
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 pub enum SeasonType {
+    #[default]
     Regular,
     Postseason,
     Both,
     Allstar,
     SpringRegular,
     SpringPostseason,
+    /// A season type added since this was written
+    #[serde(other)]
+    Other,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -61,6 +100,9 @@ pub enum Classification {
     Fcs,
     Ii,
     Iii,
+    /// A classification added since this was written
+    #[serde(other)]
+    Other,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -91,17 +133,23 @@ pub struct GameResultsApiClient {
 impl GameResultsApiClient {
     pub fn new(api_key: String) -> Self {
         Self {
-            client: Client::new(),
+            client: crate::http_client_builder().build().unwrap(),
             api_key,
         }
     }
 
-    pub async fn fetch_cfb_game_results(
-        &self,
-        year: u32,
-        week: u8,
-    ) -> Result<Vec<GameResult>, reqwest::Error> {
-        let week = week - FIRST_WEEK;
+    /// Fetch results for an ISO `week`
+    /// Weeks before the season starts are an `OutOfSeason` error rather than a request.
+    #[instrument(skip(self), fields(source = "cfbd"))]
+    pub async fn fetch_cfb_game_results(&self, year: u32, week: u8) -> Result<Vec<GameResult>> {
+        let week = season_week(week)?;
+        let result = self.try_fetch_cfb_game_results(year, week).await;
+        record_fetch("cfbd", &result);
+        result
+    }
+
+    async fn try_fetch_cfb_game_results(&self, year: u32, week: u8) -> Result<Vec<GameResult>> {
+        let start = Instant::now();
         let url = format!("{}/games?year={}&week={}", BASE_URL, year, week);
 
         let response = self
@@ -109,16 +157,44 @@ impl GameResultsApiClient {
             .get(&url)
             .header("Authorization", format!("Bearer {}", self.api_key))
             .send()
-            .await?;
+            .await
+            .map_err(|e| CfbBettingError::api(CFBD, e))?;
 
-        let results: Vec<GameResult> = response.json().await?;
+        let games: Vec<serde_json::Value> = response
+            .json()
+            .await
+            .map_err(|e| CfbBettingError::parse("CFB game results", e))?;
+        let results = parse_game_results(games)?;
+        info!(
+            games = results.len(),
+            elapsed_ms = start.elapsed().as_millis() as u64,
+            "Fetched CFB game results"
+        );
         Ok(results)
     }
 
-    pub async fn fetch_cbb_game_results(
+    /// Fetch results for every week from `start_week` through `end_week`
+    /// Weeks are ISO weeks, like `fetch_cfb_game_results`; weeks before the season starts
+    /// are skipped. Games returned by more than one week are only included once.
+    #[instrument(skip(self), fields(source = "cfbd"))]
+    pub async fn fetch_cfb_game_results_range(
         &self,
-        day: &str,
-    ) -> Result<Vec<CbbGameResult>, reqwest::Error> {
+        year: u32,
+        start_week: u8,
+        end_week: u8,
+    ) -> Result<Vec<GameResult>> {
+        let mut results = Vec::new();
+        let first_week = start_week.max(FIRST_WEEK);
+        for week in first_week..=end_week {
+            if week > first_week {
+                tokio::time::sleep(RANGE_REQUEST_DELAY).await;
+            }
+            results.extend(self.fetch_cfb_game_results(year, week).await?);
+        }
+        Ok(dedupe_by_game_id(results))
+    }
+
+    pub async fn fetch_cbb_game_results(&self, day: &str) -> Result<Vec<CbbGameResult>> {
         let url = format!("{}/scoreboard?day={}", BASE_URL, day);
 
         let response = self
@@ -126,14 +202,19 @@ impl GameResultsApiClient {
             .get(&url)
             .header("Authorization", format!("Bearer {}", self.api_key))
             .send()
-            .await?;
+            .await
+            .map_err(|e| CfbBettingError::api(CFBD, e))?;
 
-        let results: Vec<CbbGameResult> = response.json().await?;
+        let results: Vec<CbbGameResult> = response
+            .json()
+            .await
+            .map_err(|e| CfbBettingError::parse("CBB game results", e))?;
         Ok(results)
     }
 
     /// Check API usage/rate limits for College Football Data API
-    pub async fn check_usage(&self) -> Result<(), reqwest::Error> {
+    #[instrument(skip(self), fields(source = "cfbd"))]
+    pub async fn check_usage(&self) -> Result<()> {
         // Make a lightweight request to check headers
         let url = format!("{}/info", BASE_URL);
 
@@ -142,24 +223,154 @@ impl GameResultsApiClient {
             .get(&url)
             .header("Authorization", format!("Bearer {}", self.api_key))
             .send()
-            .await?;
+            .await
+            .map_err(|e| CfbBettingError::api(CFBD, e))?;
 
-        let result: InfoResult = response.json().await?;
-        println!(
-            "CFB Data API requests remaining: {}",
-            result.remaining_calls
+        let result: InfoResult = response
+            .json()
+            .await
+            .map_err(|e| CfbBettingError::parse("CFBD usage info", e))?;
+        info!(
+            remaining = result.remaining_calls,
+            "CFB Data API requests remaining"
         );
 
         Ok(())
     }
 }
 
+/// CFBD's week number for an ISO week
+fn season_week(iso_week: u8) -> Result<u8> {
+    iso_week
+        .checked_sub(FIRST_WEEK)
+        .ok_or_else(|| CfbBettingError::OutOfSeason(format!("CFB (ISO week {})", iso_week)))
+}
+
+/// Parse each game on its own, so one malformed game is skipped (with a warning) instead
+/// of failing the rest
+/// It's still an error if there were games and none parsed, since that means the format
+/// changed under every game.
+fn parse_game_results(games: Vec<serde_json::Value>) -> Result<Vec<GameResult>> {
+    let total = games.len();
+    let mut results = Vec::with_capacity(total);
+    let mut first_error = None;
+
+    for game in games {
+        let id = game.get("id").cloned();
+        match serde_json::from_value::<GameResult>(game) {
+            Ok(result) => results.push(result),
+            Err(e) => {
+                warn!(game_id = ?id, error = %e, "Skipping unreadable CFB game result");
+                first_error.get_or_insert(e);
+            }
+        }
+    }
+
+    match first_error {
+        Some(e) if results.is_empty() => Err(CfbBettingError::parse("CFB game results", e)),
+        _ => {
+            if results.len() < total {
+                warn!(
+                    skipped = total - results.len(),
+                    total, "Some CFB game results couldn't be read"
+                );
+            }
+            Ok(results)
+        }
+    }
+}
+
+/// Drop repeats of the same game, keeping the first
+fn dedupe_by_game_id(results: Vec<GameResult>) -> Vec<GameResult> {
+    let mut seen = HashSet::new();
+    results
+        .into_iter()
+        .filter(|result| seen.insert(result.id))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::game_results_api::GameResultsApiClient;
+    use super::*;
     use anyhow::Context;
     use chrono::{Datelike, Local};
 
+    #[test]
+    fn test_dedupe_by_game_id() {
+        let result = |id: i32, week: i32| -> GameResult {
+            serde_json::from_value(serde_json::json!({
+                "id": id,
+                "season": 2025,
+                "week": week,
+                "seasonType": "regular",
+                "startDate": "2025-09-13T19:30:00.000Z",
+                "startTimeTBD": false,
+                "completed": true,
+                "neutralSite": false,
+                "conferenceGame": false,
+                "homeId": 1,
+                "homeTeam": "Iowa",
+                "awayId": 2,
+                "awayTeam": "Nebraska",
+            }))
+            .unwrap()
+        };
+
+        let results = dedupe_by_game_id(vec![result(1, 2), result(2, 2), result(1, 3)]);
+
+        assert_eq!(
+            results.iter().map(|r| (r.id, r.week)).collect::<Vec<_>>(),
+            vec![(1, 2), (2, 2)]
+        );
+    }
+
+    #[test]
+    fn test_season_week() {
+        assert_eq!(season_week(FIRST_WEEK).unwrap(), 0);
+        assert_eq!(season_week(40).unwrap(), 6);
+        assert!(matches!(
+            season_week(10),
+            Err(CfbBettingError::OutOfSeason(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_game_results_tolerates_missing_fields() {
+        let games = vec![
+            // Only the required fields, with a season type and classification from the future
+            serde_json::json!({
+                "id": 1,
+                "homeTeam": "Iowa",
+                "homePoints": 24,
+                "awayTeam": "Nebraska",
+                "awayPoints": 17,
+                "completed": true,
+                "seasonType": "winterRegular",
+                "homeClassification": "fbsIndependent",
+            }),
+            // No team names: skipped on its own
+            serde_json::json!({ "id": 2, "season": 2025 }),
+        ];
+
+        let results = parse_game_results(games).unwrap();
+
+        assert_eq!(results.len(), 1);
+        let result = &results[0];
+        assert_eq!(result.id, 1);
+        assert_eq!(result.home_points, Some(24));
+        assert!(result.completed);
+        assert!(!result.neutral_site);
+        assert!(matches!(result.season_type, SeasonType::Other));
+        assert!(matches!(
+            result.home_classification,
+            Some(Classification::Other)
+        ));
+
+        // Nothing readable at all is still an error
+        assert!(parse_game_results(vec![serde_json::json!({ "id": 3 })]).is_err());
+        assert!(parse_game_results(Vec::new()).unwrap().is_empty());
+    }
+
     #[tokio::test]
     #[ignore]
     async fn test_fetch_games() {
@@ -1,10 +1,19 @@
+use crate::config::odds_api_books;
+use crate::error::{CfbBettingError, Result};
 use crate::models::{BettingOdds, Game, MoneylineOdds, Sport, SpreadOdds};
-use anyhow::{Context, Result};
+use crate::utils::monitoring::{record_fetch, record_odds_api_credits};
 use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+use tracing::{debug, info, instrument, warn};
 
 const ODDS_API_BASE_URL: &str = "https://api.the-odds-api.com/v4";
 
+/// Service name used in errors
+const ODDS_API: &str = "Odds API";
+
 impl Sport {
     pub fn api_key(&self) -> &'static str {
         match self {
@@ -14,7 +23,23 @@ impl Sport {
     }
 }
 
+/// Only games starting within this many days are returned
+const LOOKAHEAD_DAYS: i64 = 7;
+
+/// Times a rate-limited or failed request is retried before giving up
+const MAX_RETRIES: u32 = 2;
+
+/// Markets `fetch_games` asks for: h2h = head-to-head (moneyline), spreads = point spreads
+pub const GAME_MARKETS: &[&str] = &["h2h", "spreads"];
+
+/// Bookmakers the Odds API bills as one region when they're requested by name
+const BOOKMAKERS_PER_REGION: u32 = 10;
+
+/// Wait before the first retry, doubled for each one after
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+
 /// Response from The Odds API for a single game
+/// The events endpoint returns the same shape without `bookmakers`.
 #[derive(Debug, Deserialize)]
 struct OddsApiGame {
     id: String,
@@ -22,9 +47,140 @@ struct OddsApiGame {
     commence_time: DateTime<Utc>,
     home_team: String,
     away_team: String,
+    #[serde(default)]
     bookmakers: Vec<OddsApiBookmaker>,
 }
 
+impl OddsApiGame {
+    fn game(&self) -> Game {
+        Game {
+            id: self.id.clone(),
+            home_team: self.home_team.clone(),
+            away_team: self.away_team.clone(),
+            commence_time: self.commence_time,
+            sport_title: self.sport_title.clone(),
+            is_live: false,
+        }
+    }
+
+    /// The game and each bookmaker's moneyline and spread odds
+    /// A market missing a side (usually suspended) is cleared, since a one-sided price would
+    /// skew best-odds and arbitrage calculations; a bookmaker left with no market is dropped.
+    fn into_game_with_odds(self) -> (Game, Vec<BettingOdds>) {
+        let game = self.game();
+
+        let odds: Vec<BettingOdds> = self
+            .bookmakers
+            .into_iter()
+            .map(|bookmaker| {
+                // Find the moneyline market
+                let moneyline: Vec<MoneylineOdds> = bookmaker
+                    .markets
+                    .iter()
+                    .find(|m| m.key == "h2h")
+                    .map(|moneyline_market| {
+                        moneyline_market
+                            .outcomes
+                            .iter()
+                            .filter_map(|outcome| {
+                                Some(MoneylineOdds {
+                                    team: outcome.name.clone(),
+                                    price: american_price(outcome.price, &bookmaker.title)?,
+                                })
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                // Find the spreads market
+                let spreads: Vec<SpreadOdds> = bookmaker
+                    .markets
+                    .iter()
+                    .find(|m| m.key == "spreads")
+                    .map(|spread_market| {
+                        spread_market
+                            .outcomes
+                            .iter()
+                            .filter_map(|outcome| {
+                                Some(SpreadOdds {
+                                    team: outcome.name.clone(),
+                                    point: outcome.point?,
+                                    price: american_price(outcome.price, &bookmaker.title)?,
+                                })
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                BettingOdds {
+                    game_id: self.id.clone(),
+                    bookmaker: bookmaker.title,
+                    last_update: bookmaker.last_update,
+                    moneyline,
+                    spreads,
+                }
+            })
+            .filter_map(|odds| drop_incomplete_markets(odds, &game))
+            .collect();
+
+        (game, odds)
+    }
+
+    /// Whether the game starts within the lookahead window
+    fn is_upcoming(&self) -> bool {
+        self.commence_time <= Utc::now() + chrono::Duration::days(LOOKAHEAD_DAYS)
+    }
+}
+
+/// A bookmaker's odds with any market missing a side cleared (logged), or `None` if no
+/// market is left
+fn drop_incomplete_markets(mut odds: BettingOdds, game: &Game) -> Option<BettingOdds> {
+    let matchup = format!("{} @ {}", game.away_team, game.home_team);
+    if !odds.moneyline.is_empty() && !odds.has_complete_moneyline(game) {
+        warn!(bookmaker = %odds.bookmaker, game = %matchup, "Dropping incomplete moneyline market");
+        odds.moneyline.clear();
+    }
+    if !odds.has_complete_spreads(game) {
+        warn!(bookmaker = %odds.bookmaker, game = %matchup, "Dropping incomplete spread market");
+        odds.spreads.clear();
+    }
+    (!odds.moneyline.is_empty() || !odds.spreads.is_empty()).then_some(odds)
+}
+
+/// Games that kicked off by `now`, tagged as live
+fn live_games(
+    games: Vec<(Game, Vec<BettingOdds>)>,
+    now: DateTime<Utc>,
+) -> Vec<(Game, Vec<BettingOdds>)> {
+    games
+        .into_iter()
+        .filter(|(game, _)| game.commence_time <= now)
+        .map(|(mut game, odds)| {
+            game.is_live = true;
+            (game, odds)
+        })
+        .collect()
+}
+
+/// Largest American-odds magnitude treated as a real price (+100000 is a 1000-to-1 shot)
+const MAX_AMERICAN_ODDS: f64 = 100_000.0;
+
+/// Round an Odds API price to whole American odds, or `None` (logged) if it can't be one
+/// American odds are never between -100 and +100, so a price like 1.91 means the response
+/// was in decimal format rather than a line to trust.
+fn american_price(price: f64, bookmaker: &str) -> Option<i32> {
+    let rounded = price.round();
+    if rounded.is_finite() && (100.0..=MAX_AMERICAN_ODDS).contains(&rounded.abs()) {
+        Some(rounded as i32)
+    } else {
+        warn!(
+            bookmaker,
+            price, "Skipping outcome with an implausible American odds price"
+        );
+        None
+    }
+}
+
 /// Bookmaker data from The Odds API
 #[derive(Debug, Deserialize)]
 struct OddsApiBookmaker {
@@ -60,111 +216,183 @@ impl OddsApiClient {
     pub fn new(api_key: String) -> Self {
         Self {
             api_key,
-            client: reqwest::Client::new(),
+            client: crate::http_client_builder().build().unwrap(),
         }
     }
 
-    /// Fetch upcoming games with odds for a given sport
-    /// Only returns games that are in the future and within the next 7 days
-    pub async fn fetch_games(&self, sport: Sport) -> Result<Vec<(Game, Vec<BettingOdds>)>> {
-        let url = format!("{}/sports/{}/odds", ODDS_API_BASE_URL, sport.api_key());
+    /// GET an Odds API path and parse the JSON response
+    /// Rate limits, server errors and dropped connections are retried with backoff.
+    async fn get_json<T: DeserializeOwned>(&self, path: &str, query: &[(&str, &str)]) -> Result<T> {
+        let mut attempt = 0;
+        loop {
+            match self.try_get_json(path, query).await {
+                Err(e) if e.is_retryable() && attempt < MAX_RETRIES => {
+                    let delay = RETRY_BASE_DELAY * 2u32.pow(attempt);
+                    attempt += 1;
+                    warn!(error = %e, attempt, delay_ms = delay.as_millis() as u64, "Retrying Odds API request");
+                    tokio::time::sleep(delay).await;
+                }
+                result => {
+                    record_fetch("odds_api", &result);
+                    return result;
+                }
+            }
+        }
+    }
+
+    async fn try_get_json<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+    ) -> Result<T> {
+        let url = format!("{}{}", ODDS_API_BASE_URL, path);
 
         let response = self
             .client
             .get(&url)
-            .query(&[
-                ("apiKey", self.api_key.as_str()),
-                ("regions", "us"),
-                ("markets", "h2h,spreads"), // h2h = head-to-head (moneyline), spreads = point spreads
-                ("oddsFormat", "american"),
-            ])
+            .query(&[("apiKey", self.api_key.as_str())])
+            .query(query)
             .send()
             .await
-            .context("Failed to fetch odds from The Odds API")?;
+            .map_err(|e| CfbBettingError::api(ODDS_API, e))?;
 
-        println!("Fetching from Odds API");
+        debug!(status = %response.status(), "Odds API responded");
+        if let Some(remaining) = usage_header(&response, "x-requests-remaining") {
+            record_odds_api_credits(remaining);
+        }
 
         if !response.status().is_success() {
-            anyhow::bail!("Odds API returned error: {}", response.status());
+            return Err(CfbBettingError::api_status(ODDS_API, response.status()));
         }
 
-        let api_games: Vec<OddsApiGame> = response
+        response
             .json()
             .await
-            .context("Failed to parse Odds API response")?;
+            .map_err(|e| CfbBettingError::parse("Odds API response", e))
+    }
 
-        // Filter games to only include those in the future and within the next week
-        let now = Utc::now();
-        let one_week_from_now = now + chrono::Duration::days(7);
+    /// List upcoming games for a sport without odds
+    /// The events endpoint doesn't cost any credits, so use it to pick games before pulling
+    /// their odds. Only returns games within the next 7 days.
+    #[instrument(skip(self), fields(source = "odds_api"))]
+    pub async fn fetch_events(&self, sport: Sport) -> Result<Vec<Game>> {
+        let start = Instant::now();
+        let path = format!("/sports/{}/events", sport.api_key());
+        let events: Vec<OddsApiGame> = self.get_json(&path, &[]).await?;
 
-        Ok(api_games
+        let games: Vec<Game> = events
+            .iter()
+            .filter(|event| event.is_upcoming())
+            .map(OddsApiGame::game)
+            .collect();
+
+        info!(
+            games = games.len(),
+            elapsed_ms = start.elapsed().as_millis() as u64,
+            "Fetched events"
+        );
+
+        Ok(games)
+    }
+
+    /// Fetch upcoming games with odds for a given sport
+    /// Only returns games that are in the future and within the next 7 days
+    #[instrument(skip(self), fields(source = "odds_api"))]
+    pub async fn fetch_games(&self, sport: Sport) -> Result<Vec<(Game, Vec<BettingOdds>)>> {
+        let start = Instant::now();
+        let path = format!("/sports/{}/odds", sport.api_key());
+        let (books_param, books) = odds_api_books();
+        let markets = GAME_MARKETS.join(",");
+        let query = [
+            (books_param, books.as_str()),
+            ("markets", markets.as_str()),
+            ("oddsFormat", "american"),
+        ];
+        let api_games: Vec<OddsApiGame> = self.get_json(&path, &query).await?;
+
+        let games: Vec<(Game, Vec<BettingOdds>)> = api_games
             .into_iter()
-            .filter(|api_game| {
-                // Only include games that start in the future and within the next N days
-                api_game.commence_time <= one_week_from_now
-            })
-            .map(|api_game| {
-                let game = Game {
-                    id: api_game.id.clone(),
-                    home_team: api_game.home_team,
-                    away_team: api_game.away_team,
-                    commence_time: api_game.commence_time,
-                    sport_title: api_game.sport_title,
-                };
-
-                let odds: Vec<BettingOdds> = api_game
-                    .bookmakers
-                    .into_iter()
-                    .filter_map(|bookmaker| {
-                        // Find the moneyline market
-                        let moneyline_market = bookmaker.markets.iter().find(|m| m.key == "h2h")?;
-
-                        let moneyline: Vec<MoneylineOdds> = moneyline_market
-                            .outcomes
-                            .iter()
-                            .map(|outcome| MoneylineOdds {
-                                team: outcome.name.clone(),
-                                price: outcome.price as i32,
-                            })
-                            .collect();
+            .filter(OddsApiGame::is_upcoming)
+            .map(OddsApiGame::into_game_with_odds)
+            .collect();
 
-                        // Find the spreads market
-                        let spreads: Vec<SpreadOdds> = bookmaker
-                            .markets
-                            .iter()
-                            .find(|m| m.key == "spreads")
-                            .map(|spread_market| {
-                                spread_market
-                                    .outcomes
-                                    .iter()
-                                    .filter_map(|outcome| {
-                                        Some(SpreadOdds {
-                                            team: outcome.name.clone(),
-                                            point: outcome.point?,
-                                            price: outcome.price as i32,
-                                        })
-                                    })
-                                    .collect()
-                            })
-                            .unwrap_or_default();
-
-                        Some(BettingOdds {
-                            game_id: api_game.id.clone(),
-                            bookmaker: bookmaker.title,
-                            last_update: bookmaker.last_update,
-                            moneyline,
-                            spreads,
-                        })
-                    })
-                    .collect();
+        info!(
+            games = games.len(),
+            elapsed_ms = start.elapsed().as_millis() as u64,
+            "Fetched odds"
+        );
 
-                (game, odds)
-            })
-            .collect())
+        Ok(games)
+    }
+
+    /// Fetch live (in-play) odds for games already in progress
+    /// The odds endpoint keeps pricing games after kickoff; this keeps only those and tags
+    /// them `is_live` so the arbitrage search includes them and applies its tighter
+    /// staleness limit. Costs the same credits as `fetch_games`.
+    #[instrument(skip(self), fields(source = "odds_api"))]
+    pub async fn fetch_live_games(&self, sport: Sport) -> Result<Vec<(Game, Vec<BettingOdds>)>> {
+        let games = live_games(self.fetch_games(sport).await?, Utc::now());
+        info!(games = games.len(), "Fetched live odds");
+        Ok(games)
+    }
+
+    /// Fetch current odds for a single game by its event ID
+    /// `markets` are Odds API market keys, e.g. `["h2h", "spreads"]`. Costs credits per
+    /// market like `fetch_games`, but only for the one game. Markets not requested are left
+    /// empty on each bookmaker's odds.
+    #[instrument(skip(self), fields(source = "odds_api"))]
+    pub async fn fetch_game_odds(
+        &self,
+        sport: Sport,
+        event_id: &str,
+        markets: &[&str],
+    ) -> Result<(Game, Vec<BettingOdds>)> {
+        let start = Instant::now();
+        let path = format!("/sports/{}/events/{}/odds", sport.api_key(), event_id);
+        let markets = markets.join(",");
+        let (books_param, books) = odds_api_books();
+        let query = [
+            (books_param, books.as_str()),
+            ("markets", markets.as_str()),
+            ("oddsFormat", "american"),
+        ];
+        let api_game: OddsApiGame = self.get_json(&path, &query).await?;
+        let (game, odds) = api_game.into_game_with_odds();
+
+        info!(
+            bookmakers = odds.len(),
+            elapsed_ms = start.elapsed().as_millis() as u64,
+            "Fetched game odds"
+        );
+
+        Ok((game, odds))
+    }
+
+    /// Credits an odds request for these markets and regions costs: one per market per
+    /// region, whatever the sport
+    /// The `/odds` endpoint bills once for all games, and the event odds endpoint once per
+    /// game. Duplicate keys are only billed once.
+    pub fn estimate_cost(markets: &[&str], regions: &[&str]) -> u32 {
+        unique_keys(markets) * unique_keys(regions)
+    }
+
+    /// Credits an odds request for these markets costs with the configured books
+    /// (`ODDS_API_REGIONS`, or `ODDS_API_BOOKMAKERS` billed as one region per 10 books)
+    pub fn estimate_configured_cost(markets: &[&str]) -> u32 {
+        let (books_param, books) = odds_api_books();
+        let books: Vec<&str> = books.split(',').collect();
+        let regions = if books_param == "bookmakers" {
+            unique_keys(&books).div_ceil(BOOKMAKERS_PER_REGION)
+        } else {
+            unique_keys(&books)
+        };
+        unique_keys(markets) * regions
     }
 
     /// Check how many API requests you have remaining
-    pub async fn check_usage(&self) -> Result<()> {
+    /// The `/sports` endpoint doesn't cost any credits.
+    #[instrument(skip(self), fields(source = "odds_api"))]
+    pub async fn check_usage(&self) -> Result<ApiUsage> {
         let url = format!("{}/sports", ODDS_API_BASE_URL);
 
         let response = self
@@ -172,24 +400,296 @@ impl OddsApiClient {
             .get(&url)
             .query(&[("apiKey", self.api_key.as_str())])
             .send()
-            .await?;
+            .await
+            .map_err(|e| CfbBettingError::api(ODDS_API, e))?;
+
+        let usage = ApiUsage {
+            remaining: usage_header(&response, "x-requests-remaining"),
+            used: usage_header(&response, "x-requests-used"),
+        };
 
-        if let Some(remaining) = response.headers().get("x-requests-remaining") {
-            println!("API requests remaining: {:?}", remaining);
+        if let Some(remaining) = usage.remaining {
+            record_odds_api_credits(remaining);
+            info!(remaining, "Odds API requests remaining");
         }
 
-        if let Some(used) = response.headers().get("x-requests-used") {
-            println!("API requests used: {:?}", used);
+        if let Some(used) = usage.used {
+            info!(used, "Odds API requests used");
         }
 
-        Ok(())
+        Ok(usage)
     }
 }
 
+/// Credit usage reported by The Odds API
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ApiUsage {
+    pub remaining: Option<u32>,
+    pub used: Option<u32>,
+}
+
+impl ApiUsage {
+    /// Whether at least `threshold` credits are left
+    /// An unreported balance counts as enough, so a missing header never blocks a fetch.
+    pub fn has_at_least(&self, threshold: u32) -> bool {
+        self.remaining
+            .is_none_or(|remaining| remaining >= threshold)
+    }
+}
+
+/// How many distinct, non-blank keys are listed
+fn unique_keys(keys: &[&str]) -> u32 {
+    keys.iter()
+        .map(|key| key.trim())
+        .filter(|key| !key.is_empty())
+        .collect::<HashSet<_>>()
+        .len() as u32
+}
+
+/// Read a numeric usage header; the API sometimes sends these as decimals
+fn usage_header(response: &reqwest::Response, name: &str) -> Option<u32> {
+    response
+        .headers()
+        .get(name)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .map(|value| value as u32)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_events_parse_without_bookmakers() {
+        let kickoff = Utc::now() + chrono::Duration::days(2);
+        let far_off = Utc::now() + chrono::Duration::days(30);
+        let events: Vec<OddsApiGame> = serde_json::from_value(serde_json::json!([
+            {
+                "id": "e912304de2b2ce35b473ce2ecd3d1502",
+                "sport_key": "americanfootball_ncaaf",
+                "sport_title": "NCAAF",
+                "commence_time": kickoff,
+                "home_team": "Iowa Hawkeyes",
+                "away_team": "Nebraska Cornhuskers"
+            },
+            {
+                "id": "later",
+                "sport_key": "americanfootball_ncaaf",
+                "sport_title": "NCAAF",
+                "commence_time": far_off,
+                "home_team": "Ohio State Buckeyes",
+                "away_team": "Michigan Wolverines"
+            }
+        ]))
+        .unwrap();
+
+        let games: Vec<Game> = events
+            .iter()
+            .filter(|event| event.is_upcoming())
+            .map(OddsApiGame::game)
+            .collect();
+        assert_eq!(games.len(), 1);
+        assert_eq!(games[0].id, "e912304de2b2ce35b473ce2ecd3d1502");
+        assert_eq!(games[0].home_team, "Iowa Hawkeyes");
+        assert_eq!(games[0].commence_time, kickoff);
+    }
+
+    #[test]
+    fn test_live_games_keeps_started_games() {
+        let now = Utc::now();
+        let game = |id: &str, kickoff: DateTime<Utc>| {
+            (
+                Game {
+                    id: id.to_string(),
+                    home_team: "Iowa Hawkeyes".to_string(),
+                    away_team: "Nebraska Cornhuskers".to_string(),
+                    commence_time: kickoff,
+                    sport_title: "NCAAF".to_string(),
+                    is_live: false,
+                },
+                Vec::new(),
+            )
+        };
+
+        let live = live_games(
+            vec![
+                game("started", now - chrono::Duration::hours(1)),
+                game("upcoming", now + chrono::Duration::hours(1)),
+            ],
+            now,
+        );
+
+        assert_eq!(live.len(), 1);
+        assert_eq!(live[0].0.id, "started");
+        assert!(live[0].0.is_live);
+    }
+
+    #[test]
+    fn test_event_odds_parse_like_game_odds() {
+        let (game, odds) = serde_json::from_value::<OddsApiGame>(serde_json::json!({
+            "id": "e912304de2b2ce35b473ce2ecd3d1502",
+            "sport_key": "americanfootball_ncaaf",
+            "sport_title": "NCAAF",
+            "commence_time": "2025-11-28T17:00:00Z",
+            "home_team": "Iowa Hawkeyes",
+            "away_team": "Nebraska Cornhuskers",
+            "bookmakers": [{
+                "key": "fanduel",
+                "title": "FanDuel",
+                "last_update": "2025-11-27T12:00:00Z",
+                "markets": [
+                    {
+                        "key": "h2h",
+                        "last_update": "2025-11-27T12:00:00Z",
+                        "outcomes": [
+                            {"name": "Iowa Hawkeyes", "price": -250},
+                            {"name": "Nebraska Cornhuskers", "price": 205}
+                        ]
+                    },
+                    {
+                        "key": "spreads",
+                        "last_update": "2025-11-27T12:00:00Z",
+                        "outcomes": [
+                            {"name": "Iowa Hawkeyes", "price": -110, "point": -6.5},
+                            {"name": "Nebraska Cornhuskers", "price": -110, "point": 6.5}
+                        ]
+                    }
+                ]
+            }]
+        }))
+        .unwrap()
+        .into_game_with_odds();
+
+        assert_eq!(game.id, "e912304de2b2ce35b473ce2ecd3d1502");
+        assert_eq!(odds.len(), 1);
+        assert_eq!(odds[0].game_id, game.id);
+        assert_eq!(odds[0].bookmaker, "FanDuel");
+        assert_eq!(odds[0].moneyline[1].price, 205);
+        assert_eq!(odds[0].spreads[0].point, -6.5);
+    }
+
+    #[test]
+    fn test_incomplete_markets_are_dropped_one_at_a_time() {
+        let bookmaker = |title: &str, markets: serde_json::Value| {
+            serde_json::json!({
+                "key": title.to_lowercase(),
+                "title": title,
+                "last_update": "2025-11-27T12:00:00Z",
+                "markets": markets,
+            })
+        };
+        let moneyline = serde_json::json!({
+            "key": "h2h",
+            "outcomes": [
+                {"name": "Iowa Hawkeyes", "price": -250},
+                {"name": "Nebraska Cornhuskers", "price": 205}
+            ]
+        });
+        let suspended_spread = serde_json::json!({
+            "key": "spreads",
+            "outcomes": [{"name": "Iowa Hawkeyes", "price": -110, "point": -6.5}]
+        });
+        let suspended_moneyline = serde_json::json!({
+            "key": "h2h",
+            "outcomes": [{"name": "Iowa Hawkeyes", "price": -250}]
+        });
+        let (_, odds) = serde_json::from_value::<OddsApiGame>(serde_json::json!({
+            "id": "game",
+            "sport_title": "NCAAF",
+            "commence_time": "2025-11-28T17:00:00Z",
+            "home_team": "Iowa Hawkeyes",
+            "away_team": "Nebraska Cornhuskers",
+            "bookmakers": [
+                // No spread market at all
+                bookmaker("FanDuel", serde_json::json!([moneyline])),
+                // A suspended spread side
+                bookmaker("DraftKings", serde_json::json!([moneyline, suspended_spread])),
+                // Nothing complete left
+                bookmaker("BetMGM", serde_json::json!([suspended_moneyline])),
+            ]
+        }))
+        .unwrap()
+        .into_game_with_odds();
+
+        assert_eq!(odds.len(), 2);
+        for book in &odds {
+            assert_eq!(book.moneyline.len(), 2);
+            assert!(book.spreads.is_empty());
+        }
+        assert_eq!(odds[1].bookmaker, "DraftKings");
+    }
+
+    #[test]
+    fn test_spreads_only_markets_parse() {
+        let (_, odds) = serde_json::from_value::<OddsApiGame>(serde_json::json!({
+            "id": "game",
+            "sport_title": "NCAAF",
+            "commence_time": "2025-11-28T17:00:00Z",
+            "home_team": "Iowa Hawkeyes",
+            "away_team": "Nebraska Cornhuskers",
+            "bookmakers": [{
+                "key": "fanduel",
+                "title": "FanDuel",
+                "last_update": "2025-11-27T12:00:00Z",
+                "markets": [{
+                    "key": "spreads",
+                    "outcomes": [
+                        {"name": "Iowa Hawkeyes", "price": -110, "point": -6.5},
+                        {"name": "Nebraska Cornhuskers", "price": -110, "point": 6.5}
+                    ]
+                }]
+            }]
+        }))
+        .unwrap()
+        .into_game_with_odds();
+
+        assert_eq!(odds.len(), 1);
+        assert!(odds[0].moneyline.is_empty());
+        assert_eq!(odds[0].spreads.len(), 2);
+    }
+
+    #[test]
+    fn test_american_price_rounds_and_validates() {
+        assert_eq!(american_price(-110.0, "FanDuel"), Some(-110));
+        assert_eq!(american_price(109.9, "FanDuel"), Some(110));
+        assert_eq!(american_price(-109.6, "FanDuel"), Some(-110));
+        assert_eq!(american_price(-100.4, "FanDuel"), Some(-100));
+        // Decimal odds and junk aren't American prices
+        assert_eq!(american_price(1.91, "FanDuel"), None);
+        assert_eq!(american_price(-99.0, "FanDuel"), None);
+        assert_eq!(american_price(f64::NAN, "FanDuel"), None);
+    }
+
+    #[test]
+    fn test_api_usage_threshold() {
+        let usage = ApiUsage {
+            remaining: Some(40),
+            used: Some(460),
+        };
+        assert!(usage.has_at_least(40));
+        assert!(!usage.has_at_least(41));
+        assert!(ApiUsage::default().has_at_least(1000));
+    }
+
+    #[test]
+    fn test_estimate_cost() {
+        assert_eq!(OddsApiClient::estimate_cost(GAME_MARKETS, &["us"]), 2);
+        assert_eq!(
+            OddsApiClient::estimate_cost(&["h2h", "spreads", "totals"], &["us", "us2"]),
+            6
+        );
+        // Repeated or blank keys aren't billed twice
+        assert_eq!(
+            OddsApiClient::estimate_cost(&["h2h", "h2h"], &["us", " us", ""]),
+            1
+        );
+        assert_eq!(OddsApiClient::estimate_cost(&[], &["us"]), 0);
+    }
+
     #[tokio::test]
     #[ignore]
     async fn test_fetch_games() {
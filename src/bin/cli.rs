@@ -1,21 +1,53 @@
 use anyhow::{Context, Result};
-use cfb_betting_ev::arbitrage::{find_moneyline_arbitrage, find_spread_arbitrage};
+use cfb_betting_ev::arbitrage::{
+    dedupe_games, find_moneyline_arbitrage, find_spread_arbitrage, find_spread_middles,
+    merge_kalshi_odds, round_spread_to_increment, round_to_increment, ArbFilter,
+};
+use cfb_betting_ev::backtest::{backtest, closing_lines, OddsSnapshot, StakingConfig};
+use cfb_betting_ev::closing::{
+    capture_batches, capture_closing_lines, CaptureBatch, DEFAULT_CLOSING_LEAD_MINUTES,
+};
+use cfb_betting_ev::conferences::game_in_conferences;
+use cfb_betting_ev::config::{
+    confirm_above_credits, kalshi_api_key, kalshi_private_key_path, load_env, profile_arg,
+    DEFAULT_MIN_BOOKS_IN_MARKET,
+};
 use cfb_betting_ev::data::{
-    load_from_cache, load_moneyline_bets_from_csv, load_spread_bets_from_csv,
-    save_moneyline_arbitrage_to_csv, save_moneyline_bets_to_csv, save_spread_arbitrage_to_csv,
-    save_spread_bets_to_csv, save_to_cache,
+    load_adjustments_from_csv, load_all_snapshots, load_boosts_from_csv, load_from_cache,
+    load_moneyline_bets_from_csv, load_spread_bets_from_csv, save_moneyline_arbitrage_to_csv,
+    save_moneyline_bets_to_csv, save_spread_arbitrage_to_csv, save_spread_bets_to_csv,
+    save_to_cache,
 };
 use cfb_betting_ev::ev_analysis::{
-    compare_ev_bets_to_results, compare_spread_ev_bets_to_results, find_top_ev_bets,
-    find_top_spread_ev_bets,
+    add_closing_lines, add_spread_closing_lines, adjustment_notes_for, apply_adjustments,
+    best_line_per_team, compare_ev_bets_to_results, compare_spread_ev_bets_to_results,
+    find_data_anomalies, find_market_value_bets, find_top_ev_bets, find_top_ev_bets_vs_sharp,
+    find_top_spread_ev_bets, AdjustmentNotes, BetResult, BoostConfig, EvFilter, GradingSummary,
+    MarginStdDev, SpreadBetResult, SpreadModel, DEFAULT_MAX_PROB_GAP, DEFAULT_UNIT_SIZE,
+    SPREAD_STD_DEV,
+};
+use cfb_betting_ev::export::{
+    format_as_markdown, format_moneyline_arbs_as_markdown, format_spread_arbs_as_markdown,
+    format_spread_bets_as_markdown, post_to_webhook, WebhookConfig,
+};
+use cfb_betting_ev::ledger::update_ledger;
+use cfb_betting_ev::line_movement::{compare_snapshots, find_steam_moves, DEFAULT_STEAM_THRESHOLD};
+use cfb_betting_ev::odds_api::GAME_MARKETS;
+use cfb_betting_ev::prediction_tracker::GamePrediction;
+use cfb_betting_ev::staking::{
+    size_stakes, KellySizing, DEFAULT_KELLY_MULTIPLIER, DEFAULT_MAX_GAME_EXPOSURE,
 };
 use cfb_betting_ev::{
-    BettingOdds, Game, GameResultsApiClient, KalshiClient, OddsApiClient, PredictionTrackerScraper,
-    Sport,
+    default_prediction_sources, display_timezone, fetch_from_sources, format_kickoff,
+    record_odds_history, required_env_var, retain_next_game_day, top_n_limit, BettingOdds,
+    Bookmaker, Game, GameResult, GameResultsApiClient, KalshiClient, KalshiCredentials,
+    OddsApiClient, Sport, CBB_ODDS_HISTORY_FILE, CLOSING_ODDS_FILE, DEFAULT_TOP_N, LEDGER_FILE,
+    ODDS_HISTORY_FILE,
 };
-use chrono::{Datelike, Local};
-use clap::{Parser, Subcommand};
+use chrono::{Datelike, Local, Utc};
+use clap::{Parser, Subcommand, ValueEnum};
 use std::path::Path;
+use tracing::warn;
 
 #[derive(Parser)]
 #[command(name = "cfb-betting")]
@@ -23,22 +55,260 @@ use std::path::Path;
 struct Cli {
     #[command(subcommand)]
     command: Option<Commands>,
+
+    /// Load `.env.NAME` layered over `.env`, e.g. a cached "scan" or fresh "live" setup
+    #[arg(long, value_name = "NAME", env = "CFB_PROFILE", global = true)]
+    profile: Option<String>,
+
+    /// Load odds and predictions from the cache files instead of calling the APIs
+    #[arg(long, env = "USE_CACHE", global = true)]
+    use_cache: bool,
+
+    /// Save bets and arbitrage opportunities to CSV files
+    #[arg(long, global = true)]
+    save_csv: bool,
+
+    /// Number of EV bets (and line moves) to show for each market; 0 shows them all
+    #[arg(long = "top", value_name = "N", env = "TOP_N", default_value_t = DEFAULT_TOP_N, global = true)]
+    top: usize,
+
+    /// Which sport(s) to analyze
+    #[arg(long, value_enum, default_value_t = SportFilter::Both, global = true)]
+    sport: SportFilter,
+
+    /// Minimum expected value (in percent) for an EV bet to be shown
+    #[arg(
+        long,
+        value_name = "PERCENT",
+        env = "MIN_EV",
+        default_value_t = 0.0,
+        global = true
+    )]
+    min_ev: f64,
+
+    /// Only show EV bets and results for games involving this conference (repeatable,
+    /// e.g. --conference SEC --conference "Big Ten"; "Other" matches unknown teams)
+    #[arg(long, value_name = "NAME", global = true)]
+    conference: Vec<String>,
+
+    /// Only analyze games where both teams are FBS
+    #[arg(long, global = true)]
+    fbs_only: bool,
+
+    /// Only analyze the soonest day with games (days in DISPLAY_TZ), not the whole week
+    #[arg(long, env = "NEXT_GAME_DAY_ONLY", global = true)]
+    next_game_day: bool,
+
+    /// Price spreads with the prediction page's own cover probability on the line it lists
+    /// one for, instead of the normal model
+    #[arg(long, env = "SCRAPED_COVER", global = true)]
+    scraped_cover: bool,
+
+    /// Drop EV bets whose edge (in percentage points) exceeds this, e.g. 25
+    #[arg(long, value_name = "PERCENT", env = "MAX_EDGE", global = true)]
+    max_edge: Option<f64>,
+
+    /// CSV of manual injury/weather adjustments (team, spread_delta, prob_delta, note)
+    /// applied to the predictions before EV is computed
+    #[arg(long, value_name = "FILE", global = true)]
+    adjustments: Option<String>,
+
+    /// CSV of profit boosts (team, bookmaker, opponent, profit_boost) to price moneylines
+    /// with; boosted bets are ranked by their boosted EV
+    #[arg(long, value_name = "FILE", env = "BOOSTS_FILE", global = true)]
+    boosts: Option<String>,
+
+    /// Price moneylines against this bookmaker's de-vigged line instead of the predictions
+    /// ("consensus" averages every book's de-vigged line, weighting sharp books up)
+    #[arg(long, value_name = "BOOKMAKER", global = true)]
+    sharp_book: Option<String>,
+
+    /// Only recommend each bet at the bookmaker offering the best price (same as --max-books 1)
+    #[arg(long, global = true)]
+    line_shop: bool,
+
+    /// Only show the best-EV spread line for each team when books offer alternate lines
+    #[arg(long, global = true)]
+    best_line: bool,
+
+    /// List at most N bookmakers for the same bet, keeping the best-EV ones
+    #[arg(long, value_name = "N", global = true)]
+    max_books: Option<usize>,
+
+    /// Std dev of the final margin (in points) used to price spreads
+    #[arg(long, value_name = "POINTS", default_value_t = SPREAD_STD_DEV, global = true)]
+    spread_std_dev: f64,
+
+    /// Widen the spread std dev by this many points per point of projected margin, so
+    /// lopsided games get more margin variance (e.g. 0.1; 0 keeps it constant)
+    #[arg(long, value_name = "POINTS", default_value_t = 0.0, global = true)]
+    std_dev_per_spread_point: f64,
+
+    /// Stake used for the dollar profit figures in the output and CSV files
+    #[arg(long, value_name = "DOLLARS", default_value_t = DEFAULT_UNIT_SIZE, global = true)]
+    unit_size: f64,
+
+    /// Size EV bets with fractional Kelly against this bankroll instead of a flat --unit-size
+    #[arg(long, value_name = "DOLLARS", global = true)]
+    bankroll: Option<f64>,
+
+    /// Fraction of the full Kelly stake to bet when sizing against --bankroll
+    #[arg(long, value_name = "FRACTION", default_value_t = DEFAULT_KELLY_MULTIPLIER, global = true)]
+    kelly_multiplier: f64,
+
+    /// Most of the --bankroll (in percent) to stake on one game across all its bets;
+    /// larger totals are scaled down proportionally
+    #[arg(long, value_name = "PERCENT", default_value_t = DEFAULT_MAX_GAME_EXPOSURE * 100.0, global = true)]
+    max_game_exposure: f64,
+
+    /// Skip arbitrage with a leg at this bookmaker (repeatable, e.g. books you can't bet at)
+    #[arg(long, value_name = "BOOKMAKER", global = true)]
+    exclude_book: Vec<Bookmaker>,
+
+    /// Only look for arbitrage in markets at least this many books quote
+    #[arg(long, value_name = "BOOKS", env = "MIN_BOOKS_IN_MARKET", default_value_t = DEFAULT_MIN_BOOKS_IN_MARKET, global = true)]
+    min_books: usize,
+
+    /// Ignore prices more than this many percentage points of implied probability from
+    /// the median of the books quoting the same bet when looking for arbitrage
+    #[arg(long, value_name = "PERCENT", env = "ARB_MAX_PRICE_GAP", global = true)]
+    max_price_gap: Option<f64>,
+
+    /// Keep arbitrage with both legs at the same book
+    #[arg(long, env = "ALLOW_SAME_BOOK_ARBS", global = true)]
+    allow_same_book: bool,
+
+    /// Total amount to split across the two legs of each arbitrage
+    #[arg(long, value_name = "DOLLARS", default_value_t = 100.0, value_parser = parse_positive_dollars, global = true)]
+    arb_stake: f64,
+
+    /// Smallest bet increment at the books; arbitrage stakes are rounded to multiples of it
+    #[arg(long, value_name = "DOLLARS", default_value_t = 1.0, value_parser = parse_positive_dollars, global = true)]
+    stake_increment: f64,
+
+    /// Look for arbitrage on games in progress using live (in-play) odds instead of
+    /// upcoming games; skips EV analysis and the odds cache
+    #[arg(long, global = true)]
+    live: bool,
+
+    /// Directory CSV files are written to
+    #[arg(long, value_name = "DIR", default_value = "cache", global = true)]
+    output_dir: String,
+
+    /// Go ahead with runs estimated to cost more Odds API credits than
+    /// CONFIRM_ABOVE_CREDITS (default 4)
+    #[arg(long, global = true)]
+    confirm: bool,
+
+    /// How EV bets and arbitrage are listed; markdown is ready to paste into Discord or Slack
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text, global = true)]
+    format: OutputFormat,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    Text,
+    Markdown,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum SportFilter {
+    Cfb,
+    Cbb,
+    Both,
+}
+
+impl Cli {
+    /// The --min-books, --max-price-gap and --allow-same-book arbitrage checks
+    fn arb_filter(&self) -> ArbFilter {
+        ArbFilter {
+            require_distinct_books: !self.allow_same_book,
+            min_books_in_market: self.min_books,
+            max_median_gap: self.max_price_gap.map(|gap| gap / 100.0),
+        }
+    }
+
+    /// EV bet screening from --fbs-only, --min-ev, --max-edge and --conference
+    fn ev_filter(&self) -> EvFilter {
+        EvFilter {
+            fbs_only: self.fbs_only,
+            min_ev: self.min_ev / 100.0,
+            max_edge: self.max_edge.map(|max_edge| max_edge / 100.0),
+            conferences: self.conference.clone(),
+        }
+    }
+
+    /// Whether a game passes the --conference filter (no filter includes every game)
+    fn includes_game(&self, home_team: &str, away_team: &str) -> bool {
+        self.conference.is_empty() || game_in_conferences(home_team, away_team, &self.conference)
+    }
+
+    /// Spread margin model from --spread-std-dev and --std-dev-per-spread-point
+    fn margin_std_dev(&self) -> MarginStdDev {
+        if self.std_dev_per_spread_point == 0.0 {
+            MarginStdDev::Constant(self.spread_std_dev)
+        } else {
+            MarginStdDev::Linear {
+                base: self.spread_std_dev,
+                per_spread_point: self.std_dev_per_spread_point,
+                per_total_point: 0.0,
+            }
+        }
+    }
+
+    /// How many EV bets to keep for each market (`None` keeps them all)
+    fn top_n(&self) -> Option<usize> {
+        top_n_limit(self.top)
+    }
+
+    /// How many bookmakers to list for the same EV bet (`None` lists them all)
+    fn max_books_per_bet(&self) -> Option<usize> {
+        if self.line_shop {
+            Some(1)
+        } else {
+            self.max_books
+        }
+    }
+}
+
+/// Parse a dollar amount that has to be above zero (stakes are divided by it)
+fn parse_positive_dollars(value: &str) -> Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(dollars) if dollars.is_finite() && dollars > 0.0 => Ok(dollars),
+        Ok(_) => Err("must be a positive amount".to_string()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+impl SportFilter {
+    fn includes_cfb(self) -> bool {
+        self != SportFilter::Cbb
+    }
+
+    fn includes_cbb(self) -> bool {
+        self != SportFilter::Cfb
+    }
 }
 
 #[derive(Subcommand)]
 enum Commands {
-    /// Check API usage for Odds API and/or College Football Data API
-    CheckUsage {
-        /// Check Odds API usage
+    /// Find positive EV moneyline and spread bets (CFB only)
+    Ev,
+    /// Find arbitrage opportunities across sportsbooks
+    Arb,
+    /// Show CFB game results for a week
+    Results {
+        /// Year of the games (defaults to current year)
         #[arg(long)]
-        odds: bool,
+        year: Option<u32>,
 
-        /// Check College Football Data API usage
+        /// Week of the games (defaults to current week)
         #[arg(long)]
-        cfb_data: bool,
+        week: Option<u8>,
     },
-    /// Compare saved bet recommendations with actual game results
-    CompareBets {
+    /// Grade saved bet recommendations against actual game results
+    #[command(alias = "compare-bets")]
+    Grade {
         /// Path to moneyline bets CSV file
         #[arg(long, default_value = "cache/moneyline_bets.csv")]
         moneyline_csv: String,
@@ -54,514 +324,1169 @@ enum Commands {
         /// Week of the games (defaults to current week)
         #[arg(long)]
         week: Option<u8>,
-    },
-    /// Run the full betting analysis (default)
-    Analyze,
-}
 
-/// Merge Kalshi odds into existing games
-/// For each Kalshi game, find a matching game in the existing list and append Kalshi odds
-/// If no match is found, add the Kalshi game as a new entry
-fn merge_kalshi_odds(
-    existing_games: &mut Vec<(Game, Vec<BettingOdds>)>,
-    kalshi_games: Vec<(Game, Vec<BettingOdds>)>,
-) {
-    use cfb_betting_ev::kalshi_api::normalize_team_name;
-
-    for (kalshi_game, kalshi_odds_list) in kalshi_games {
-        // Try to find a matching game in the existing list
-        let kalshi_home_normalized = normalize_team_name(&kalshi_game.home_team);
-        let kalshi_away_normalized = normalize_team_name(&kalshi_game.away_team);
-
-        let mut found_match = false;
-        for (existing_game, existing_odds_list) in existing_games.iter_mut() {
-            let existing_home_normalized = normalize_team_name(&existing_game.home_team);
-            let existing_away_normalized = normalize_team_name(&existing_game.away_team);
-
-            // Check if teams match (in either order)
-            let teams_match = (kalshi_home_normalized == existing_home_normalized
-                && kalshi_away_normalized == existing_away_normalized)
-                || (kalshi_home_normalized == existing_away_normalized
-                    && kalshi_away_normalized == existing_home_normalized);
-
-            if teams_match {
-                // Merge Kalshi odds into this game
-                existing_odds_list.extend(kalshi_odds_list.clone());
-                found_match = true;
-                break;
-            }
-        }
-
-        if !found_match {
-            // No matching game found, add as new entry
-            existing_games.push((kalshi_game, kalshi_odds_list));
-        }
-    }
-}
-
-#[tokio::main]
-async fn main() -> Result<()> {
-    let cli = Cli::parse();
-    // Load environment variables from .env file
-    dotenv::dotenv().ok();
-
-    // Initialize logging
-    tracing_subscriber::fmt::init();
-
-    match cli.command {
-        Some(Commands::CheckUsage { odds, cfb_data }) => {
-            // If no flags are provided, check both by default
-            let check_odds = odds || !cfb_data;
-            let check_cfb = cfb_data || !odds;
-
-            if check_odds {
-                let odds_api_key =
-                    std::env::var("ODDS_API_KEY").expect("ODDS_API_KEY not set in .env file");
-                let odds_client = OddsApiClient::new(odds_api_key);
-                println!("Checking Odds API usage...\n");
-                odds_client.check_usage().await?;
-                println!();
-            }
-
-            if check_cfb {
-                let cfb_api_key = std::env::var("COLLEGE_FOOTBALL_DATA_API_KEY")
-                    .expect("COLLEGE_FOOTBALL_DATA_API_KEY not set in .env file");
-                let cfb_client = GameResultsApiClient::new(cfb_api_key);
-                println!("Checking College Football Data API usage...\n");
-                cfb_client.check_usage().await?;
-            }
+        /// Grade every week from this one through --week, e.g. for a whole season
+        #[arg(long)]
+        from_week: Option<u8>,
 
-            return Ok(());
-        }
-        Some(Commands::CompareBets {
-            moneyline_csv,
-            spread_csv,
-            year,
-            week,
-        }) => {
-            println!("Comparing bet recommendations with game results...\n");
-
-            // Get year and week (default to current if not specified)
-            let now = Local::now();
-            let year = year.unwrap_or(now.year() as u32);
-            let week = week.unwrap_or(now.iso_week().week() as u8);
-
-            println!("Fetching game results for week {} of {}...\n", week, year);
-
-            // Fetch game results
-            let cfb_api_key = std::env::var("COLLEGE_FOOTBALL_DATA_API_KEY")
-                .expect("COLLEGE_FOOTBALL_DATA_API_KEY not set in .env file");
-            let cfb_client = GameResultsApiClient::new(cfb_api_key);
-            let game_results = cfb_client
-                .fetch_cfb_game_results(year, week)
-                .await
-                .context("Failed to fetch CFB game results")?;
-
-            println!("Fetched {} completed games\n", game_results.len());
-
-            // Load bets from CSV files
-            let moneyline_bets = if Path::new(&moneyline_csv).exists() {
-                println!("Loading moneyline bets from {}...", moneyline_csv);
-                load_moneyline_bets_from_csv(&moneyline_csv)?
-            } else {
-                println!(
-                    "Moneyline CSV file not found: {}. Skipping moneyline comparison.",
-                    moneyline_csv
-                );
-                Vec::new()
-            };
+        /// Odds cache snapshot to take closing lines from (repeatable; each game uses the
+        /// last one before kickoff)
+        #[arg(long = "closing-odds", value_name = "FILE")]
+        closing_odds: Vec<String>,
 
-            let spread_bets = if Path::new(&spread_csv).exists() {
-                println!("Loading spread bets from {}...", spread_csv);
-                load_spread_bets_from_csv(&spread_csv)?
-            } else {
-                println!(
-                    "Spread CSV file not found: {}. Skipping spread comparison.",
-                    spread_csv
-                );
-                Vec::new()
-            };
-
-            // Compare bets with results
-            if !moneyline_bets.is_empty() {
-                println!("\n=== MONEYLINE BET RESULTS ===\n");
-                let bet_results = compare_ev_bets_to_results(&moneyline_bets, &game_results);
-
-                let mut total_wins = 0;
-                let mut total_losses = 0;
-                let mut total_payout = 0.0;
-                let mut total_bet = 0.0;
-
-                for (i, result) in bet_results.iter().enumerate() {
-                    println!("{}. {}", i + 1, result.format());
-
-                    if let (Some(won), Some(payout)) = (result.bet_won, result.actual_payout) {
-                        if won {
-                            total_wins += 1;
-                            total_payout += payout;
-                        } else {
-                            total_losses += 1;
-                        }
-                        total_bet += 1.0;
-                    }
-                }
+        /// Add the settled bets to this season ledger, converting stakes to units with
+        /// --unit-size
+        #[arg(long, value_name = "FILE", num_args = 0..=1, default_missing_value = LEDGER_FILE)]
+        ledger: Option<String>,
+    },
+    /// Compare two odds cache snapshots and report line moves, largest first
+    Movement {
+        /// Older odds cache snapshot
+        before: String,
 
-                if total_bet > 0.0 {
-                    let net_profit = total_payout - total_losses as f64;
-                    let roi = (net_profit / total_bet) * 100.0;
-                    println!("\n--- Moneyline Summary ---");
-                    println!("Total Bets Resolved: {}", total_bet as i32);
-                    println!(
-                        "Wins: {} ({:.1}%)",
-                        total_wins,
-                        (total_wins as f64 / total_bet) * 100.0
-                    );
-                    println!(
-                        "Losses: {} ({:.1}%)",
-                        total_losses,
-                        (total_losses as f64 / total_bet) * 100.0
-                    );
-                    println!("Net Profit: ${:.2}", net_profit);
-                    println!("ROI: {:.2}%", roi);
-                }
-            }
+        /// Newer odds cache snapshot
+        after: String,
+    },
+    /// Flag games whose line has moved significantly from the opening line
+    Steam {
+        /// Minimum move from the opening line, in points
+        #[arg(long, default_value_t = DEFAULT_STEAM_THRESHOLD)]
+        min_move: f64,
+    },
+    /// Flag bookmakers whose CFB moneyline and spread on a game disagree
+    Anomalies {
+        /// Largest gap between moneyline- and spread-implied win probabilities to allow
+        #[arg(long, default_value_t = DEFAULT_MAX_PROB_GAP)]
+        max_gap: f64,
+    },
+    /// Replay the EV model over saved odds snapshots and grade the bets it would have made
+    Backtest {
+        /// Odds cache snapshot to replay (repeatable; each game uses the last one before kickoff)
+        #[arg(long = "odds", value_name = "FILE", required = true)]
+        odds: Vec<String>,
+
+        /// Predictions cache file
+        #[arg(long, default_value = "cache/predictions_cache.json")]
+        predictions: String,
+
+        /// CFB game results cache file
+        #[arg(long, default_value = "cache/cfb_results_cache.json")]
+        results: String,
+    },
+    /// List upcoming CFB games and their Odds API event ids (costs no credits)
+    Events,
+    /// Wait out the CFB slate, capturing each game's odds shortly before kickoff as its
+    /// closing line for CLV
+    Closing {
+        /// Minutes before kickoff to capture the odds
+        #[arg(long, value_name = "MINUTES", env = "CLOSING_LEAD_MINUTES", default_value_t = DEFAULT_CLOSING_LEAD_MINUTES)]
+        lead_minutes: i64,
+    },
+    /// Check API usage for Odds API and/or College Football Data API
+    #[command(alias = "check-usage")]
+    Usage {
+        /// Check Odds API usage
+        #[arg(long)]
+        odds: bool,
 
-            if !spread_bets.is_empty() {
-                println!("\n=== SPREAD BET RESULTS ===\n");
-                let spread_results = compare_spread_ev_bets_to_results(&spread_bets, &game_results);
-
-                let mut total_wins = 0;
-                let mut total_losses = 0;
-                let mut total_payout = 0.0;
-                let mut total_bet = 0.0;
-
-                for (i, result) in spread_results.iter().enumerate() {
-                    println!("{}. {}", i + 1, result.format());
-
-                    if let (Some(won), Some(payout)) = (result.bet_won, result.actual_payout) {
-                        if won {
-                            total_wins += 1;
-                            total_payout += payout;
-                        } else {
-                            total_losses += 1;
-                        }
-                        total_bet += 1.0;
-                    }
-                }
+        /// Check College Football Data API usage
+        #[arg(long)]
+        cfb_data: bool,
+    },
+    /// Run the full betting analysis: EV bets and arbitrage (default)
+    Analyze,
+    /// Fetch everything and post the best opportunities to the WEBHOOK_URL webhook
+    /// (Discord or Slack), e.g. from a cron job
+    Notify {
+        /// Most opportunities to post
+        #[arg(long, value_name = "N", default_value_t = WebhookConfig::default().top_n)]
+        limit: usize,
+
+        /// Minimum EV (in percent) for an EV bet to be posted
+        #[arg(long, value_name = "PERCENT", default_value_t = WebhookConfig::default().min_ev * 100.0)]
+        alert_ev: f64,
+
+        /// Minimum guaranteed profit (in percent) for an arbitrage to be posted
+        #[arg(long, value_name = "PERCENT", default_value_t = WebhookConfig::default().min_arb_profit)]
+        alert_profit: f64,
+    },
+}
 
-                if total_bet > 0.0 {
-                    let net_profit = total_payout - total_losses as f64;
-                    let roi = (net_profit / total_bet) * 100.0;
-                    println!("\n--- Spread Summary ---");
-                    println!("Total Bets Resolved: {}", total_bet as i32);
-                    println!(
-                        "Wins: {} ({:.1}%)",
-                        total_wins,
-                        (total_wins as f64 / total_bet) * 100.0
-                    );
-                    println!(
-                        "Losses: {} ({:.1}%)",
-                        total_losses,
-                        (total_losses as f64 / total_bet) * 100.0
-                    );
-                    println!("Net Profit: ${:.2}", net_profit);
-                    println!("ROI: {:.2}%", roi);
-                }
-            }
+/// Load predictions from the cache file, or fetch them from the prediction sources
+async fn load_predictions(use_cache: bool) -> Result<Vec<GamePrediction>> {
+    let predictions_cache_file = "cache/predictions_cache.json";
 
-            return Ok(());
-        }
-        Some(Commands::Analyze) | None => {
-            // Run the full analysis (default behavior)
-        }
+    if use_cache && Path::new(predictions_cache_file).exists() {
+        println!(
+            "Loading predictions from cache file: {}\n",
+            predictions_cache_file
+        );
+        return Ok(load_from_cache(predictions_cache_file)?);
     }
 
-    println!("College Football Betting EV Calculator\n");
-    println!("Fetching betting odds and model data...\n");
+    let predictions = fetch_from_sources(&default_prediction_sources())
+        .await
+        .context("Failed to fetch predictions")?;
+    save_to_cache(&predictions, predictions_cache_file)?;
+    println!(
+        "Saved predictions to cache file: {}\n",
+        predictions_cache_file
+    );
+    Ok(predictions)
+}
 
-    // Get API key from environment
-    let api_key = std::env::var("ODDS_API_KEY").expect("ODDS_API_KEY not set in .env file");
+/// Odds cache file for a sport
+fn odds_cache_file(sport: &Sport) -> &'static str {
+    match sport {
+        Sport::CollegeFootball => "cache/odds_cache.json",
+        Sport::CollegeBasketball => "cache/cbb_odds_cache.json",
+    }
+}
 
-    // Create clients
-    let odds_client = OddsApiClient::new(api_key);
-    let prediction_scraper = PredictionTrackerScraper::new();
+/// Whether a sport's odds will be fetched from The Odds API rather than read from the cache
+fn fetches_odds(sport: &Sport, use_cache: bool, live: bool) -> bool {
+    live || !(use_cache && Path::new(odds_cache_file(sport)).exists())
+}
 
-    // Optionally create Kalshi client if API key is available
-    let kalshi_client = std::env::var("KALSHI_API_KEY")
-        .ok()
-        .map(KalshiClient::new);
+/// Print the Odds API credits this run's odds fetches should cost, and stop before
+/// fetching if that's more than `CONFIRM_ABOVE_CREDITS` and `--confirm` wasn't given
+fn check_fetch_cost(cli: &Cli) -> Result<()> {
+    let sports = [
+        (cli.sport.includes_cfb(), Sport::CollegeFootball),
+        (cli.sport.includes_cbb(), Sport::CollegeBasketball),
+    ];
+    let fetches = sports
+        .iter()
+        .filter(|(included, sport)| *included && fetches_odds(sport, cli.use_cache, cli.live))
+        .count() as u32;
+    if fetches == 0 {
+        return Ok(());
+    }
 
-    if kalshi_client.is_some() {
-        println!("Kalshi integration enabled\n");
+    let per_fetch = OddsApiClient::estimate_configured_cost(GAME_MARKETS);
+    let estimate = per_fetch * fetches;
+    println!(
+        "Estimated Odds API cost: {} credits ({} per sport, {} sport(s))\n",
+        estimate, per_fetch, fetches
+    );
+
+    let limit = confirm_above_credits();
+    if estimate > limit && !cli.confirm {
+        anyhow::bail!(
+            "this run is estimated to cost {} Odds API credits, more than CONFIRM_ABOVE_CREDITS ({}); rerun with --confirm to go ahead",
+            estimate,
+            limit
+        );
     }
+    Ok(())
+}
 
-    // Check if we should use cached data
-    let odds_cache_file = "cache/odds_cache.json";
-    let predictions_cache_file = "cache/predictions_cache.json";
-    let use_cache = std::env::var("USE_CACHE").unwrap_or_default() == "1";
-    let save_csv = std::env::var("SAVE_CSV").unwrap_or_default() == "1";
+/// Load odds for a sport from the cache file, or fetch them from The Odds API.
+/// Kalshi odds are merged in when a Kalshi client is configured.
+async fn load_odds(
+    odds_client: &OddsApiClient,
+    kalshi_client: Option<&KalshiClient>,
+    sport: Sport,
+    use_cache: bool,
+    live: bool,
+) -> Result<Vec<(Game, Vec<BettingOdds>)>> {
+    let odds_cache_file = odds_cache_file(&sport);
+    let (label, history_file, kalshi_cache_file) = match sport {
+        Sport::CollegeFootball => ("CFB", ODDS_HISTORY_FILE, "cache/kalshi_cfb_cache.json"),
+        Sport::CollegeBasketball => ("CBB", CBB_ODDS_HISTORY_FILE, "cache/kalshi_cbb_cache.json"),
+    };
 
-    let predictions = if use_cache && Path::new(predictions_cache_file).exists() {
-        println!(
-            "Loading predictions from cache file: {}\n",
-            predictions_cache_file
-        );
-        load_from_cache(predictions_cache_file)?
-    } else {
-        // Fetch predictions from The Prediction Tracker
-        let predictions = prediction_scraper
-            .fetch_game_predictions()
+    let mut games_with_odds = if live {
+        // Live lines move by the minute, so they're never cached
+        odds_client
+            .fetch_live_games(sport.clone())
             .await
-            .context("Failed to fetch predictions")?;
-        save_to_cache(&predictions, predictions_cache_file)?;
+            .with_context(|| format!("Failed to fetch live {} odds", label))?
+    } else if !fetches_odds(&sport, use_cache, live) {
         println!(
-            "Saved predictions to cache file: {}\n",
-            predictions_cache_file
+            "Loading {} odds from cache file: {}\n",
+            label, odds_cache_file
         );
-        predictions
-    };
-    // Fetch college football odds
-    let mut cfb_games_with_odds = if use_cache && Path::new(odds_cache_file).exists() {
-        println!("Loading odds from cache file: {}\n", odds_cache_file);
         load_from_cache(odds_cache_file)?
     } else {
         // Fetch odds from The Odds API
         let games_with_odds = odds_client
-            .fetch_games(Sport::CollegeFootball)
+            .fetch_games(sport.clone())
             .await
-            .context("Failed to fetch CFB odds")?;
+            .with_context(|| format!("Failed to fetch {} odds", label))?;
 
-        // Save to cache file
+        // Save to cache file, and keep every fetch in the line history
         save_to_cache(&games_with_odds, odds_cache_file)?;
-        println!("Saved odds to cache file: {}\n", odds_cache_file);
+        println!("Saved {} odds to cache file: {}\n", label, odds_cache_file);
+        record_odds_history(&games_with_odds, history_file);
 
         games_with_odds
     };
 
-    // Fetch and merge Kalshi odds for CFB if available
-    if let Some(ref kalshi) = kalshi_client {
-        let kalshi_cfb_cache = "cache/kalshi_cfb_cache.json";
-        let kalshi_cfb_games = if use_cache && Path::new(kalshi_cfb_cache).exists() {
+    // Fetch and merge Kalshi odds if available
+    if let Some(kalshi) = kalshi_client {
+        let kalshi_games = if use_cache && Path::new(kalshi_cache_file).exists() {
             println!(
-                "Loading Kalshi CFB odds from cache file: {}\n",
-                kalshi_cfb_cache
+                "Loading Kalshi {} odds from cache file: {}\n",
+                label, kalshi_cache_file
             );
-            load_from_cache(kalshi_cfb_cache)?
+            load_from_cache(kalshi_cache_file)?
         } else {
-            match kalshi.fetch_games(Sport::CollegeFootball).await {
+            match kalshi.fetch_games(sport).await {
                 Ok(games) => {
-                    save_to_cache(&games, kalshi_cfb_cache)?;
+                    save_to_cache(&games, kalshi_cache_file)?;
                     println!(
-                        "Saved Kalshi CFB odds to cache file: {}\n",
-                        kalshi_cfb_cache
+                        "Saved Kalshi {} odds to cache file: {}\n",
+                        label, kalshi_cache_file
                     );
                     games
                 }
                 Err(e) => {
-                    eprintln!("Warning: Failed to fetch Kalshi CFB odds: {}\n", e);
+                    eprintln!("Warning: Failed to fetch Kalshi {} odds: {}\n", label, e);
                     Vec::new()
                 }
             }
         };
 
-        if !kalshi_cfb_games.is_empty() {
+        if !kalshi_games.is_empty() {
             println!(
-                "Merging {} Kalshi CFB games with existing odds\n",
-                kalshi_cfb_games.len()
+                "Merging {} Kalshi {} games with existing odds\n",
+                kalshi_games.len(),
+                label
             );
-            merge_kalshi_odds(&mut cfb_games_with_odds, kalshi_cfb_games);
+            merge_kalshi_odds(&mut games_with_odds, kalshi_games);
         }
     }
 
-    // Fetch college basketball odds
-    let cbb_cache_file = "cache/cbb_odds_cache.json";
-    let mut cbb_games_with_odds = if use_cache && Path::new(cbb_cache_file).exists() {
-        println!("Loading CBB odds from cache file: {}\n", cbb_cache_file);
-        load_from_cache(cbb_cache_file)?
-    } else {
-        // Fetch odds from The Odds API
-        let games_with_odds = odds_client
-            .fetch_games(Sport::CollegeBasketball)
-            .await
-            .context("Failed to fetch CBB odds")?;
+    Ok(dedupe_games(games_with_odds))
+}
 
-        // Save to cache file
-        save_to_cache(&games_with_odds, cbb_cache_file)?;
-        println!("Saved CBB odds to cache file: {}\n", cbb_cache_file);
+/// Create the Odds API client and, if a key is configured, the Kalshi client
+fn create_odds_clients() -> Result<(OddsApiClient, Option<KalshiClient>)> {
+    // Get API key from environment
+    let api_key = required_env_var("ODDS_API_KEY")?;
+    let odds_client = OddsApiClient::new(api_key);
 
-        games_with_odds
-    };
+    // Optionally create Kalshi client if API key is available. Requests are signed
+    // when a private key is configured; otherwise only public market data is used.
+    let kalshi_client = kalshi_api_key().map(|key_id| match kalshi_private_key_path() {
+        Some(path) => match KalshiCredentials::from_file(key_id, &path) {
+            Ok(credentials) => KalshiClient::with_credentials(credentials),
+            Err(e) => {
+                warn!(error = %e, "Using an unauthenticated Kalshi client");
+                KalshiClient::new()
+            }
+        },
+        None => KalshiClient::new(),
+    });
 
-    // Fetch and merge Kalshi odds for CBB if available
-    if let Some(ref kalshi) = kalshi_client {
-        let kalshi_cbb_cache = "cache/kalshi_cbb_cache.json";
-        let kalshi_cbb_games = if use_cache && Path::new(kalshi_cbb_cache).exists() {
+    if kalshi_client.is_some() {
+        println!("Kalshi integration enabled\n");
+    }
+
+    Ok((odds_client, kalshi_client))
+}
+
+/// Print (and optionally save) the top moneyline and spread EV bets for CFB
+async fn report_ev_bets(
+    cli: &Cli,
+    cfb_games_with_odds: &[(Game, Vec<BettingOdds>)],
+    predictions: &[GamePrediction],
+) -> Result<()> {
+    let output_dir = Path::new(&cli.output_dir);
+
+    // Manual adjustments are applied before any EV is computed
+    let mut predictions = predictions.to_vec();
+    let adjustment_notes = match &cli.adjustments {
+        Some(path) => {
+            let adjustments = load_adjustments_from_csv(path)?;
             println!(
-                "Loading Kalshi CBB odds from cache file: {}\n",
-                kalshi_cbb_cache
+                "Applying {} prediction adjustments from {}\n",
+                adjustments.len(),
+                path
             );
-            load_from_cache(kalshi_cbb_cache)?
-        } else {
-            match kalshi.fetch_games(Sport::CollegeBasketball).await {
-                Ok(games) => {
-                    save_to_cache(&games, kalshi_cbb_cache)?;
-                    println!(
-                        "Saved Kalshi CBB odds to cache file: {}\n",
-                        kalshi_cbb_cache
-                    );
-                    games
-                }
-                Err(e) => {
-                    eprintln!("Warning: Failed to fetch Kalshi CBB odds: {}\n", e);
-                    Vec::new()
-                }
-            }
-        };
-
-        if !kalshi_cbb_games.is_empty() {
+            apply_adjustments(&mut predictions, &adjustments)
+        }
+        None => AdjustmentNotes::new(),
+    };
+    let boosts = match &cli.boosts {
+        Some(path) => {
+            let boosts = load_boosts_from_csv(path)?;
             println!(
-                "Merging {} Kalshi CBB games with existing odds\n",
-                kalshi_cbb_games.len()
+                "Pricing {} odds boosts from {}\n",
+                boosts.boosts.len(),
+                path
             );
-            merge_kalshi_odds(&mut cbb_games_with_odds, kalshi_cbb_games);
+            boosts
         }
-    }
+        None => BoostConfig::default(),
+    };
 
     // Find top moneyline EV bets (CFB only - requires predictions)
     println!("COLLEGE FOOTBALL\n");
-    println!("MONEYLINE BETS\n");
-    let moneyline_bets = match find_top_ev_bets(&cfb_games_with_odds, &predictions, Some(30)).await
+    let moneyline_result = match &cli.sharp_book {
+        Some(sharp_book) => {
+            println!("Model probabilities from de-vigged {} lines\n", sharp_book);
+            find_top_ev_bets_vs_sharp(
+                sharp_book,
+                cfb_games_with_odds,
+                cli.top_n(),
+                cli.max_books_per_bet(),
+                &cli.ev_filter(),
+            )
+            .await
+        }
+        None => {
+            find_top_ev_bets(
+                cfb_games_with_odds,
+                &predictions,
+                cli.top_n(),
+                cli.max_books_per_bet(),
+                &cli.ev_filter(),
+                &boosts,
+            )
+            .await
+        }
+    };
+    let mut moneyline_bets = match moneyline_result {
+        Ok(bets) => bets,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return Err(e.into());
+        }
+    };
+
+    // Find top spread EV bets
+    let mut spread_bets = match find_top_spread_ev_bets(
+        cfb_games_with_odds,
+        &predictions,
+        cli.top_n(),
+        cli.max_books_per_bet(),
+        &cli.ev_filter(),
+        SpreadModel {
+            margin_std_dev: cli.margin_std_dev(),
+            scraped_cover: cli.scraped_cover,
+        },
+    )
+    .await
     {
-        Ok(bets) => {
-            if bets.is_empty() {
-                println!("No positive EV moneyline bets found.");
-            } else {
-                println!("Top {} Moneyline EV Bets:\n", bets.len());
-                for (i, bet) in bets.iter().enumerate() {
-                    println!("{}. {}", i + 1, bet.format());
-                }
+        Ok(mut bets) => {
+            if cli.best_line {
+                bets = best_line_per_team(bets);
             }
             bets
         }
         Err(e) => {
-            eprintln!("Error: {}", e);
-            return Err(e);
+            eprintln!("Error fetching spread bets: {}", e);
+            // Don't return error - still show API usage
+            Vec::new()
         }
     };
 
-    if save_csv && !moneyline_bets.is_empty() {
-        save_moneyline_bets_to_csv(&moneyline_bets, "cache/moneyline_bets.csv")?;
-        println!("\nSaved moneyline bets to moneyline_bets.csv");
+    // Record which adjustments moved each number; de-vigged sharp lines don't use them
+    if cli.sharp_book.is_none() {
+        for bet in &mut moneyline_bets {
+            bet.adjustments =
+                adjustment_notes_for(&adjustment_notes, &bet.home_team, &bet.away_team);
+        }
+    }
+    for bet in &mut spread_bets {
+        bet.adjustments = adjustment_notes_for(&adjustment_notes, &bet.home_team, &bet.away_team);
+    }
+
+    // Both markets are sized together so bets on the same game share one exposure cap
+    if let Some(bankroll) = cli.bankroll {
+        let sizing = KellySizing {
+            bankroll,
+            kelly_multiplier: cli.kelly_multiplier,
+            max_game_exposure: cli.max_game_exposure / 100.0,
+        };
+        size_stakes(&mut moneyline_bets, &mut spread_bets, &sizing);
+        println!(
+            "Stakes: {}x Kelly on a ${:.2} bankroll, at most {:.1}% (${:.2}) per game\n",
+            sizing.kelly_multiplier,
+            bankroll,
+            cli.max_game_exposure,
+            bankroll * sizing.max_game_exposure
+        );
     }
 
-    // Find top spread EV bets
-    println!("\nSPREAD BETS\n");
-    let spread_bets =
-        match find_top_spread_ev_bets(&cfb_games_with_odds, &predictions, Some(30)).await {
-            Ok(bets) => {
-                if bets.is_empty() {
-                    println!("No positive EV spread bets found.");
-                } else {
-                    println!("Top {} Spread EV Bets:\n", bets.len());
-                    for (i, bet) in bets.iter().enumerate() {
-                        println!("{}. {}", i + 1, bet.format());
-                    }
-                }
-                bets
+    println!("MONEYLINE BETS\n");
+    if moneyline_bets.is_empty() {
+        println!("No positive EV moneyline bets found.");
+    } else {
+        println!("Top {} Moneyline EV Bets:\n", moneyline_bets.len());
+        if cli.format == OutputFormat::Markdown {
+            println!("{}", format_as_markdown(&moneyline_bets));
+        } else {
+            for (i, bet) in moneyline_bets.iter().enumerate() {
+                let stake = bet.stake.unwrap_or(cli.unit_size);
+                println!("{}. {}", i + 1, bet.format_with_unit(stake));
             }
-            Err(e) => {
-                eprintln!("Error fetching spread bets: {}", e);
-                // Don't return error - still show API usage
-                Vec::new()
+        }
+    }
+
+    if cli.save_csv && !moneyline_bets.is_empty() {
+        let path = output_dir.join("moneyline_bets.csv");
+        save_moneyline_bets_to_csv(&moneyline_bets, &path.to_string_lossy(), cli.unit_size)?;
+        println!("\nSaved moneyline bets to {}", path.display());
+    }
+
+    println!("\nSPREAD BETS\n");
+    if spread_bets.is_empty() {
+        println!("No positive EV spread bets found.");
+    } else {
+        println!("Top {} Spread EV Bets:\n", spread_bets.len());
+        if cli.format == OutputFormat::Markdown {
+            println!("{}", format_spread_bets_as_markdown(&spread_bets));
+        } else {
+            for (i, bet) in spread_bets.iter().enumerate() {
+                let stake = bet.stake.unwrap_or(cli.unit_size);
+                println!("{}. {}", i + 1, bet.format_with_unit(stake));
             }
-        };
+        }
+    }
 
-    if save_csv && !spread_bets.is_empty() {
-        save_spread_bets_to_csv(&spread_bets, "cache/spread_bets.csv")?;
-        println!("\nSaved spread bets to spread_bets.csv");
+    if cli.save_csv && !spread_bets.is_empty() {
+        let path = output_dir.join("spread_bets.csv");
+        save_spread_bets_to_csv(&spread_bets, &path.to_string_lossy(), cli.unit_size)?;
+        println!("\nSaved spread bets to {}", path.display());
     }
 
-    // Find arbitrage opportunities for CFB
-    println!("\nCFB ARBITRAGE OPPORTUNITIES\n");
+    Ok(())
+}
+
+/// Print (and optionally save) CBB moneyline value bets
+/// With no basketball model, each book is judged against the de-vigged market consensus.
+async fn report_market_value_bets(
+    cli: &Cli,
+    cbb_games_with_odds: &[(Game, Vec<BettingOdds>)],
+) -> Result<()> {
+    println!("\nMARKET VALUE BETS (vs de-vigged consensus)\n");
+    let bets = find_market_value_bets(
+        cbb_games_with_odds,
+        cli.top_n(),
+        cli.max_books_per_bet(),
+        &cli.ev_filter(),
+    )
+    .await?;
+
+    if bets.is_empty() {
+        println!("No CBB moneyline prices beat the market consensus.");
+        return Ok(());
+    }
+
+    println!("Top {} CBB Moneyline Value Bets:\n", bets.len());
+    if cli.format == OutputFormat::Markdown {
+        println!("{}", format_as_markdown(&bets));
+    } else {
+        for (i, bet) in bets.iter().enumerate() {
+            println!("{}. {}", i + 1, bet.format_with_unit(cli.unit_size));
+        }
+    }
+
+    if cli.save_csv {
+        let path = Path::new(&cli.output_dir).join("cbb_moneyline_bets.csv");
+        save_moneyline_bets_to_csv(&bets, &path.to_string_lossy(), cli.unit_size)?;
+        println!("\nSaved CBB moneyline bets to {}", path.display());
+    }
+
+    Ok(())
+}
+
+/// Print (and optionally save) moneyline and spread arbitrage opportunities for a sport
+fn report_arbitrage(
+    cli: &Cli,
+    sport: Sport,
+    games_with_odds: &[(Game, Vec<BettingOdds>)],
+) -> Result<()> {
+    let output_dir = Path::new(&cli.output_dir);
+    let (label, prefix) = match sport {
+        Sport::CollegeFootball => ("CFB", "cfb"),
+        Sport::CollegeBasketball => ("CBB", "cbb"),
+    };
+    let arb_filter = cli.arb_filter();
+    let games_with_odds = &arb_filter.screen(games_with_odds);
+
+    println!("\n{} ARBITRAGE OPPORTUNITIES\n", label);
 
     println!("MONEYLINE ARBITRAGE\n");
-    let cfb_moneyline_arbs = find_moneyline_arbitrage(&cfb_games_with_odds)?;
-    if cfb_moneyline_arbs.is_empty() {
-        println!("No CFB moneyline arbitrage opportunities found.");
+    let mut moneyline_arbs = find_moneyline_arbitrage(games_with_odds, &arb_filter)?;
+    moneyline_arbs.retain(|arb| !arb.uses_any_bookmaker(&cli.exclude_book));
+    if moneyline_arbs.is_empty() {
+        println!("No {} moneyline arbitrage opportunities found.", label);
     } else {
         println!(
-            "Found {} CFB Moneyline Arbitrage Opportunities:\n",
-            cfb_moneyline_arbs.len()
+            "Found {} {} Moneyline Arbitrage Opportunities:\n",
+            moneyline_arbs.len(),
+            label
         );
-        for (i, arb) in cfb_moneyline_arbs.iter().enumerate() {
-            println!("{}. {}", i + 1, arb.format());
+        if cli.format == OutputFormat::Markdown {
+            println!("{}", format_moneyline_arbs_as_markdown(&moneyline_arbs));
+        } else {
+            for (i, arb) in moneyline_arbs.iter().enumerate() {
+                println!("{}. {}", i + 1, arb.format());
+                let rounded = round_to_increment(arb, cli.arb_stake, cli.stake_increment);
+                println!("   {}", rounded.format());
+            }
+        }
+
+        let kalshi_arb_count = moneyline_arbs
+            .iter()
+            .filter(|arb| arb.involves_kalshi())
+            .count();
+        if kalshi_arb_count > 0 {
+            println!(
+                "\n{} of these are Kalshi vs sportsbook arbitrage",
+                kalshi_arb_count
+            );
         }
     }
 
-    if save_csv && !cfb_moneyline_arbs.is_empty() {
-        save_moneyline_arbitrage_to_csv(&cfb_moneyline_arbs, "cache/cfb_moneyline_arbitrage.csv")?;
-        println!("\nSaved CFB moneyline arbitrage to cfb_moneyline_arbitrage.csv");
+    if cli.save_csv && !moneyline_arbs.is_empty() {
+        let path = output_dir.join(format!("{}_moneyline_arbitrage.csv", prefix));
+        save_moneyline_arbitrage_to_csv(&moneyline_arbs, &path.to_string_lossy())?;
+        println!(
+            "\nSaved {} moneyline arbitrage to {}",
+            label,
+            path.display()
+        );
     }
 
     println!("\nSPREAD ARBITRAGE\n");
-    let cfb_spread_arbs = find_spread_arbitrage(&cfb_games_with_odds)?;
-    if cfb_spread_arbs.is_empty() {
-        println!("No CFB spread arbitrage opportunities found.");
+    let mut spread_arbs = find_spread_arbitrage(games_with_odds, &arb_filter)?;
+    spread_arbs.retain(|arb| !arb.uses_any_bookmaker(&cli.exclude_book));
+    if spread_arbs.is_empty() {
+        println!("No {} spread arbitrage opportunities found.", label);
     } else {
         println!(
-            "Found {} CFB Spread Arbitrage Opportunities:\n",
-            cfb_spread_arbs.len()
+            "Found {} {} Spread Arbitrage Opportunities:\n",
+            spread_arbs.len(),
+            label
         );
-        for (i, arb) in cfb_spread_arbs.iter().enumerate() {
-            println!("{}. {}", i + 1, arb.format());
+        if cli.format == OutputFormat::Markdown {
+            println!("{}", format_spread_arbs_as_markdown(&spread_arbs));
+        } else {
+            for (i, arb) in spread_arbs.iter().enumerate() {
+                println!("{}. {}", i + 1, arb.format());
+                let rounded = round_spread_to_increment(arb, cli.arb_stake, cli.stake_increment);
+                println!("   {}", rounded.format());
+            }
         }
     }
 
-    if save_csv && !cfb_spread_arbs.is_empty() {
-        save_spread_arbitrage_to_csv(&cfb_spread_arbs, "cache/cfb_spread_arbitrage.csv")?;
-        println!("\nSaved CFB spread arbitrage to cfb_spread_arbitrage.csv");
+    if cli.save_csv && !spread_arbs.is_empty() {
+        let path = output_dir.join(format!("{}_spread_arbitrage.csv", prefix));
+        save_spread_arbitrage_to_csv(&spread_arbs, &path.to_string_lossy())?;
+        println!("\nSaved {} spread arbitrage to {}", label, path.display());
     }
 
-    // Find arbitrage opportunities for CBB
-    println!("\nCOLLEGE BASKETBALL\n");
-    println!("CBB ARBITRAGE OPPORTUNITIES\n");
+    println!("\nSPREAD MIDDLES\n");
+    let mut middles = find_spread_middles(games_with_odds, &arb_filter)?;
+    middles.retain(|middle| !middle.uses_any_bookmaker(&cli.exclude_book));
+    if middles.is_empty() {
+        println!("No positive EV {} spread middles found.", label);
+    } else {
+        println!("Found {} {} Spread Middles:\n", middles.len(), label);
+        for (i, middle) in middles.iter().enumerate() {
+            println!("{}. {}", i + 1, middle.format());
+        }
+    }
 
-    println!("MONEYLINE ARBITRAGE\n");
-    let cbb_moneyline_arbs = find_moneyline_arbitrage(&cbb_games_with_odds)?;
-    if cbb_moneyline_arbs.is_empty() {
-        println!("No CBB moneyline arbitrage opportunities found.");
+    Ok(())
+}
+
+/// Fetch all betting data and post the best opportunities to the `WEBHOOK_URL` webhook
+async fn notify_webhook(cli: &Cli, config: &WebhookConfig) -> Result<()> {
+    let url = required_env_var("WEBHOOK_URL")?;
+    let data = cfb_betting_ev::fetch_all_betting_data(cli.use_cache, &cli.ev_filter(), cli.top_n())
+        .await?;
+
+    let posted = post_to_webhook(&url, &data, config).await?;
+    if posted == 0 {
+        println!("No opportunities above the alert thresholds; nothing posted.");
+    } else {
+        println!("Posted {} opportunities to the webhook.", posted);
+    }
+    Ok(())
+}
+
+/// Resolve the year and week to use, defaulting to the current ones
+fn year_and_week(year: Option<u32>, week: Option<u8>) -> (u32, u8) {
+    let now = Local::now();
+    (
+        year.unwrap_or(now.year() as u32),
+        week.unwrap_or(now.iso_week().week() as u8),
+    )
+}
+
+/// Fetch CFB game results for a week from the College Football Data API
+async fn fetch_game_results(year: u32, week: u8) -> Result<Vec<GameResult>> {
+    println!("Fetching game results for week {} of {}...\n", week, year);
+
+    let cfb_api_key = required_env_var("COLLEGE_FOOTBALL_DATA_API_KEY")?;
+    let cfb_client = GameResultsApiClient::new(cfb_api_key);
+    cfb_client
+        .fetch_cfb_game_results(year, week)
+        .await
+        .context("Failed to fetch CFB game results")
+}
+
+/// Fetch CFB game results for weeks `from_week` through `week`
+async fn fetch_game_results_range(year: u32, from_week: u8, week: u8) -> Result<Vec<GameResult>> {
+    println!(
+        "Fetching game results for weeks {}-{} of {}...\n",
+        from_week, week, year
+    );
+
+    let cfb_api_key = required_env_var("COLLEGE_FOOTBALL_DATA_API_KEY")?;
+    let cfb_client = GameResultsApiClient::new(cfb_api_key);
+    cfb_client
+        .fetch_cfb_game_results_range(year, from_week, week)
+        .await
+        .context("Failed to fetch CFB game results")
+}
+
+/// Print CFB game results for a week
+async fn report_results(cli: &Cli, year: Option<u32>, week: Option<u8>) -> Result<()> {
+    let (year, week) = year_and_week(year, week);
+    let mut game_results = fetch_game_results(year, week).await?;
+    game_results.retain(|result| cli.includes_game(&result.home_team, &result.away_team));
+
+    if game_results.is_empty() {
+        println!("No game results found.");
+        return Ok(());
+    }
+
+    println!("Found {} games:\n", game_results.len());
+    for (i, result) in game_results.iter().enumerate() {
+        match (result.completed, result.away_points, result.home_points) {
+            (true, Some(away_points), Some(home_points)) => println!(
+                "{}. {} {} @ {} {}",
+                i + 1,
+                result.away_team,
+                away_points,
+                result.home_team,
+                home_points
+            ),
+            _ => println!(
+                "{}. {} @ {} | Not completed",
+                i + 1,
+                result.away_team,
+                result.home_team
+            ),
+        }
+    }
+
+    Ok(())
+}
+
+/// Grade saved bet recommendations against actual game results, returning the graded bets
+async fn grade_bets(
+    moneyline_csv: &str,
+    spread_csv: &str,
+    year: Option<u32>,
+    week: Option<u8>,
+    from_week: Option<u8>,
+    closing_odds: &[String],
+) -> Result<(Vec<BetResult>, Vec<SpreadBetResult>)> {
+    println!("Comparing bet recommendations with game results...\n");
+
+    // Get year and week (default to current if not specified)
+    let (year, week) = year_and_week(year, week);
+
+    // Fetch game results
+    let game_results = match from_week {
+        Some(from_week) => fetch_game_results_range(year, from_week, week).await?,
+        None => fetch_game_results(year, week).await?,
+    };
+
+    let completed = game_results
+        .iter()
+        .filter(|result| result.completed)
+        .count();
+    println!("Fetched {} completed games\n", completed);
+
+    // Load bets from CSV files
+    let moneyline_bets = if Path::new(moneyline_csv).exists() {
+        println!("Loading moneyline bets from {}...", moneyline_csv);
+        load_moneyline_bets_from_csv(moneyline_csv)?
     } else {
         println!(
-            "Found {} CBB Moneyline Arbitrage Opportunities:\n",
-            cbb_moneyline_arbs.len()
+            "Moneyline CSV file not found: {}. Skipping moneyline comparison.",
+            moneyline_csv
         );
-        for (i, arb) in cbb_moneyline_arbs.iter().enumerate() {
-            println!("{}. {}", i + 1, arb.format());
+        Vec::new()
+    };
+
+    let spread_bets = if Path::new(spread_csv).exists() {
+        println!("Loading spread bets from {}...", spread_csv);
+        load_spread_bets_from_csv(spread_csv)?
+    } else {
+        println!(
+            "Spread CSV file not found: {}. Skipping spread comparison.",
+            spread_csv
+        );
+        Vec::new()
+    };
+
+    let mut snapshots = closing_odds
+        .iter()
+        .map(|file| load_from_cache(file).map(OddsSnapshot::from_games))
+        .collect::<Result<Vec<_>, _>>()?;
+    // Odds captured by the `closing` command count too
+    if Path::new(CLOSING_ODDS_FILE).exists() {
+        snapshots.extend(load_all_snapshots(CLOSING_ODDS_FILE)?);
+    }
+    let closing = closing_lines(&snapshots);
+
+    // Compare bets with results
+    let mut bet_results = compare_ev_bets_to_results(&moneyline_bets, &game_results);
+    add_closing_lines(&mut bet_results, &closing);
+    if !moneyline_bets.is_empty() {
+        println!("\n=== MONEYLINE BET RESULTS ===\n");
+
+        for (i, result) in bet_results.iter().enumerate() {
+            println!("{}. {}", i + 1, result.format());
+        }
+
+        let summary = GradingSummary::from_graded(
+            bet_results
+                .iter()
+                .filter_map(|result| Some((result.stake, result.profit()?))),
+        );
+        if summary.bets() > 0 {
+            println!("\n--- Moneyline Summary ---");
+            print_grading_summary(&summary);
+            print_beat_close(bet_results.iter().map(|result| result.beat_close));
         }
     }
 
-    if save_csv && !cbb_moneyline_arbs.is_empty() {
-        save_moneyline_arbitrage_to_csv(&cbb_moneyline_arbs, "cache/cbb_moneyline_arbitrage.csv")?;
-        println!("\nSaved CBB moneyline arbitrage to cbb_moneyline_arbitrage.csv");
+    let mut spread_results = compare_spread_ev_bets_to_results(&spread_bets, &game_results);
+    add_spread_closing_lines(&mut spread_results, &closing);
+    if !spread_bets.is_empty() {
+        println!("\n=== SPREAD BET RESULTS ===\n");
+
+        for (i, result) in spread_results.iter().enumerate() {
+            println!("{}. {}", i + 1, result.format());
+        }
+
+        let summary = GradingSummary::from_graded(
+            spread_results
+                .iter()
+                .filter_map(|result| Some((result.stake, result.profit()?))),
+        );
+        if summary.bets() > 0 {
+            println!("\n--- Spread Summary ---");
+            print_grading_summary(&summary);
+            print_beat_close(spread_results.iter().map(|result| result.beat_close));
+        }
     }
 
-    println!("\nSPREAD ARBITRAGE\n");
-    let cbb_spread_arbs = find_spread_arbitrage(&cbb_games_with_odds)?;
-    if cbb_spread_arbs.is_empty() {
-        println!("No CBB spread arbitrage opportunities found.");
-    } else {
+    Ok((bet_results, spread_results))
+}
+
+/// Add graded bets to the season ledger and print its running totals
+fn record_in_ledger(
+    path: &str,
+    bet_results: &[BetResult],
+    spread_results: &[SpreadBetResult],
+    unit_size: f64,
+) -> Result<()> {
+    let ledger = update_ledger(path, bet_results, spread_results, unit_size)?;
+    let (longest_win, longest_loss) = ledger.longest_streaks();
+    println!("\n=== SEASON LEDGER ({}) ===\n", path);
+    println!(
+        "Record: {}-{} | Units: {:+.2} | ROI: {:+.1}%",
+        ledger.wins(),
+        ledger.losses(),
+        ledger.total_units(),
+        ledger.roi() * 100.0
+    );
+    println!(
+        "Longest win streak: {} | Longest losing streak: {}",
+        longest_win, longest_loss
+    );
+    for week in ledger.weeks() {
         println!(
-            "Found {} CBB Spread Arbitrage Opportunities:\n",
-            cbb_spread_arbs.len()
+            "  {} Week {:>2}: {}-{}  {:+.2}u",
+            week.season, week.week, week.wins, week.losses, week.units
         );
-        for (i, arb) in cbb_spread_arbs.iter().enumerate() {
-            println!("{}. {}", i + 1, arb.format());
+    }
+    Ok(())
+}
+
+/// Print the record and stake-weighted return for graded bets
+fn print_grading_summary(summary: &GradingSummary) {
+    let bets = summary.bets() as f64;
+    println!("Total Bets Resolved: {}", summary.bets());
+    println!(
+        "Wins: {} ({:.1}%)",
+        summary.wins,
+        summary.wins as f64 / bets * 100.0
+    );
+    println!(
+        "Losses: {} ({:.1}%)",
+        summary.losses,
+        summary.losses as f64 / bets * 100.0
+    );
+    println!("Total Staked: ${:.2}", summary.total_staked);
+    println!("Net Profit: ${:.2}", summary.net_profit);
+    println!("ROI: {:.2}%", summary.roi() * 100.0);
+}
+
+/// Print how many bets beat the closing line, if any had closing odds
+fn print_beat_close(beat_close: impl Iterator<Item = Option<bool>>) {
+    let (beat, with_close) = beat_close.flatten().fold((0, 0), |(beat, total), won| {
+        (beat + won as usize, total + 1)
+    });
+    if with_close > 0 {
+        println!(
+            "Beat the Close: {} of {} ({:.1}%)",
+            beat,
+            with_close,
+            beat as f64 / with_close as f64 * 100.0
+        );
+    }
+}
+
+/// Print per-book line moves between two odds cache snapshots
+fn report_line_movement(cli: &Cli, before: &str, after: &str) -> Result<()> {
+    let before_games: Vec<(Game, Vec<BettingOdds>)> = load_from_cache(before)?;
+    let after_games: Vec<(Game, Vec<BettingOdds>)> = load_from_cache(after)?;
+
+    let moves = compare_snapshots(&before_games, &after_games);
+    if moves.is_empty() {
+        println!("No matching games between {} and {}", before, after);
+        return Ok(());
+    }
+
+    println!("Line moves from {} to {}:\n", before, after);
+    for (i, line_move) in moves
+        .iter()
+        .take(cli.top_n().unwrap_or(usize::MAX))
+        .enumerate()
+    {
+        println!("{}. {}", i + 1, line_move.format());
+    }
+
+    Ok(())
+}
+
+/// Report games whose Prediction Tracker line has moved from the open, largest first
+async fn report_steam_moves(cli: &Cli, min_move: f64) -> Result<()> {
+    let predictions = load_predictions(cli.use_cache).await?;
+    let mut moves = find_steam_moves(&predictions, min_move);
+    moves.retain(|steam| cli.includes_game(&steam.home_team, &steam.away_team));
+
+    if moves.is_empty() {
+        println!("No lines have moved {:.1}+ points from the open", min_move);
+        return Ok(());
+    }
+
+    println!("Lines moved {:.1}+ points from the open:\n", min_move);
+    for (i, steam) in moves
+        .iter()
+        .take(cli.top_n().unwrap_or(usize::MAX))
+        .enumerate()
+    {
+        println!("{}. {}", i + 1, steam.format());
+    }
+
+    Ok(())
+}
+
+/// Report bookmakers whose moneyline and spread on a game disagree
+async fn report_anomalies(cli: &Cli, max_gap: f64) -> Result<()> {
+    let (odds_client, _) = create_odds_clients()?;
+    let games_with_odds = load_odds(
+        &odds_client,
+        None,
+        Sport::CollegeFootball,
+        cli.use_cache,
+        false,
+    )
+    .await?;
+    let mut anomalies = find_data_anomalies(&games_with_odds, max_gap);
+    anomalies.retain(|anomaly| cli.includes_game(&anomaly.home_team, &anomaly.away_team));
+
+    if anomalies.is_empty() {
+        println!("Every book's moneyline and spread agree");
+        return Ok(());
+    }
+
+    println!(
+        "{} books with a moneyline and spread that disagree:\n",
+        anomalies.len()
+    );
+    for (i, anomaly) in anomalies.iter().enumerate() {
+        println!("{}. {}", i + 1, anomaly.format());
+    }
+
+    Ok(())
+}
+
+/// Backtest the moneyline EV model against saved odds snapshots and game results
+fn report_backtest(cli: &Cli, odds: &[String], predictions: &str, results: &str) -> Result<()> {
+    let snapshots = odds
+        .iter()
+        .map(|file| load_from_cache(file).map(OddsSnapshot::from_games))
+        .collect::<Result<Vec<_>, _>>()?;
+    let predictions: Vec<GamePrediction> = load_from_cache(predictions)?;
+    let results: Vec<GameResult> = load_from_cache(results)?;
+
+    let staking = StakingConfig {
+        unit_size: cli.unit_size,
+        min_ev: cli.min_ev / 100.0,
+    };
+    let report = backtest(&snapshots, &predictions, &results, staking);
+
+    println!(
+        "Backtest over {} odds snapshots (${:.2} per bet):\n",
+        snapshots.len(),
+        cli.unit_size
+    );
+    println!("{}", report.format());
+
+    Ok(())
+}
+
+/// List upcoming CFB games from the Odds API events endpoint
+async fn report_events(cli: &Cli) -> Result<()> {
+    let odds_client = OddsApiClient::new(required_env_var("ODDS_API_KEY")?);
+    let mut games = odds_client
+        .fetch_events(Sport::CollegeFootball)
+        .await
+        .context("Failed to fetch CFB events")?;
+    games.retain(|game| cli.includes_game(&game.home_team, &game.away_team));
+    games.sort_by_key(|game| game.commence_time);
+
+    println!("{} upcoming CFB games:\n", games.len());
+    let timezone = display_timezone();
+    for game in &games {
+        println!(
+            "{} | {} @ {} | {}",
+            format_kickoff(game.commence_time, timezone),
+            game.away_team,
+            game.home_team,
+            game.id
+        );
+    }
+
+    Ok(())
+}
+
+/// Capture closing odds for the upcoming CFB games (within --conference), running until
+/// the last one kicks off
+async fn capture_closing(cli: &Cli, lead_minutes: i64) -> Result<()> {
+    let odds_client = OddsApiClient::new(required_env_var("ODDS_API_KEY")?);
+    let mut games = odds_client
+        .fetch_events(Sport::CollegeFootball)
+        .await
+        .context("Failed to fetch CFB events")?;
+    games.retain(|game| cli.includes_game(&game.home_team, &game.away_team));
+
+    let lead = chrono::Duration::minutes(lead_minutes);
+    let batches = capture_batches(&games, lead, Utc::now());
+    let estimate: u32 = batches.iter().map(CaptureBatch::estimated_cost).sum();
+    println!(
+        "Capturing closing odds for {} CFB games at {} kickoff times, {} minutes before each",
+        games.len(),
+        batches.len(),
+        lead_minutes
+    );
+    println!("Estimated Odds API cost: {} credits\n", estimate);
+    let limit = confirm_above_credits();
+    if estimate > limit && !cli.confirm {
+        anyhow::bail!(
+            "capturing this slate is estimated to cost {} Odds API credits, more than CONFIRM_ABOVE_CREDITS ({}); rerun with --confirm to go ahead",
+            estimate,
+            limit
+        );
+    }
+
+    let timezone = display_timezone();
+    for batch in &batches {
+        println!(
+            "{} | {} game(s)",
+            format_kickoff(batch.capture_at, timezone),
+            batch.event_ids.len()
+        );
+    }
+
+    let captured = capture_closing_lines(
+        &odds_client,
+        Sport::CollegeFootball,
+        &games,
+        lead,
+        CLOSING_ODDS_FILE,
+    )
+    .await?;
+    println!(
+        "\nCaptured closing odds for {} games to {}",
+        captured, CLOSING_ODDS_FILE
+    );
+    Ok(())
+}
+
+/// Check remaining API usage for the Odds API and/or College Football Data API
+async fn check_usage(odds: bool, cfb_data: bool) -> Result<()> {
+    // If no flags are provided, check both by default
+    let check_odds = odds || !cfb_data;
+    let check_cfb = cfb_data || !odds;
+
+    if check_odds {
+        let odds_api_key = required_env_var("ODDS_API_KEY")?;
+        let odds_client = OddsApiClient::new(odds_api_key);
+        println!("Checking Odds API usage...\n");
+        odds_client.check_usage().await?;
+        println!();
+    }
+
+    if check_cfb {
+        let cfb_api_key = required_env_var("COLLEGE_FOOTBALL_DATA_API_KEY")?;
+        let cfb_client = GameResultsApiClient::new(cfb_api_key);
+        println!("Checking College Football Data API usage...\n");
+        cfb_client.check_usage().await?;
+    }
+
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // Load environment variables from .env (and the profile's file) before parsing, so they
+    // can fill in argument defaults
+    load_env(profile_arg(std::env::args().skip(1)).as_deref())?;
+    let cli = Cli::parse();
+
+    // Initialize logging
+    cfb_betting_ev::init_logging();
+
+    if cli.save_csv {
+        std::fs::create_dir_all(&cli.output_dir).context("Failed to create output directory")?;
+    }
+
+    match &cli.command {
+        Some(Commands::Usage { odds, cfb_data }) => return check_usage(*odds, *cfb_data).await,
+        Some(Commands::Events) => return report_events(&cli).await,
+        Some(Commands::Closing { lead_minutes }) => {
+            return capture_closing(&cli, *lead_minutes).await
+        }
+        Some(Commands::Grade {
+            moneyline_csv,
+            spread_csv,
+            year,
+            week,
+            from_week,
+            closing_odds,
+            ledger,
+        }) => {
+            let (bet_results, spread_results) = grade_bets(
+                moneyline_csv,
+                spread_csv,
+                *year,
+                *week,
+                *from_week,
+                closing_odds,
+            )
+            .await?;
+            if let Some(ledger) = ledger {
+                record_in_ledger(ledger, &bet_results, &spread_results, cli.unit_size)?;
+            }
+            return Ok(());
+        }
+        Some(Commands::Results { year, week }) => return report_results(&cli, *year, *week).await,
+        Some(Commands::Movement { before, after }) => {
+            return report_line_movement(&cli, before, after)
+        }
+        Some(Commands::Steam { min_move }) => return report_steam_moves(&cli, *min_move).await,
+        Some(Commands::Anomalies { max_gap }) => return report_anomalies(&cli, *max_gap).await,
+        Some(Commands::Backtest {
+            odds,
+            predictions,
+            results,
+        }) => return report_backtest(&cli, odds, predictions, results),
+        Some(Commands::Notify {
+            limit,
+            alert_ev,
+            alert_profit,
+        }) => {
+            let config = WebhookConfig {
+                top_n: *limit,
+                min_ev: alert_ev / 100.0,
+                min_arb_profit: *alert_profit,
+            };
+            return notify_webhook(&cli, &config).await;
+        }
+        Some(Commands::Ev) | Some(Commands::Arb) | Some(Commands::Analyze) | None => {}
+    }
+
+    // Predictions are pregame, so live mode only looks for arbitrage
+    let run_ev = !matches!(cli.command, Some(Commands::Arb)) && !cli.live;
+    let run_arb = !matches!(cli.command, Some(Commands::Ev));
+    if !run_ev && !run_arb {
+        println!("EV bets are pregame only; use `arb --live` for in-play arbitrage.");
+        return Ok(());
+    }
+
+    println!("College Football Betting EV Calculator\n");
+    check_fetch_cost(&cli)?;
+    println!("Fetching betting odds and model data...\n");
+
+    let (odds_client, kalshi_client) = create_odds_clients()?;
+
+    // EV analysis needs predictions, which only exist for CFB
+    if cli.sport.includes_cfb() {
+        // Predictions and odds are independent, so fetch them concurrently
+        let (predictions, mut cfb_games_with_odds) = tokio::try_join!(
+            async {
+                if run_ev {
+                    load_predictions(cli.use_cache).await
+                } else {
+                    Ok(Vec::new())
+                }
+            },
+            load_odds(
+                &odds_client,
+                kalshi_client.as_ref(),
+                Sport::CollegeFootball,
+                cli.use_cache,
+                cli.live,
+            ),
+        )?;
+        if cli.next_game_day {
+            retain_next_game_day(&mut cfb_games_with_odds, display_timezone());
+        }
+
+        if run_ev {
+            report_ev_bets(&cli, &cfb_games_with_odds, &predictions).await?;
+        }
+        if run_arb {
+            report_arbitrage(&cli, Sport::CollegeFootball, &cfb_games_with_odds)?;
         }
     }
 
-    if save_csv && !cbb_spread_arbs.is_empty() {
-        save_spread_arbitrage_to_csv(&cbb_spread_arbs, "cache/cbb_spread_arbitrage.csv")?;
-        println!("\nSaved CBB spread arbitrage to cbb_spread_arbitrage.csv");
+    if cli.sport.includes_cbb() {
+        let mut cbb_games_with_odds = load_odds(
+            &odds_client,
+            kalshi_client.as_ref(),
+            Sport::CollegeBasketball,
+            cli.use_cache,
+            cli.live,
+        )
+        .await?;
+        if cli.next_game_day {
+            retain_next_game_day(&mut cbb_games_with_odds, display_timezone());
+        }
+
+        println!("\nCOLLEGE BASKETBALL");
+        if run_ev {
+            report_market_value_bets(&cli, &cbb_games_with_odds).await?;
+        }
+        if run_arb {
+            report_arbitrage(&cli, Sport::CollegeBasketball, &cbb_games_with_odds)?;
+        }
     }
 
     // Check API usage
@@ -0,0 +1,512 @@
+use anyhow::Result;
+use cfb_betting_ev::config::{ev_filter, load_env, profile_arg, use_cache};
+use cfb_betting_ev::{
+    display_timezone, fetch_all_betting_data, format_kickoff, top_n_from_env, BettingData,
+    BETTING_DATA_FILE,
+};
+use chrono::{DateTime, Utc};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Modifier, Style, Stylize};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Cell, Paragraph, Row, Table, TableState, Wrap};
+use ratatui::{DefaultTerminal, Frame};
+use std::cmp::Ordering;
+use std::path::Path;
+
+/// Rows moved by PageUp/PageDown
+const PAGE_ROWS: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Sport {
+    Cfb,
+    Cbb,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Market {
+    Moneyline,
+    Spread,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum View {
+    EvBets,
+    Arbitrage,
+}
+
+impl View {
+    /// Column sorted on when the view is opened: EV for bets, profit for arbitrage
+    fn default_sort_column(self) -> usize {
+        match self {
+            View::EvBets => 4,
+            View::Arbitrage => 3,
+        }
+    }
+}
+
+/// What a column sorts by
+#[derive(Debug, Clone)]
+enum SortKey {
+    Number(f64),
+    Text(String),
+}
+
+impl SortKey {
+    fn compare(&self, other: &SortKey) -> Ordering {
+        match (self, other) {
+            (SortKey::Number(a), SortKey::Number(b)) => a.total_cmp(b),
+            (SortKey::Text(a), SortKey::Text(b)) => a.cmp(b),
+            (SortKey::Number(_), SortKey::Text(_)) => Ordering::Less,
+            (SortKey::Text(_), SortKey::Number(_)) => Ordering::Greater,
+        }
+    }
+}
+
+/// One table row: the text shown, a sort key per column, and the full description shown
+/// for the selected row
+struct Listing {
+    cells: Vec<String>,
+    keys: Vec<SortKey>,
+    detail: String,
+}
+
+/// A text column that sorts alphabetically
+fn text(value: String) -> (String, SortKey) {
+    (value.clone(), SortKey::Text(value.to_lowercase()))
+}
+
+/// A percentage column, given as a fraction or already in percent
+fn percent(value: f64, in_percent: bool) -> (String, SortKey) {
+    let value = if in_percent { value } else { value * 100.0 };
+    (format!("{:+.2}%", value), SortKey::Number(value))
+}
+
+/// Kickoff in the display timezone, sorting unknown times last
+fn kickoff(commence_time: Option<DateTime<Utc>>) -> (String, SortKey) {
+    match commence_time {
+        Some(time) => (
+            format_kickoff(time, display_timezone()),
+            SortKey::Number(time.timestamp() as f64),
+        ),
+        None => ("-".to_string(), SortKey::Number(f64::MAX)),
+    }
+}
+
+/// Minutes since the odds were updated, when known
+fn age(age_minutes: Option<i64>, likely_gone: bool) -> (String, SortKey) {
+    match age_minutes {
+        Some(age) if likely_gone => (format!("{}m (gone?)", age), SortKey::Number(age as f64)),
+        Some(age) => (format!("{}m", age), SortKey::Number(age as f64)),
+        None => ("-".to_string(), SortKey::Number(f64::MAX)),
+    }
+}
+
+fn listing(columns: Vec<(String, SortKey)>, detail: String) -> Listing {
+    let (cells, keys) = columns.into_iter().unzip();
+    Listing {
+        cells,
+        keys,
+        detail,
+    }
+}
+
+/// Column headers and rows for one sport, market and view
+fn listings(
+    data: &BettingData,
+    sport: Sport,
+    market: Market,
+    view: View,
+) -> (&'static [&'static str], Vec<Listing>) {
+    let matchup = |away: &str, home: &str| text(format!("{} @ {}", away, home));
+
+    match (view, market) {
+        (View::EvBets, Market::Moneyline) => {
+            let bets = match sport {
+                Sport::Cfb => &data.cfb_moneyline_bets,
+                Sport::Cbb => &data.cbb_moneyline_bets,
+            };
+            let rows = bets
+                .iter()
+                .map(|bet| {
+                    listing(
+                        vec![
+                            matchup(&bet.away_team, &bet.home_team),
+                            text(bet.team.clone()),
+                            text(bet.bookmaker.clone()),
+                            (format!("{:+}", bet.odds), SortKey::Number(bet.odds as f64)),
+                            percent(bet.expected_value, false),
+                            percent(bet.edge, false),
+                            percent(bet.model_prob, false),
+                            kickoff(bet.commence_time),
+                        ],
+                        bet.format(),
+                    )
+                })
+                .collect();
+            (
+                &[
+                    "Game", "Bet", "Book", "Odds", "EV", "Edge", "Model", "Kickoff",
+                ],
+                rows,
+            )
+        }
+        (View::EvBets, Market::Spread) => {
+            // There's no CBB spread model, so CBB has no spread EV bets
+            let bets = match sport {
+                Sport::Cfb => data.cfb_spread_bets.as_slice(),
+                Sport::Cbb => &[],
+            };
+            let rows = bets
+                .iter()
+                .map(|bet| {
+                    listing(
+                        vec![
+                            matchup(&bet.away_team, &bet.home_team),
+                            text(format!("{} {:+.1}", bet.team, bet.spread_line)),
+                            text(bet.bookmaker.clone()),
+                            (format!("{:+}", bet.odds), SortKey::Number(bet.odds as f64)),
+                            percent(bet.expected_value, false),
+                            percent(bet.edge, false),
+                            percent(bet.model_prob, false),
+                            kickoff(bet.commence_time),
+                        ],
+                        bet.format(),
+                    )
+                })
+                .collect();
+            (
+                &[
+                    "Game", "Bet", "Book", "Odds", "EV", "Edge", "Cover", "Kickoff",
+                ],
+                rows,
+            )
+        }
+        (View::Arbitrage, Market::Moneyline) => {
+            let arbs = match sport {
+                Sport::Cfb => &data.cfb_moneyline_arbs,
+                Sport::Cbb => &data.cbb_moneyline_arbs,
+            };
+            let rows = arbs
+                .iter()
+                .map(|arb| {
+                    listing(
+                        vec![
+                            matchup(&arb.away_team, &arb.home_team),
+                            text(format!(
+                                "{} {:+} @ {}",
+                                arb.home_team, arb.home_odds, arb.home_bookmaker
+                            )),
+                            text(format!(
+                                "{} {:+} @ {}",
+                                arb.away_team, arb.away_odds, arb.away_bookmaker
+                            )),
+                            percent(arb.profit_percentage, true),
+                            age(arb.age_minutes(), arb.is_likely_gone()),
+                            kickoff(arb.commence_time),
+                        ],
+                        arb.format(),
+                    )
+                })
+                .collect();
+            (
+                &["Game", "Home leg", "Away leg", "Profit", "Age", "Kickoff"],
+                rows,
+            )
+        }
+        (View::Arbitrage, Market::Spread) => {
+            let arbs = match sport {
+                Sport::Cfb => &data.cfb_spread_arbs,
+                Sport::Cbb => &data.cbb_spread_arbs,
+            };
+            let rows = arbs
+                .iter()
+                .map(|arb| {
+                    listing(
+                        vec![
+                            matchup(&arb.away_team, &arb.home_team),
+                            text(format!(
+                                "{} {:+.1} {:+} @ {}",
+                                arb.side1_team,
+                                arb.side1_spread,
+                                arb.side1_odds,
+                                arb.side1_bookmaker
+                            )),
+                            text(format!(
+                                "{} {:+.1} {:+} @ {}",
+                                arb.side2_team,
+                                arb.side2_spread,
+                                arb.side2_odds,
+                                arb.side2_bookmaker
+                            )),
+                            percent(arb.profit_percentage, true),
+                            age(arb.age_minutes(), arb.is_likely_gone()),
+                            kickoff(arb.commence_time),
+                        ],
+                        arb.format(),
+                    )
+                })
+                .collect();
+            (
+                &["Game", "Side 1", "Side 2", "Profit", "Age", "Kickoff"],
+                rows,
+            )
+        }
+    }
+}
+
+struct App {
+    data: BettingData,
+    sport: Sport,
+    market: Market,
+    view: View,
+    sort_column: usize,
+    descending: bool,
+    filter: String,
+    editing_filter: bool,
+    table: TableState,
+}
+
+impl App {
+    fn new(data: BettingData) -> Self {
+        Self {
+            data,
+            sport: Sport::Cfb,
+            market: Market::Moneyline,
+            view: View::EvBets,
+            sort_column: View::EvBets.default_sort_column(),
+            descending: true,
+            filter: String::new(),
+            editing_filter: false,
+            table: TableState::default().with_selected(0),
+        }
+    }
+
+    /// Headers and the rows matching the filter, in the current sort order
+    fn rows(&self) -> (&'static [&'static str], Vec<Listing>) {
+        let (headers, mut rows) = listings(&self.data, self.sport, self.market, self.view);
+        let filter = self.filter.to_lowercase();
+        if !filter.is_empty() {
+            rows.retain(|row| {
+                row.cells
+                    .iter()
+                    .any(|cell| cell.to_lowercase().contains(&filter))
+            });
+        }
+        let column = self.sort_column.min(headers.len() - 1);
+        rows.sort_by(|a, b| {
+            let order = a.keys[column].compare(&b.keys[column]);
+            if self.descending {
+                order.reverse()
+            } else {
+                order
+            }
+        });
+        (headers, rows)
+    }
+
+    /// Switch what's listed, starting back at the top
+    fn show(&mut self, sport: Sport, market: Market, view: View) {
+        if view != self.view {
+            self.sort_column = view.default_sort_column();
+            self.descending = true;
+        }
+        self.sport = sport;
+        self.market = market;
+        self.view = view;
+        self.table.select(Some(0));
+    }
+
+    /// Move the selection by `delta` rows, staying within `len`
+    fn scroll(&mut self, delta: isize, len: usize) {
+        let current = self.table.selected().unwrap_or(0) as isize;
+        let last = len.saturating_sub(1) as isize;
+        self.table
+            .select(Some((current + delta).clamp(0, last) as usize));
+    }
+
+    /// Handle a key press, returning whether to quit
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        if self.editing_filter {
+            match key {
+                KeyCode::Enter | KeyCode::Esc => self.editing_filter = false,
+                KeyCode::Backspace => {
+                    self.filter.pop();
+                }
+                KeyCode::Char(c) => self.filter.push(c),
+                _ => {}
+            }
+            self.table.select(Some(0));
+            return false;
+        }
+
+        let (headers, rows) = self.rows();
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => return true,
+            KeyCode::Char('s') => {
+                let sport = match self.sport {
+                    Sport::Cfb => Sport::Cbb,
+                    Sport::Cbb => Sport::Cfb,
+                };
+                self.show(sport, self.market, self.view);
+            }
+            KeyCode::Char('m') => {
+                let market = match self.market {
+                    Market::Moneyline => Market::Spread,
+                    Market::Spread => Market::Moneyline,
+                };
+                self.show(self.sport, market, self.view);
+            }
+            KeyCode::Tab => {
+                let view = match self.view {
+                    View::EvBets => View::Arbitrage,
+                    View::Arbitrage => View::EvBets,
+                };
+                self.show(self.sport, self.market, view);
+            }
+            KeyCode::Char('/') => self.editing_filter = true,
+            KeyCode::Char('c') => {
+                self.filter.clear();
+                self.table.select(Some(0));
+            }
+            KeyCode::Left | KeyCode::Char('h') => {
+                self.sort_column = self.sort_column.checked_sub(1).unwrap_or(headers.len() - 1);
+            }
+            KeyCode::Right | KeyCode::Char('l') => {
+                self.sort_column = (self.sort_column + 1) % headers.len();
+            }
+            KeyCode::Char('r') => self.descending = !self.descending,
+            KeyCode::Down | KeyCode::Char('j') => self.scroll(1, rows.len()),
+            KeyCode::Up | KeyCode::Char('k') => self.scroll(-1, rows.len()),
+            KeyCode::PageDown => self.scroll(PAGE_ROWS as isize, rows.len()),
+            KeyCode::PageUp => self.scroll(-(PAGE_ROWS as isize), rows.len()),
+            KeyCode::Home | KeyCode::Char('g') => self.table.select(Some(0)),
+            KeyCode::End | KeyCode::Char('G') => self.scroll(rows.len() as isize, rows.len()),
+            _ => {}
+        }
+        false
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [status_area, table_area, detail_area, help_area] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Min(5),
+            Constraint::Length(4),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        let (headers, rows) = self.rows();
+        let sport = match self.sport {
+            Sport::Cfb => "CFB",
+            Sport::Cbb => "CBB",
+        };
+        let market = match self.market {
+            Market::Moneyline => "Moneyline",
+            Market::Spread => "Spread",
+        };
+        let view = match self.view {
+            View::EvBets => "EV Bets",
+            View::Arbitrage => "Arbitrage",
+        };
+        let filter = if self.editing_filter {
+            format!(" | Filter: {}_", self.filter)
+        } else if self.filter.is_empty() {
+            String::new()
+        } else {
+            format!(" | Filter: {}", self.filter)
+        };
+        frame.render_widget(
+            Line::from(format!(
+                "{} {} {} ({}){}",
+                sport,
+                market,
+                view,
+                rows.len(),
+                filter
+            ))
+            .bold(),
+            status_area,
+        );
+
+        let sort_column = self.sort_column.min(headers.len() - 1);
+        let header = Row::new(headers.iter().enumerate().map(|(i, title)| {
+            if i == sort_column {
+                let arrow = if self.descending { "▼" } else { "▲" };
+                Cell::from(format!("{} {}", title, arrow)).underlined()
+            } else {
+                Cell::from(*title)
+            }
+        }))
+        .bold();
+        let widths = headers.iter().map(|title| match *title {
+            "Game" | "Home leg" | "Away leg" | "Side 1" | "Side 2" => Constraint::Fill(2),
+            "Bet" | "Book" | "Kickoff" => Constraint::Fill(1),
+            _ => Constraint::Length(10),
+        });
+        let selected_detail = self
+            .table
+            .selected()
+            .and_then(|index| rows.get(index))
+            .map(|row| row.detail.clone());
+        let table = Table::new(rows.into_iter().map(|row| Row::new(row.cells)), widths)
+            .header(header)
+            .block(Block::bordered())
+            .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+        frame.render_stateful_widget(table, table_area, &mut self.table);
+
+        let detail = selected_detail.unwrap_or_else(|| "Nothing to show".to_string());
+        frame.render_widget(
+            Paragraph::new(detail)
+                .wrap(Wrap { trim: true })
+                .block(Block::bordered().title("Selected")),
+            detail_area,
+        );
+        frame.render_widget(
+            Line::from(
+                "q quit | tab EV/arb | s sport | m market | ←/→ sort column | r reverse | / filter | c clear | ↑/↓ scroll",
+            )
+            .dim(),
+            help_area,
+        );
+    }
+}
+
+fn run(terminal: &mut DefaultTerminal, mut app: App) -> Result<()> {
+    loop {
+        terminal.draw(|frame| app.draw(frame))?;
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press && app.handle_key(key.code) {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Saved betting data when `USE_CACHE=1` and it exists, otherwise a fresh fetch
+async fn load_data() -> Result<BettingData> {
+    let use_cache = use_cache();
+    if use_cache && Path::new(BETTING_DATA_FILE).exists() {
+        if let Ok(data) = BettingData::load(BETTING_DATA_FILE) {
+            return Ok(data);
+        }
+    }
+    let data = fetch_all_betting_data(use_cache, &ev_filter(), top_n_from_env()).await?;
+    Ok(data)
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // Load environment variables, layering `--profile NAME` (or CFB_PROFILE) over .env
+    load_env(profile_arg(std::env::args().skip(1)).as_deref())?;
+
+    // Everything is fetched before the UI starts, so log lines can't draw over it
+    cfb_betting_ev::init_logging();
+    println!("Fetching betting data...");
+    let data = load_data().await?;
+
+    let mut terminal = ratatui::init();
+    let result = run(&mut terminal, App::new(data));
+    ratatui::restore();
+    result
+}
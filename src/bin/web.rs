@@ -1,17 +1,47 @@
 use askama::Template;
 use axum::{
+    extract::{FromRef, Query, State},
     http::StatusCode,
-    response::{Html, IntoResponse, Response},
-    routing::get,
-    Router,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        Html, IntoResponse, Response,
+    },
+    routing::{get, post},
+    Json, Router,
 };
-use cfb_betting_ev::fetch_all_betting_data;
+use cfb_betting_ev::accuracy::{model_accuracy, ModelAccuracy};
+use cfb_betting_ev::config::{
+    ev_filter, feed_arb_weight, load_env, profile_arg, refresh_minutes, use_cache,
+};
+use cfb_betting_ev::data::load_from_cache;
+use cfb_betting_ev::ev_analysis::{
+    calibration_report, ev_by_bookmaker, lowest_vig_book, overround, spread_ev_by_bookmaker,
+    BookStats, EvFilter, GradingSummary, FLAT_STAKE,
+};
+use cfb_betting_ev::feed::{betting_data_feed, FeedWeights, Opportunity};
+use cfb_betting_ev::ledger::{update_ledger, EquityPoint, Ledger};
+use cfb_betting_ev::line_movement::{compare_snapshots, BookChange, LineMove};
+use cfb_betting_ev::live::{grade_live_moneyline_bets, grade_live_spread_bets, LiveBet};
+use cfb_betting_ev::monitoring::{record_betting_data, record_fetch};
+use cfb_betting_ev::{
+    fetch_all_betting_data, fetch_latest_cfb_results, offline_mode, top_n_from_env, BettingData,
+    BettingOdds, Bookmaker, CfbBettingError, Game, BETTING_DATA_FILE, LEDGER_FILE,
+    PREVIOUS_ODDS_CACHE_FILE,
+};
+use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle};
+use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
+use std::path::Path;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::time::Duration;
+use tokio::sync::{watch, RwLock};
+use tokio_stream::wrappers::WatchStream;
+use tokio_stream::{Stream, StreamExt};
 use tower_http::services::ServeDir;
 
 // Custom filters for formatting
 mod filters {
+    use cfb_betting_ev::team_names::team_metadata;
     use chrono::{DateTime, Utc};
 
     pub fn format_odds(odds: &i32) -> ::askama::Result<String> {
@@ -34,14 +64,78 @@ mod filters {
         Ok(format!("{:.2}", value))
     }
 
+    /// Whole number with thousands separators, e.g. 104,944
+    pub fn thousands(value: &i32) -> ::askama::Result<String> {
+        let digits = value.unsigned_abs().to_string();
+        let mut grouped = String::new();
+        for (i, digit) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                grouped.push(',');
+            }
+            grouped.push(digit);
+        }
+        Ok(if *value < 0 {
+            format!("-{}", grouped)
+        } else {
+            grouped
+        })
+    }
+
     pub fn calc_profit(profit_pct: &f64) -> ::askama::Result<String> {
         let profit = (profit_pct / 100.0) * 100.0;
         Ok(format!("{:.2}", profit))
     }
 
+    /// Date part of an RFC 3339 timestamp; anything unparseable is shown as-is
     pub fn date(s: &str) -> ::askama::Result<String> {
-        let dt = s.parse::<DateTime<Utc>>().unwrap();
-        Ok(dt.format("%Y-%m-%d").to_string())
+        Ok(match s.parse::<DateTime<Utc>>() {
+            Ok(dt) => dt.format("%Y-%m-%d").to_string(),
+            Err(_) => s.to_string(),
+        })
+    }
+
+    /// Team name with its logo and a conference badge in the team's color, or just the
+    /// name for teams without metadata
+    /// Returns HTML, so follow it with `|safe`.
+    pub fn team_badge(team: impl AsRef<str>) -> ::askama::Result<String> {
+        let team = team.as_ref();
+        let name = escape_html(team);
+        Ok(match team_metadata(team) {
+            Some(meta) => {
+                let badge = meta
+                    .conference
+                    .map(|conference| {
+                        format!(
+                            r#" <span class="conference-badge" style="background: {}">{}</span>"#,
+                            meta.primary_color,
+                            escape_html(conference)
+                        )
+                    })
+                    .unwrap_or_default();
+                format!(
+                    r#"<img class="team-logo" src="{}" alt="" loading="lazy"> {}{}"#,
+                    meta.logo_url, name, badge
+                )
+            }
+            None => name,
+        })
+    }
+
+    fn escape_html(s: &str) -> String {
+        s.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+            .replace('\'', "&#39;")
+    }
+
+    /// Day and time of an RFC 3339 timestamp in the `DISPLAY_TZ` timezone
+    pub fn kickoff(s: impl AsRef<str>) -> ::askama::Result<String> {
+        let s = s.as_ref();
+        Ok(match s.parse::<DateTime<Utc>>() {
+            Ok(dt) => cfb_betting_ev::format_kickoff(dt, cfb_betting_ev::display_timezone()),
+            Err(_) => s.to_string(),
+        })
     }
 }
 
@@ -55,8 +149,233 @@ struct HomeTemplate {
     cbb_arb_count: usize,
     cfb_game_results_count: usize,
     cbb_game_results_count: usize,
-    show_top_bets: bool,
-    top_bets: Vec<cfb_betting_ev::utils::ev_analysis::EvBetRecommendation>,
+    feed_count: usize,
+    feed: Vec<FeedRow>,
+    bookmaker_stats: Vec<BookmakerRow>,
+}
+
+/// Number of opportunities shown in the home page feed
+const MAX_FEED_ROWS: usize = 10;
+
+/// One row of the combined EV bet and arbitrage feed on the home page
+#[derive(Clone, Serialize)]
+struct FeedRow {
+    kind: &'static str,
+    matchup: String,
+    description: String,
+    score: f64,
+    is_arbitrage: bool,
+}
+
+impl FeedRow {
+    fn new(opportunity: &Opportunity, weights: &FeedWeights) -> Self {
+        Self {
+            kind: opportunity.kind(),
+            matchup: opportunity.matchup(),
+            description: opportunity.description(),
+            score: opportunity.score(weights),
+            is_arbitrage: matches!(
+                opportunity,
+                Opportunity::MoneylineArb(_) | Opportunity::SpreadArb(_)
+            ),
+        }
+    }
+}
+
+/// Feed weights, with arbitrage weighted by `FEED_ARB_WEIGHT` when set
+fn feed_weights() -> FeedWeights {
+    let mut weights = FeedWeights::default();
+    if let Some(arbitrage) = feed_arb_weight() {
+        weights.arbitrage = arbitrage;
+    }
+    weights
+}
+
+/// One row of the per-bookmaker EV table on the home page
+struct BookmakerRow {
+    bookmaker: String,
+    moneyline: BookStats,
+    spread: BookStats,
+}
+
+/// Moneyline and spread EV stats per bookmaker, most bets first
+fn bookmaker_rows(data: &cfb_betting_ev::BettingData) -> Vec<BookmakerRow> {
+    let moneyline = ev_by_bookmaker(&data.cfb_moneyline_bets);
+    let spread = spread_ev_by_bookmaker(&data.cfb_spread_bets);
+
+    let mut bookmakers: Vec<&Bookmaker> = moneyline.keys().chain(spread.keys()).collect();
+    bookmakers.sort_by(|a, b| a.name().cmp(b.name()));
+    bookmakers.dedup();
+
+    let mut rows: Vec<BookmakerRow> = bookmakers
+        .into_iter()
+        .map(|bookmaker| BookmakerRow {
+            bookmaker: bookmaker.to_string(),
+            moneyline: moneyline.get(bookmaker).copied().unwrap_or_default(),
+            spread: spread.get(bookmaker).copied().unwrap_or_default(),
+        })
+        .collect();
+    rows.sort_by_key(|row| std::cmp::Reverse(row.moneyline.count + row.spread.count));
+    rows
+}
+
+#[derive(Template)]
+#[template(path = "movement.html")]
+struct MovementTemplate {
+    active_page: String,
+    /// Why there's nothing to compare against, if the previous snapshot couldn't be read
+    snapshot_error: Option<String>,
+    moves: Vec<MovementRow>,
+}
+
+/// One book's line move on a game, formatted for display
+struct MovementRow {
+    matchup: String,
+    bookmaker: String,
+    old_line: String,
+    new_line: String,
+    delta: String,
+    moneyline: String,
+    fair_prob_delta: String,
+}
+
+/// Most line moves listed on the movement page
+const MAX_MOVEMENT_ROWS: usize = 50;
+
+impl From<&LineMove> for MovementRow {
+    fn from(line_move: &LineMove) -> Self {
+        let spread = |spread: Option<f64>| spread.map_or("-".to_string(), |s| format!("{:+.1}", s));
+        Self {
+            matchup: format!("{} @ {}", line_move.away_team, line_move.home_team),
+            bookmaker: line_move.bookmaker.clone(),
+            old_line: spread(line_move.spread_before),
+            new_line: spread(line_move.spread_after),
+            delta: spread(line_move.spread_delta()),
+            moneyline: match (line_move.moneyline_before, line_move.moneyline_after) {
+                (Some(before), Some(after)) => format!("{:+} → {:+}", before, after),
+                _ => "-".to_string(),
+            },
+            fair_prob_delta: line_move
+                .fair_prob_delta()
+                .map_or("-".to_string(), |delta| format!("{:+.1}%", delta * 100.0)),
+        }
+    }
+}
+
+#[derive(Template)]
+#[template(path = "debug.html")]
+struct DebugTemplate {
+    active_page: String,
+    skipped: Vec<SkippedRow>,
+}
+
+/// A CFB game with no moneyline bet, and why
+struct SkippedRow {
+    matchup: String,
+    kickoff: String,
+    reason: String,
+}
+
+#[derive(Template)]
+#[template(path = "ledger.html")]
+struct LedgerTemplate {
+    active_page: String,
+    bets: usize,
+    wins: usize,
+    losses: usize,
+    total_units: String,
+    roi: String,
+    longest_win_streak: usize,
+    longest_loss_streak: usize,
+    weeks: Vec<LedgerWeekRow>,
+    chart: EquityChart,
+}
+
+/// One week of the season ledger
+struct LedgerWeekRow {
+    label: String,
+    record: String,
+    staked: String,
+    units: String,
+    roi: String,
+    positive: bool,
+}
+
+/// SVG coordinates for the ledger's running units, scaled to the chart's viewBox
+struct EquityChart {
+    /// `points` attribute for the polyline, starting from zero before the first bet
+    points: String,
+    /// Height of the zero-units line
+    zero_y: String,
+    max_label: String,
+    min_label: String,
+}
+
+const EQUITY_CHART_WIDTH: f64 = 600.0;
+const EQUITY_CHART_HEIGHT: f64 = 200.0;
+
+impl EquityChart {
+    fn new(curve: &[EquityPoint]) -> Self {
+        let units: Vec<f64> = std::iter::once(0.0)
+            .chain(curve.iter().map(|point| point.units))
+            .collect();
+        let max = units.iter().copied().fold(0.0, f64::max);
+        let min = units.iter().copied().fold(0.0, f64::min);
+        let range = (max - min).max(1.0);
+        let y = |value: f64| EQUITY_CHART_HEIGHT * (max - value) / range;
+        let step = EQUITY_CHART_WIDTH / (units.len() - 1).max(1) as f64;
+
+        Self {
+            points: units
+                .iter()
+                .enumerate()
+                .map(|(i, &value)| format!("{:.1},{:.1}", i as f64 * step, y(value)))
+                .collect::<Vec<_>>()
+                .join(" "),
+            zero_y: format!("{:.1}", y(0.0)),
+            max_label: format!("{:+.2}u", max),
+            min_label: format!("{:+.2}u", min),
+        }
+    }
+}
+
+#[derive(Template)]
+#[template(path = "vig.html")]
+struct VigTemplate {
+    active_page: String,
+    games: Vec<VigRow>,
+}
+
+/// The cheapest and most expensive books on one game's moneyline
+struct VigRow {
+    matchup: String,
+    kickoff: String,
+    lowest_book: String,
+    lowest_vig: String,
+    highest_book: String,
+    highest_vig: String,
+    books: usize,
+}
+
+impl VigRow {
+    /// `None` when no book prices both sides of the game
+    fn new(game: &Game, odds_list: &[BettingOdds]) -> Option<Self> {
+        let (lowest_book, lowest) = lowest_vig_book(odds_list, game)?;
+        let overrounds = overround(odds_list, game);
+        let (highest_book, highest) = overrounds
+            .iter()
+            .max_by(|a, b| a.1.total_cmp(b.1).then_with(|| b.0.cmp(a.0)))?;
+        let vig = |overround: f64| format!("{:.2}%", (overround - 1.0) * 100.0);
+        Some(Self {
+            matchup: format!("{} @ {}", game.away_team, game.home_team),
+            kickoff: game.commence_time.to_rfc3339(),
+            lowest_book: lowest_book.to_string(),
+            lowest_vig: vig(lowest),
+            highest_book: highest_book.clone(),
+            highest_vig: vig(*highest),
+            books: overrounds.len(),
+        })
+    }
 }
 
 #[derive(Template)]
@@ -81,6 +400,7 @@ struct CfbSpreadTemplate {
     active_page: String,
     cfb_spread_bets: Vec<cfb_betting_ev::utils::ev_analysis::SpreadEvBetRecommendation>,
     cfb_spread_arbs: Vec<cfb_betting_ev::utils::arbitrage::SpreadArbitrage>,
+    cfb_spread_middles: Vec<cfb_betting_ev::utils::arbitrage::SpreadMiddle>,
 }
 
 #[derive(Template)]
@@ -89,6 +409,7 @@ struct CbbTemplate {
     active_page: String,
     cbb_moneyline_arbs: Vec<cfb_betting_ev::utils::arbitrage::MoneylineArbitrage>,
     cbb_spread_arbs: Vec<cfb_betting_ev::utils::arbitrage::SpreadArbitrage>,
+    cbb_moneyline_bets: Vec<cfb_betting_ev::utils::ev_analysis::EvBetRecommendation>,
 }
 
 #[derive(Template)]
@@ -111,6 +432,22 @@ struct CfbBetResultsTemplate {
     active_page: String,
     cfb_moneyline_bet_results: Vec<cfb_betting_ev::utils::ev_analysis::BetResult>,
     cfb_spread_bet_results: Vec<cfb_betting_ev::utils::ev_analysis::SpreadBetResult>,
+    calibration: Vec<cfb_betting_ev::utils::ev_analysis::CalibrationBucket>,
+    live_bets: Vec<LiveBet>,
+    /// Recommended bets whose games aren't final yet, left out of the results lists
+    pending_bets: usize,
+    /// Graded bets with closing odds, and how many of them beat the close
+    closing_bets: usize,
+    beat_close: usize,
+    beat_close_rate: f64,
+    model_accuracy: Vec<ModelAccuracy>,
+}
+
+#[derive(Template)]
+#[template(path = "data_unavailable.html")]
+struct DataUnavailableTemplate {
+    active_page: String,
+    message: String,
 }
 
 struct HtmlTemplate<T>(T);
@@ -131,40 +468,137 @@ where
     }
 }
 
+/// Optional `?conference=SEC` and `?book=draftkings` query filters for the bet pages
+/// Book names are normalized, so `book=DraftKings` and `book=draftkings` match the same bets.
+#[derive(Deserialize)]
+struct BetFilter {
+    conference: Option<String>,
+    book: Option<String>,
+}
+
+impl BetFilter {
+    /// The configured EV filter narrowed to `?conference=`, so the top N are taken from
+    /// that conference's games only
+    fn ev_filter(&self) -> Option<EvFilter> {
+        let conference = self.conference.clone()?;
+        Some(EvFilter {
+            conferences: vec![conference],
+            ..ev_filter()
+        })
+    }
+
+    fn includes_book(&self, bookmaker: &str) -> bool {
+        match &self.book {
+            Some(book) => Bookmaker::from_title(book).matches(bookmaker),
+            None => true,
+        }
+    }
+}
+
 // Shared state to cache data
-type SharedData = Arc<RwLock<Option<cfb_betting_ev::BettingData>>>;
+/// Betting data loaded at startup (or by the latest refresh), or why it couldn't be loaded
+type SharedData = Arc<RwLock<Result<cfb_betting_ev::BettingData, String>>>;
+
+/// Compact summary of the current betting data, pushed to `/events` subscribers as JSON
+#[derive(Clone, Serialize)]
+struct DataSummary {
+    cfb_moneyline_count: usize,
+    cfb_spread_count: usize,
+    cfb_arb_count: usize,
+    cbb_arb_count: usize,
+    cfb_game_results_count: usize,
+    cbb_game_results_count: usize,
+    feed_count: usize,
+    /// Best opportunities, as shown in the home page feed
+    feed: Vec<FeedRow>,
+}
+
+impl DataSummary {
+    fn new(data: &cfb_betting_ev::BettingData) -> Self {
+        let weights = feed_weights();
+        let opportunities = betting_data_feed(data, &weights);
+        Self {
+            cfb_moneyline_count: data.cfb_moneyline_bets.len(),
+            cfb_spread_count: data.cfb_spread_bets.len(),
+            cfb_arb_count: data.cfb_moneyline_arbs.len() + data.cfb_spread_arbs.len(),
+            cbb_arb_count: data.cbb_moneyline_arbs.len() + data.cbb_spread_arbs.len(),
+            cfb_game_results_count: data.cfb_game_results.len(),
+            cbb_game_results_count: data.cbb_game_results.len(),
+            feed_count: opportunities.len(),
+            feed: opportunities
+                .iter()
+                .take(MAX_FEED_ROWS)
+                .map(|opportunity| FeedRow::new(opportunity, &weights))
+                .collect(),
+        }
+    }
+
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
+/// Everything the handlers share: the data itself and the channel announcing refreshes
+#[derive(Clone)]
+struct AppState {
+    data: SharedData,
+    /// Latest `DataSummary` as JSON; empty until data has loaded
+    updates: watch::Receiver<String>,
+    /// Renders the Prometheus metrics recorded by the fetch paths
+    metrics: PrometheusHandle,
+}
+
+impl FromRef<AppState> for PrometheusHandle {
+    fn from_ref(state: &AppState) -> Self {
+        state.metrics.clone()
+    }
+}
+
+impl FromRef<AppState> for SharedData {
+    fn from_ref(state: &AppState) -> Self {
+        state.data.clone()
+    }
+}
+
+impl FromRef<AppState> for watch::Receiver<String> {
+    fn from_ref(state: &AppState) -> Self {
+        state.updates.clone()
+    }
+}
+
+/// Page shown in place of every view when betting data failed to load at startup
+fn data_unavailable(message: &str) -> Response {
+    let template = DataUnavailableTemplate {
+        active_page: String::new(),
+        message: message.to_string(),
+    };
+
+    (StatusCode::SERVICE_UNAVAILABLE, HtmlTemplate(template)).into_response()
+}
 
 async fn home(data: axum::extract::State<SharedData>) -> impl IntoResponse {
     let betting_data = data.read().await;
 
     let data = match betting_data.as_ref() {
-        Some(d) => d.clone(),
-        None => {
-            return (StatusCode::INTERNAL_SERVER_ERROR, "Data not loaded yet").into_response();
-        }
+        Ok(d) => d.clone(),
+        Err(e) => return data_unavailable(e),
     };
 
-    let cfb_moneyline_count = data.cfb_moneyline_bets.len();
-    let cfb_spread_count = data.cfb_spread_bets.len();
-    let cfb_arb_count = data.cfb_moneyline_arbs.len() + data.cfb_spread_arbs.len();
-    let cbb_arb_count = data.cbb_moneyline_arbs.len() + data.cbb_spread_arbs.len();
-    let cfb_game_results_count = data.cfb_game_results.len();
-    let cbb_game_results_count = data.cbb_game_results.len();
-
-    // Get top 3 bets
-    let top_bets: Vec<_> = data.cfb_moneyline_bets.iter().take(3).cloned().collect();
-    let show_top_bets = !top_bets.is_empty();
+    // Best EV bets and arbs, ranked together
+    let summary = DataSummary::new(&data);
+    let bookmaker_stats = bookmaker_rows(&data);
 
     let template = HomeTemplate {
         active_page: "home".to_string(),
-        cfb_moneyline_count,
-        cfb_spread_count,
-        cfb_arb_count,
-        cbb_arb_count,
-        cfb_game_results_count,
-        cbb_game_results_count,
-        show_top_bets,
-        top_bets,
+        cfb_moneyline_count: summary.cfb_moneyline_count,
+        cfb_spread_count: summary.cfb_spread_count,
+        cfb_arb_count: summary.cfb_arb_count,
+        cbb_arb_count: summary.cbb_arb_count,
+        cfb_game_results_count: summary.cfb_game_results_count,
+        cbb_game_results_count: summary.cbb_game_results_count,
+        feed_count: summary.feed_count,
+        feed: summary.feed,
+        bookmaker_stats,
     };
 
     HtmlTemplate(template).into_response()
@@ -174,10 +608,8 @@ async fn cfb(data: axum::extract::State<SharedData>) -> impl IntoResponse {
     let betting_data = data.read().await;
 
     let data = match betting_data.as_ref() {
-        Some(d) => d.clone(),
-        None => {
-            return (StatusCode::INTERNAL_SERVER_ERROR, "Data not loaded yet").into_response();
-        }
+        Ok(d) => d.clone(),
+        Err(e) => return data_unavailable(e),
     };
 
     let template = CfbTemplate {
@@ -189,58 +621,196 @@ async fn cfb(data: axum::extract::State<SharedData>) -> impl IntoResponse {
     HtmlTemplate(template).into_response()
 }
 
-async fn cfb_moneyline(data: axum::extract::State<SharedData>) -> impl IntoResponse {
+async fn cfb_moneyline(
+    data: axum::extract::State<SharedData>,
+    Query(filter): Query<BetFilter>,
+) -> impl IntoResponse {
     let betting_data = data.read().await;
 
     let data = match betting_data.as_ref() {
-        Some(d) => d.clone(),
-        None => {
-            return (StatusCode::INTERNAL_SERVER_ERROR, "Data not loaded yet").into_response();
+        Ok(d) => d.clone(),
+        Err(e) => return data_unavailable(e),
+    };
+
+    let mut cfb_moneyline_bets = match filter.ev_filter() {
+        Some(ev_filter) => {
+            data.cfb_moneyline_bets_matching(&ev_filter, top_n_from_env())
+                .await
         }
+        None => data.cfb_moneyline_bets,
     };
+    cfb_moneyline_bets.retain(|bet| filter.includes_book(&bet.bookmaker));
 
     let template = CfbMoneylineTemplate {
         active_page: "cfb_moneyline".to_string(),
-        cfb_moneyline_bets: data.cfb_moneyline_bets,
+        cfb_moneyline_bets,
         cfb_moneyline_arbs: data.cfb_moneyline_arbs,
     };
 
     HtmlTemplate(template).into_response()
 }
 
-async fn cfb_spread(data: axum::extract::State<SharedData>) -> impl IntoResponse {
+async fn cfb_spread(
+    data: axum::extract::State<SharedData>,
+    Query(filter): Query<BetFilter>,
+) -> impl IntoResponse {
     let betting_data = data.read().await;
 
     let data = match betting_data.as_ref() {
-        Some(d) => d.clone(),
-        None => {
-            return (StatusCode::INTERNAL_SERVER_ERROR, "Data not loaded yet").into_response();
+        Ok(d) => d.clone(),
+        Err(e) => return data_unavailable(e),
+    };
+
+    let mut cfb_spread_bets = match filter.ev_filter() {
+        Some(ev_filter) => {
+            data.cfb_spread_bets_matching(&ev_filter, top_n_from_env())
+                .await
         }
+        None => data.cfb_spread_bets,
     };
+    cfb_spread_bets.retain(|bet| filter.includes_book(&bet.bookmaker));
 
     let template = CfbSpreadTemplate {
         active_page: "cfb_spread".to_string(),
-        cfb_spread_bets: data.cfb_spread_bets,
+        cfb_spread_bets,
         cfb_spread_arbs: data.cfb_spread_arbs,
+        cfb_spread_middles: data.cfb_spread_middles,
     };
 
     HtmlTemplate(template).into_response()
 }
 
-async fn cbb(data: axum::extract::State<SharedData>) -> impl IntoResponse {
+async fn movement(data: axum::extract::State<SharedData>) -> impl IntoResponse {
     let betting_data = data.read().await;
 
     let data = match betting_data.as_ref() {
-        Some(d) => d.clone(),
-        None => {
-            return (StatusCode::INTERNAL_SERVER_ERROR, "Data not loaded yet").into_response();
+        Ok(d) => d,
+        Err(e) => return data_unavailable(e),
+    };
+
+    let previous: Result<Vec<(Game, Vec<BettingOdds>)>, _> =
+        load_from_cache(PREVIOUS_ODDS_CACHE_FILE);
+    let (snapshot_error, moves) = match previous {
+        Ok(previous) => {
+            let moves = compare_snapshots(&previous, &data.cfb_games_with_odds)
+                .iter()
+                .filter(|line_move| line_move.change == BookChange::Moved)
+                .take(MAX_MOVEMENT_ROWS)
+                .map(MovementRow::from)
+                .collect();
+            (None, moves)
         }
+        Err(e) => (
+            Some(format!("{}: {:#}", PREVIOUS_ODDS_CACHE_FILE, e)),
+            Vec::new(),
+        ),
+    };
+
+    let template = MovementTemplate {
+        active_page: "movement".to_string(),
+        snapshot_error,
+        moves,
+    };
+
+    HtmlTemplate(template).into_response()
+}
+
+async fn vig(data: axum::extract::State<SharedData>) -> impl IntoResponse {
+    let betting_data = data.read().await;
+
+    let data = match betting_data.as_ref() {
+        Ok(d) => d,
+        Err(e) => return data_unavailable(e),
+    };
+
+    let mut games: Vec<(&Game, VigRow)> = data
+        .cfb_games_with_odds
+        .iter()
+        .filter_map(|(game, odds_list)| Some((game, VigRow::new(game, odds_list)?)))
+        .collect();
+    games.sort_by_key(|(game, _)| game.commence_time);
+
+    let template = VigTemplate {
+        active_page: "vig".to_string(),
+        games: games.into_iter().map(|(_, row)| row).collect(),
+    };
+
+    HtmlTemplate(template).into_response()
+}
+
+async fn debug(data: axum::extract::State<SharedData>) -> impl IntoResponse {
+    let betting_data = data.read().await;
+
+    let data = match betting_data.as_ref() {
+        Ok(d) => d,
+        Err(e) => return data_unavailable(e),
+    };
+
+    let mut skipped: Vec<_> = data.cfb_skipped_games.iter().collect();
+    skipped.sort_by_key(|skip| skip.game.commence_time);
+
+    let template = DebugTemplate {
+        active_page: "debug".to_string(),
+        skipped: skipped
+            .into_iter()
+            .map(|skip| SkippedRow {
+                matchup: format!("{} @ {}", skip.game.away_team, skip.game.home_team),
+                kickoff: skip.game.commence_time.to_rfc3339(),
+                reason: skip.reason.describe(),
+            })
+            .collect(),
+    };
+
+    HtmlTemplate(template).into_response()
+}
+
+async fn ledger() -> impl IntoResponse {
+    let ledger = match Ledger::load(LEDGER_FILE) {
+        Ok(ledger) => ledger,
+        Err(e) => return data_unavailable(&e.to_string()),
+    };
+    let (longest_win_streak, longest_loss_streak) = ledger.longest_streaks();
+
+    let template = LedgerTemplate {
+        active_page: "ledger".to_string(),
+        bets: ledger.entries.len(),
+        wins: ledger.wins(),
+        losses: ledger.losses(),
+        total_units: format!("{:+.2}", ledger.total_units()),
+        roi: format!("{:+.1}%", ledger.roi() * 100.0),
+        longest_win_streak,
+        longest_loss_streak,
+        weeks: ledger
+            .weeks()
+            .iter()
+            .map(|week| LedgerWeekRow {
+                label: format!("{} Week {}", week.season, week.week),
+                record: format!("{}-{}", week.wins, week.losses),
+                staked: format!("{:.2}", week.staked),
+                units: format!("{:+.2}", week.units),
+                roi: format!("{:+.1}%", week.roi() * 100.0),
+                positive: week.units >= 0.0,
+            })
+            .collect(),
+        chart: EquityChart::new(&ledger.equity_curve()),
+    };
+
+    HtmlTemplate(template).into_response()
+}
+
+async fn cbb(data: axum::extract::State<SharedData>) -> impl IntoResponse {
+    let betting_data = data.read().await;
+
+    let data = match betting_data.as_ref() {
+        Ok(d) => d.clone(),
+        Err(e) => return data_unavailable(e),
     };
 
     let template = CbbTemplate {
         active_page: "cbb".to_string(),
         cbb_moneyline_arbs: data.cbb_moneyline_arbs,
         cbb_spread_arbs: data.cbb_spread_arbs,
+        cbb_moneyline_bets: data.cbb_moneyline_bets,
     };
 
     HtmlTemplate(template).into_response()
@@ -250,10 +820,8 @@ async fn cfb_results(data: axum::extract::State<SharedData>) -> impl IntoRespons
     let betting_data = data.read().await;
 
     let data = match betting_data.as_ref() {
-        Some(d) => d.clone(),
-        None => {
-            return (StatusCode::INTERNAL_SERVER_ERROR, "Data not loaded yet").into_response();
-        }
+        Ok(d) => d.clone(),
+        Err(e) => return data_unavailable(e),
     };
 
     let template = CfbResultsTemplate {
@@ -268,10 +836,8 @@ async fn cbb_results(data: axum::extract::State<SharedData>) -> impl IntoRespons
     let betting_data = data.read().await;
 
     let data = match betting_data.as_ref() {
-        Some(d) => d.clone(),
-        None => {
-            return (StatusCode::INTERNAL_SERVER_ERROR, "Data not loaded yet").into_response();
-        }
+        Ok(d) => d.clone(),
+        Err(e) => return data_unavailable(e),
     };
 
     let template = CbbResultsTemplate {
@@ -285,15 +851,54 @@ async fn cbb_results(data: axum::extract::State<SharedData>) -> impl IntoRespons
 async fn cfb_bet_results(data: axum::extract::State<SharedData>) -> impl IntoResponse {
     let betting_data = data.read().await;
 
-    let data = match betting_data.as_ref() {
-        Some(d) => d.clone(),
-        None => {
-            return (StatusCode::INTERNAL_SERVER_ERROR, "Data not loaded yet").into_response();
-        }
+    let mut data = match betting_data.as_ref() {
+        Ok(d) => d.clone(),
+        Err(e) => return data_unavailable(e),
     };
 
+    // Bets in games that are underway, graded against the current score
+    let live_bets = grade_live_moneyline_bets(&data.cfb_moneyline_bets, &data.cfb_game_results)
+        .into_iter()
+        .chain(grade_live_spread_bets(
+            &data.cfb_spread_bets,
+            &data.cfb_game_results,
+        ))
+        .filter(|bet| !bet.completed)
+        .collect();
+
+    let beat_close: Vec<bool> = data
+        .cfb_moneyline_bet_results
+        .iter()
+        .filter(|result| result.bet_won.is_some())
+        .filter_map(|result| result.beat_close)
+        .chain(
+            data.cfb_spread_bet_results
+                .iter()
+                .filter(|result| result.bet_won.is_some())
+                .filter_map(|result| result.beat_close),
+        )
+        .collect();
+    let closing_bets = beat_close.len();
+    let beat_count = beat_close.iter().filter(|beat| **beat).count();
+
+    // Only graded bets are listed; before any game is final that's none of them
+    let total_bets = data.cfb_moneyline_bet_results.len() + data.cfb_spread_bet_results.len();
+    data.cfb_moneyline_bet_results
+        .retain(|result| result.bet_won.is_some());
+    data.cfb_spread_bet_results
+        .retain(|result| result.bet_won.is_some());
+    let pending_bets =
+        total_bets - data.cfb_moneyline_bet_results.len() - data.cfb_spread_bet_results.len();
+
     let template = CfbBetResultsTemplate {
         active_page: "cfb_bet_results".to_string(),
+        live_bets,
+        pending_bets,
+        closing_bets,
+        beat_close: beat_count,
+        beat_close_rate: beat_count as f64 / closing_bets.max(1) as f64,
+        model_accuracy: model_accuracy(&data.predictions, &data.cfb_game_results),
+        calibration: calibration_report(&data.cfb_moneyline_bet_results),
         cfb_moneyline_bet_results: data.cfb_moneyline_bet_results,
         cfb_spread_bet_results: data.cfb_spread_bet_results,
     };
@@ -301,20 +906,194 @@ async fn cfb_bet_results(data: axum::extract::State<SharedData>) -> impl IntoRes
     HtmlTemplate(template).into_response()
 }
 
+/// Response to `POST /grade`: the refreshed grading summary
+#[derive(Serialize)]
+struct GradeResponse {
+    #[serde(flatten)]
+    summary: GradingSummary,
+    roi: f64,
+    /// Recommended bets whose games aren't final yet
+    pending: usize,
+}
+
+impl GradeResponse {
+    fn new(data: &BettingData) -> Self {
+        let summary = data.grading_summary();
+        let total_bets = data.cfb_moneyline_bet_results.len() + data.cfb_spread_bet_results.len();
+        Self {
+            summary,
+            roi: summary.roi(),
+            pending: total_bets - summary.bets(),
+        }
+    }
+}
+
+/// Re-grade the recommendations against freshly fetched CFB results
+/// The results are fetched before the data is locked, so pages keep rendering meanwhile.
+/// Offline, the stored results are graded again instead.
+async fn grade(data: axum::extract::State<SharedData>) -> Response {
+    let fresh_results = if offline_mode() {
+        None
+    } else {
+        match fetch_latest_cfb_results().await {
+            Ok(results) => Some(results),
+            Err(e @ CfbBettingError::OutOfSeason(_)) => {
+                return (StatusCode::SERVICE_UNAVAILABLE, e.to_string()).into_response()
+            }
+            Err(e) => {
+                return (
+                    StatusCode::BAD_GATEWAY,
+                    format!("Failed to fetch game results: {:#}", e),
+                )
+                    .into_response()
+            }
+        }
+    };
+
+    let mut betting_data = data.write().await;
+    let data = match betting_data.as_mut() {
+        Ok(d) => d,
+        Err(e) => return (StatusCode::SERVICE_UNAVAILABLE, e.clone()).into_response(),
+    };
+    if let Some(results) = fresh_results {
+        data.cfb_game_results = results;
+    }
+    data.grade_bets();
+    if !offline_mode() {
+        persist(data);
+    }
+
+    Json(GradeResponse::new(data)).into_response()
+}
+
+/// Prometheus metrics in the text exposition format
+async fn metrics(State(handle): State<PrometheusHandle>) -> String {
+    handle.render()
+}
+
+/// Fetch betting data, recording the outcome and the opportunity counts for `/metrics`
+/// Fresh data is also saved to `BETTING_DATA_FILE` so a restart with `USE_CACHE=1` can
+/// serve it straight away.
+async fn fetch_and_record(
+    use_cache: bool,
+    filter: &EvFilter,
+    top_n: Option<usize>,
+) -> cfb_betting_ev::error::Result<BettingData> {
+    let result = fetch_all_betting_data(use_cache, filter, top_n).await;
+    record_fetch("betting_data", &result);
+    if let Ok(data) = &result {
+        record_betting_data(data);
+        if !offline_mode() {
+            persist(data);
+        }
+    }
+    result
+}
+
+/// Save data to `BETTING_DATA_FILE` and its settled bets to the season ledger
+/// Failures are logged; the data is still served.
+fn persist(data: &BettingData) {
+    if let Err(e) = data.save(BETTING_DATA_FILE) {
+        eprintln!("Error saving betting data snapshot: {:#}", e);
+    }
+    if let Err(e) = update_ledger(
+        LEDGER_FILE,
+        &data.cfb_moneyline_bet_results,
+        &data.cfb_spread_bet_results,
+        FLAT_STAKE,
+    ) {
+        eprintln!("Error updating the season ledger: {:#}", e);
+    }
+}
+
+/// Startup data: the last saved snapshot when `USE_CACHE=1` (no fetching at all), otherwise
+/// a fetch
+async fn load_startup_data(
+    use_cache: bool,
+    filter: &EvFilter,
+    top_n: Option<usize>,
+) -> cfb_betting_ev::error::Result<BettingData> {
+    if use_cache && !offline_mode() && Path::new(BETTING_DATA_FILE).exists() {
+        match BettingData::load(BETTING_DATA_FILE) {
+            Ok(data) => {
+                println!("Loaded saved betting data from {}", BETTING_DATA_FILE);
+                record_betting_data(&data);
+                return Ok(data);
+            }
+            Err(e) => eprintln!("Error loading saved betting data, fetching: {:#}", e),
+        }
+    }
+    fetch_and_record(use_cache, filter, top_n).await
+}
+
+/// Server-Sent Events stream of `DataSummary` JSON, one `update` event per data refresh
+/// New subscribers get the current summary right away. When a client disconnects axum drops
+/// its stream, and with it the watch receiver, so nothing is left to clean up.
+async fn events(
+    State(updates): State<watch::Receiver<String>>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let stream = WatchStream::new(updates)
+        .filter(|summary| !summary.is_empty())
+        .map(|summary| Ok(Event::default().event("update").data(summary)));
+
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
+
+/// Re-fetch betting data every `interval`, swap it in and notify `/events` subscribers
+/// A failed refresh keeps serving the previous data.
+async fn refresh_periodically(
+    data: SharedData,
+    updates: watch::Sender<String>,
+    interval: Duration,
+    use_cache: bool,
+    filter: EvFilter,
+    top_n: Option<usize>,
+) {
+    let mut ticker = tokio::time::interval(interval);
+    // The first tick completes immediately, and startup has just fetched
+    ticker.tick().await;
+
+    loop {
+        ticker.tick().await;
+        match fetch_and_record(use_cache, &filter, top_n).await {
+            Ok(fresh) => {
+                let summary = DataSummary::new(&fresh).to_json();
+                *data.write().await = Ok(fresh);
+                updates.send_replace(summary);
+                println!("Betting data refreshed");
+            }
+            Err(e) => eprintln!("Error refreshing data, keeping previous data: {:#}", e),
+        }
+    }
+}
+
 #[tokio::main]
 async fn main() {
-    // Load environment variables
-    dotenv::dotenv().ok();
+    // Load environment variables, layering `--profile NAME` (or CFB_PROFILE) over .env
+    if let Err(e) = load_env(profile_arg(std::env::args().skip(1)).as_deref()) {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
 
     // Initialize logging
-    tracing_subscriber::fmt::init();
+    cfb_betting_ev::init_logging();
+
+    // Collect metrics from the fetch paths for /metrics
+    let metrics_handle = PrometheusBuilder::new()
+        .install_recorder()
+        .expect("no other metrics recorder is installed");
 
     println!("Fetching betting data...");
 
-    let use_cache = std::env::var("USE_CACHE").unwrap_or_default() == "1";
+    let use_cache = use_cache();
+    // Minimum EV and maximum edge in percent, e.g. MIN_EV=3 hides bets below 3% EV and
+    // MAX_EDGE=25 hides edges over 25 points
+    let filter = ev_filter();
+    // EV bets per market, e.g. TOP_N=50 (0 shows every positive EV bet)
+    let top_n = top_n_from_env();
 
     // Fetch data on startup
-    let data = match fetch_all_betting_data(use_cache).await {
+    let data = match load_startup_data(use_cache, &filter, top_n).await {
         Ok(data) => {
             println!("Data loaded successfully");
             println!(
@@ -332,14 +1111,34 @@ async fn main() {
             );
             println!("  - {} CFB Game Results", data.cfb_game_results.len());
             println!("  - {} CBB Game Results", data.cbb_game_results.len());
-            Arc::new(RwLock::new(Some(data)))
+            Ok(data)
         }
         Err(e) => {
-            eprintln!("Error fetching data: {}", e);
-            eprintln!("Server will start but pages may show errors");
-            Arc::new(RwLock::new(None))
+            eprintln!("Error fetching data: {:#}", e);
+            eprintln!("Server will start and show the error on every page");
+            Err(format!("{:#}", e))
         }
     };
+    let initial_summary = data
+        .as_ref()
+        .map(|data| DataSummary::new(data).to_json())
+        .unwrap_or_default();
+    let data: SharedData = Arc::new(RwLock::new(data));
+    let (updates_tx, updates) = watch::channel(initial_summary);
+
+    // Minutes between background refreshes, e.g. REFRESH_MINUTES=15 (off by default since
+    // every refresh uses API credits)
+    if let Some(minutes) = refresh_minutes() {
+        println!("Refreshing data every {} minutes", minutes);
+        tokio::spawn(refresh_periodically(
+            data.clone(),
+            updates_tx,
+            Duration::from_secs(minutes * 60),
+            use_cache,
+            filter,
+            top_n,
+        ));
+    }
 
     println!("\nStarting web server at http://127.0.0.1:3000");
     println!("Press Ctrl+C to stop\n");
@@ -354,9 +1153,20 @@ async fn main() {
         .route("/cfb/spread", get(cfb_spread))
         .route("/cfb/results", get(cfb_results))
         .route("/cfb/bet-results", get(cfb_bet_results))
+        .route("/grade", post(grade))
+        .route("/movement", get(movement))
+        .route("/vig", get(vig))
+        .route("/ledger", get(ledger))
+        .route("/debug", get(debug))
         .route("/cbb", get(cbb))
         .route("/cbb/results", get(cbb_results))
-        .with_state(data);
+        .route("/events", get(events))
+        .route("/metrics", get(metrics))
+        .with_state(AppState {
+            data,
+            updates,
+            metrics: metrics_handle,
+        });
 
     // Run server
     let listener = tokio::net::TcpListener::bind("127.0.0.1:3000")
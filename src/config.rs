@@ -0,0 +1,307 @@
+use crate::error::{CfbBettingError, Result};
+use crate::scrapers::elo::DEFAULT_K_FACTOR;
+use crate::utils::arbitrage::ArbFilter;
+use crate::utils::ev_analysis::EvFilter;
+use crate::{top_n_limit, DEFAULT_TOP_N};
+use chrono_tz::Tz;
+use std::fmt::Display;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// Environment variable naming the profile when `--profile` isn't given
+const PROFILE_VAR: &str = "CFB_PROFILE";
+
+/// Load `.env`, with `.env.<profile>` layered over it when a profile is given
+/// Variables already set in the environment win over both files. A missing `.env` is
+/// fine; a missing profile file is an error, since a typo would otherwise silently run
+/// with the wrong settings.
+pub fn load_env(profile: Option<&str>) -> Result<()> {
+    load_env_from(Path::new("."), profile)
+}
+
+fn load_env_from(dir: &Path, profile: Option<&str>) -> Result<()> {
+    // dotenv never overwrites a variable, so the profile goes first to take precedence
+    if let Some(profile) = profile {
+        let profile_file = dir.join(format!(".env.{}", profile));
+        dotenv::from_path(&profile_file)
+            .map_err(|_| CfbBettingError::MissingProfile(profile_file.display().to_string()))?;
+        info!(profile, "Loaded env profile");
+    }
+    dotenv::from_path(dir.join(".env")).ok();
+    Ok(())
+}
+
+/// The profile from `--profile NAME` (or `--profile=NAME`) in the command line arguments,
+/// falling back to `CFB_PROFILE`
+/// Read before the arguments are parsed, so the profile's settings can feed argument
+/// defaults.
+pub fn profile_arg(args: impl IntoIterator<Item = String>) -> Option<String> {
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == "--profile" {
+            return args.next();
+        }
+        if let Some(profile) = arg.strip_prefix("--profile=") {
+            return Some(profile.to_string());
+        }
+    }
+    std::env::var(PROFILE_VAR)
+        .ok()
+        .filter(|profile| !profile.trim().is_empty())
+}
+
+/// Whether a variable is set to `1`
+fn env_flag(name: &str) -> bool {
+    std::env::var(name).unwrap_or_default() == "1"
+}
+
+/// Parse a variable, warning and using `default` when it's set but invalid
+fn parse_env<T: FromStr + Display>(name: &str, default: T, is_valid: impl Fn(&T) -> bool) -> T {
+    match std::env::var(name) {
+        Ok(value) => value
+            .trim()
+            .parse()
+            .ok()
+            .filter(|parsed| is_valid(parsed))
+            .unwrap_or_else(|| {
+                warn!(var = name, value = %value, "Invalid {}, using {}", name, default);
+                default
+            }),
+        Err(_) => default,
+    }
+}
+
+/// Parse an optional variable, warning and ignoring it when it's set but invalid
+fn parse_optional_env<T: FromStr>(name: &str) -> Option<T> {
+    let value = std::env::var(name).ok()?;
+    let parsed = value.trim().parse().ok();
+    if parsed.is_none() {
+        warn!(var = name, value = %value, "Invalid {}, ignoring it", name);
+    }
+    parsed
+}
+
+/// Whether `OFFLINE=1` is set, in which case no network calls or API keys are needed
+pub fn offline_mode() -> bool {
+    env_flag("OFFLINE")
+}
+
+/// Whether `USE_CACHE=1` is set, loading from the cache files instead of the APIs
+pub fn use_cache() -> bool {
+    env_flag("USE_CACHE")
+}
+
+/// Whether `NEXT_GAME_DAY_ONLY=1` is set, limiting analysis to the soonest game day
+pub fn next_game_day_only() -> bool {
+    env_flag("NEXT_GAME_DAY_ONLY")
+}
+
+/// Whether `SCRAPED_COVER=1` is set, pricing spreads with the prediction source's own
+/// cover probability where it lists one
+pub fn scraped_cover() -> bool {
+    env_flag("SCRAPED_COVER")
+}
+
+/// Whether `ELO_PREDICTIONS=1` is set, adding the in-house Elo model as a prediction source
+pub fn elo_predictions() -> bool {
+    env_flag("ELO_PREDICTIONS")
+}
+
+/// CSV of odds boosts to price moneylines with, from `BOOSTS_FILE`
+/// (see `load_boosts_from_csv` for the columns)
+pub fn boosts_file() -> Option<String> {
+    parse_optional_env("BOOSTS_FILE")
+}
+
+/// Kalshi API key ID from `KALSHI_API_KEY`; Kalshi markets are only fetched when it's set
+pub fn kalshi_api_key() -> Option<String> {
+    parse_optional_env::<String>("KALSHI_API_KEY").filter(|key| !key.is_empty())
+}
+
+/// PEM private key file Kalshi requests are signed with, from `KALSHI_PRIVATE_KEY_PATH`
+/// Without one only public market data is used.
+pub fn kalshi_private_key_path() -> Option<String> {
+    parse_optional_env::<String>("KALSHI_PRIVATE_KEY_PATH").filter(|path| !path.is_empty())
+}
+
+/// Most one game can move an Elo rating, from `ELO_K_FACTOR`
+pub fn elo_k_factor() -> f64 {
+    parse_env("ELO_K_FACTOR", DEFAULT_K_FACTOR, |&k: &f64| {
+        k > 0.0 && k.is_finite()
+    })
+}
+
+/// Timezone used when no valid `DISPLAY_TZ` is set
+pub const DEFAULT_DISPLAY_TZ: Tz = chrono_tz::America::New_York;
+
+/// Timezone kickoff times are shown in, from `DISPLAY_TZ` (an IANA name like
+/// "America/Chicago"), defaulting to US Eastern
+pub fn display_timezone() -> Tz {
+    parse_env("DISPLAY_TZ", DEFAULT_DISPLAY_TZ, |_| true)
+}
+
+/// Request timeout used when no valid `REQUEST_TIMEOUT_SECS` is set
+pub const DEFAULT_REQUEST_TIMEOUT_SECS: u64 = 15;
+
+/// How long an HTTP request may take before it fails as a timeout, from
+/// `REQUEST_TIMEOUT_SECS`
+pub fn request_timeout() -> Duration {
+    Duration::from_secs(parse_env(
+        "REQUEST_TIMEOUT_SECS",
+        DEFAULT_REQUEST_TIMEOUT_SECS,
+        |&secs| secs > 0,
+    ))
+}
+
+/// Odds API credits to keep in reserve when no valid `ODDS_API_MIN_CREDITS` is set
+pub const DEFAULT_MIN_ODDS_CREDITS: u32 = 100;
+
+/// Remaining Odds API credits below which optional fetches (CBB odds) are skipped,
+/// from `ODDS_API_MIN_CREDITS`
+pub fn min_odds_credits() -> u32 {
+    parse_env("ODDS_API_MIN_CREDITS", DEFAULT_MIN_ODDS_CREDITS, |_| true)
+}
+
+/// Requests allowed in flight at once when no valid `MAX_CONCURRENT_FETCHES` is set
+pub const DEFAULT_MAX_CONCURRENT_FETCHES: usize = 5;
+
+/// Most requests a batch fetch keeps in flight at once, from `MAX_CONCURRENT_FETCHES`
+pub fn max_concurrent_fetches() -> usize {
+    parse_env(
+        "MAX_CONCURRENT_FETCHES",
+        DEFAULT_MAX_CONCURRENT_FETCHES,
+        |&max| max > 0,
+    )
+}
+
+/// Odds API regions queried when neither `ODDS_API_REGIONS` nor `ODDS_API_BOOKMAKERS` is set
+pub const DEFAULT_ODDS_API_REGIONS: &str = "us";
+
+/// Which books the Odds API is asked for, as a query parameter and value
+/// `ODDS_API_BOOKMAKERS` (comma-separated Odds API bookmaker keys, e.g.
+/// "draftkings,fanduel") takes precedence over `ODDS_API_REGIONS` (e.g. "us,us2").
+pub fn odds_api_books() -> (&'static str, String) {
+    let setting = |name: &str| {
+        std::env::var(name)
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+    match setting("ODDS_API_BOOKMAKERS") {
+        Some(bookmakers) => ("bookmakers", bookmakers),
+        None => (
+            "regions",
+            setting("ODDS_API_REGIONS").unwrap_or_else(|| DEFAULT_ODDS_API_REGIONS.to_string()),
+        ),
+    }
+}
+
+/// Estimated Odds API credits a run may use without `--confirm` when no valid
+/// `CONFIRM_ABOVE_CREDITS` is set (moneylines and spreads for CFB and CBB in one region)
+pub const DEFAULT_CONFIRM_ABOVE_CREDITS: u32 = 4;
+
+/// Estimated Odds API credits above which the CLI asks for `--confirm`, from
+/// `CONFIRM_ABOVE_CREDITS`
+pub fn confirm_above_credits() -> u32 {
+    parse_env(
+        "CONFIRM_ABOVE_CREDITS",
+        DEFAULT_CONFIRM_ABOVE_CREDITS,
+        |_| true,
+    )
+}
+
+/// How many EV bets to keep for each market, from `TOP_N` (default `DEFAULT_TOP_N`)
+/// `TOP_N=0` keeps every positive EV bet.
+pub fn top_n_from_env() -> Option<usize> {
+    top_n_limit(parse_env("TOP_N", DEFAULT_TOP_N, |_| true))
+}
+
+/// Minimum EV in percent from `MIN_EV`, e.g. `MIN_EV=3` hides bets below 3% EV
+pub fn min_ev_percent() -> f64 {
+    parse_env("MIN_EV", 0.0, |min_ev: &f64| min_ev.is_finite())
+}
+
+/// Largest edge in percent from `MAX_EDGE`, e.g. `MAX_EDGE=25` hides bets whose edge is
+/// over 25 points as likely stale lines
+pub fn max_edge_percent() -> Option<f64> {
+    parse_optional_env::<f64>("MAX_EDGE").filter(|max_edge| *max_edge > 0.0)
+}
+
+/// EV bet screening from `MIN_EV` and `MAX_EDGE` (both in percent)
+pub fn ev_filter() -> EvFilter {
+    EvFilter {
+        min_ev: min_ev_percent() / 100.0,
+        max_edge: max_edge_percent().map(|max_edge| max_edge / 100.0),
+        ..EvFilter::default()
+    }
+}
+
+/// Minutes between the web server's background refreshes, from `REFRESH_MINUTES`
+/// `None` (the default) turns refreshing off, since every refresh uses API credits.
+pub fn refresh_minutes() -> Option<u64> {
+    parse_optional_env("REFRESH_MINUTES").filter(|&minutes| minutes > 0)
+}
+
+/// Weight of arbitrage against EV bets in the opportunity feed, from `FEED_ARB_WEIGHT`
+pub fn feed_arb_weight() -> Option<f64> {
+    parse_optional_env("FEED_ARB_WEIGHT")
+}
+
+/// Books that must quote a market when no valid `MIN_BOOKS_IN_MARKET` is set
+pub const DEFAULT_MIN_BOOKS_IN_MARKET: usize = 2;
+
+/// Arbitrage sanity checks, from `MIN_BOOKS_IN_MARKET`, `ARB_MAX_PRICE_GAP` (percentage
+/// points of implied probability from the market median) and `ALLOW_SAME_BOOK_ARBS=1`
+pub fn arb_filter() -> ArbFilter {
+    ArbFilter {
+        require_distinct_books: !env_flag("ALLOW_SAME_BOOK_ARBS"),
+        min_books_in_market: parse_env(
+            "MIN_BOOKS_IN_MARKET",
+            DEFAULT_MIN_BOOKS_IN_MARKET,
+            |&books| books > 0,
+        ),
+        max_median_gap: parse_optional_env::<f64>("ARB_MAX_PRICE_GAP")
+            .filter(|gap| *gap > 0.0)
+            .map(|gap| gap / 100.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_layers_over_env_file() {
+        let dir = std::env::temp_dir().join(format!("cfb_betting_profile_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join(".env"),
+            "CFB_TEST_PROFILE_SHARED=base\nCFB_TEST_PROFILE_BASE_ONLY=base\n",
+        )
+        .unwrap();
+        std::fs::write(dir.join(".env.scan"), "CFB_TEST_PROFILE_SHARED=scan\n").unwrap();
+
+        load_env_from(&dir, Some("scan")).unwrap();
+        let missing = load_env_from(&dir, Some("live"));
+        std::fs::remove_dir_all(&dir).ok();
+
+        assert_eq!(std::env::var("CFB_TEST_PROFILE_SHARED").unwrap(), "scan");
+        assert_eq!(std::env::var("CFB_TEST_PROFILE_BASE_ONLY").unwrap(), "base");
+        assert!(matches!(missing, Err(CfbBettingError::MissingProfile(_))));
+    }
+
+    #[test]
+    fn test_profile_arg() {
+        let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            profile_arg(args(&["cli", "--use-cache", "--profile", "scan", "arb"])),
+            Some("scan".to_string())
+        );
+        assert_eq!(
+            profile_arg(args(&["cli", "--profile=live"])),
+            Some("live".to_string())
+        );
+    }
+}
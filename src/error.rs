@@ -0,0 +1,153 @@
+use reqwest::StatusCode;
+use std::fmt::Display;
+use thiserror::Error;
+
+/// Result type used throughout the library
+pub type Result<T, E = CfbBettingError> = std::result::Result<T, E>;
+
+/// Errors returned by the library's API clients, scrapers and data helpers
+/// The binaries wrap these in `anyhow`; library consumers can match on them to decide
+/// whether a failure is worth retrying.
+#[derive(Debug, Error)]
+pub enum CfbBettingError {
+    /// A request to an external service failed or came back with an error status
+    #[error("{service} request failed: {message}")]
+    ApiError {
+        service: &'static str,
+        /// HTTP status, when the service responded at all
+        status: Option<u16>,
+        message: String,
+    },
+
+    /// A request to an external service got no response within the request timeout
+    #[error("{service} request timed out")]
+    Timeout { service: &'static str },
+
+    /// A response, page or file couldn't be parsed
+    #[error("Failed to parse {what}: {message}")]
+    ParseError { what: String, message: String },
+
+    /// A cache file couldn't be read, written or understood
+    #[error("Cache file {path}: {message}")]
+    CacheError { path: String, message: String },
+
+    /// A required API key isn't set in the environment
+    #[error("{0} not set; copy .env.example to .env and add your key")]
+    MissingApiKey(String),
+
+    /// A `--profile` was asked for but its `.env.<profile>` file doesn't exist
+    #[error("Profile file {0} not found")]
+    MissingProfile(String),
+
+    /// There are no games to bet on because the sport's season isn't running
+    #[error("{0} is out of season")]
+    OutOfSeason(String),
+
+    /// Reading or writing a local file (e.g. a CSV export) failed
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+impl CfbBettingError {
+    /// A request to `service` that failed before or while reading the response
+    /// Timeouts come back as `Timeout` so callers can fall back to a cache right away.
+    pub fn api(service: &'static str, error: reqwest::Error) -> Self {
+        if error.is_timeout() {
+            return Self::Timeout { service };
+        }
+        Self::ApiError {
+            service,
+            status: error.status().map(|status| status.as_u16()),
+            message: error.to_string(),
+        }
+    }
+
+    /// A response from `service` with a non-success status
+    pub fn api_status(service: &'static str, status: StatusCode) -> Self {
+        Self::ApiError {
+            service,
+            status: Some(status.as_u16()),
+            message: format!("returned error: {}", status),
+        }
+    }
+
+    pub fn parse(what: impl Into<String>, error: impl Display) -> Self {
+        Self::ParseError {
+            what: what.into(),
+            message: error.to_string(),
+        }
+    }
+
+    pub fn cache(path: impl Into<String>, error: impl Display) -> Self {
+        Self::CacheError {
+            path: path.into(),
+            message: error.to_string(),
+        }
+    }
+
+    /// Whether the service rejected the request for exceeding its rate limit or quota
+    pub fn is_rate_limited(&self) -> bool {
+        matches!(
+            self,
+            Self::ApiError {
+                status: Some(429),
+                ..
+            }
+        )
+    }
+
+    /// Whether the service didn't respond within the request timeout
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, Self::Timeout { .. })
+    }
+
+    /// Whether trying again later might succeed: rate limits, server errors and requests
+    /// that never got a response
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::ApiError { status, .. } => {
+                status.is_none_or(|status| status == 429 || status >= 500)
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_classification() {
+        let rate_limited = CfbBettingError::api_status("Odds API", StatusCode::TOO_MANY_REQUESTS);
+        assert!(rate_limited.is_rate_limited());
+        assert!(rate_limited.is_retryable());
+
+        let unauthorized = CfbBettingError::api_status("Odds API", StatusCode::UNAUTHORIZED);
+        assert!(!unauthorized.is_rate_limited());
+        assert!(!unauthorized.is_retryable());
+
+        let server_error = CfbBettingError::api_status("Kalshi", StatusCode::INTERNAL_SERVER_ERROR);
+        assert!(server_error.is_retryable());
+
+        // A hung service isn't retried; callers go straight to their cache
+        let timeout = CfbBettingError::Timeout {
+            service: "Odds API",
+        };
+        assert!(timeout.is_timeout());
+        assert!(!timeout.is_retryable());
+        assert_eq!(timeout.to_string(), "Odds API request timed out");
+
+        let parse_error = CfbBettingError::parse("Odds API response", "expected value");
+        assert!(!parse_error.is_retryable());
+        assert_eq!(
+            parse_error.to_string(),
+            "Failed to parse Odds API response: expected value"
+        );
+
+        assert_eq!(
+            CfbBettingError::MissingApiKey("ODDS_API_KEY".to_string()).to_string(),
+            "ODDS_API_KEY not set; copy .env.example to .env and add your key"
+        );
+    }
+}
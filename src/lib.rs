@@ -1,4 +1,6 @@
 pub mod api;
+pub mod config;
+pub mod error;
 pub mod models;
 pub mod scrapers;
 pub mod utils;
@@ -8,22 +10,39 @@ pub use models::*;
 pub use scrapers::*;
 pub use utils::*;
 
-use anyhow::{Context, Result};
 pub use api::game_results_api::{CbbGameResult, GameResult, GameResultsApiClient};
-pub use api::kalshi_api::KalshiClient;
+pub use api::kalshi_api::{KalshiClient, KalshiCredentials};
 pub use api::odds_api::OddsApiClient;
 use chrono::prelude::*;
+use chrono_tz::Tz;
+pub use config::{
+    display_timezone, min_odds_credits, next_game_day_only, offline_mode, request_timeout,
+    top_n_from_env, DEFAULT_DISPLAY_TZ, DEFAULT_MIN_ODDS_CREDITS, DEFAULT_REQUEST_TIMEOUT_SECS,
+};
+pub use error::CfbBettingError;
+use error::Result;
+use scrapers::prediction_tracker::GamePrediction;
 pub use scrapers::prediction_tracker::PredictionTrackerScraper;
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::future::Future;
 use std::path::Path;
+use std::time::Instant;
+use tracing::{debug, info, instrument, warn, Level};
+use tracing_subscriber::filter::Targets;
+use tracing_subscriber::prelude::*;
 use utils::arbitrage::{
-    find_moneyline_arbitrage, find_spread_arbitrage, MoneylineArbitrage, SpreadArbitrage,
+    dedupe_games, find_moneyline_arbitrage, find_spread_arbitrage, find_spread_middles,
+    MoneylineArbitrage, SpreadArbitrage, SpreadMiddle,
+};
+use utils::backtest::{closing_lines, OddsSnapshot};
+use utils::data::{
+    append_snapshot, load_all_snapshots, load_boosts_from_csv, load_from_cache, save_to_cache,
 };
-use utils::data::{load_from_cache, save_to_cache};
 use utils::ev_analysis::{
-    compare_ev_bets_to_results, compare_spread_ev_bets_to_results, find_top_ev_bets,
-    find_top_spread_ev_bets, BetResult, EvBetRecommendation, SpreadBetResult,
-    SpreadEvBetRecommendation,
+    add_closing_lines, add_spread_closing_lines, compare_ev_bets_to_results,
+    compare_spread_ev_bets_to_results, find_market_value_bets, find_top_ev_bets_with_diagnostics,
+    find_top_spread_ev_bets, BetResult, BoostConfig, EvBetRecommendation, EvFilter, GradingSummary,
+    MarginStdDev, Skipped, SpreadBetResult, SpreadEvBetRecommendation, SpreadModel,
 };
 
 /// All the data we want to display on the web page
@@ -35,84 +54,444 @@ pub struct BettingData {
     pub cfb_spread_arbs: Vec<SpreadArbitrage>,
     pub cbb_moneyline_arbs: Vec<MoneylineArbitrage>,
     pub cbb_spread_arbs: Vec<SpreadArbitrage>,
+    /// CFB spread middles with positive expected profit
+    #[serde(default)]
+    pub cfb_spread_middles: Vec<SpreadMiddle>,
+    /// CBB moneyline value against the de-vigged market consensus (there's no CBB model)
+    #[serde(default)]
+    pub cbb_moneyline_bets: Vec<EvBetRecommendation>,
     pub cfb_game_results: Vec<GameResult>,
     pub cbb_game_results: Vec<CbbGameResult>,
     pub cfb_moneyline_bet_results: Vec<BetResult>,
     pub cfb_spread_bet_results: Vec<SpreadBetResult>,
+    /// Raw CFB odds the bets were computed from, used for line movement
+    #[serde(default)]
+    pub cfb_games_with_odds: Vec<(Game, Vec<BettingOdds>)>,
+    /// Every prediction source's predictions, used to compare model accuracy
+    #[serde(default)]
+    pub predictions: Vec<GamePrediction>,
+    /// CFB games that produced no moneyline bet, and why
+    #[serde(default)]
+    pub cfb_skipped_games: Vec<Skipped>,
+}
+
+impl BettingData {
+    /// Save everything, as rendered, to one versioned cache file
+    /// Useful for restarting the web server without re-fetching, or attaching to a bug report.
+    pub fn save(&self, path: &str) -> Result<()> {
+        save_to_cache(self, path)
+    }
+
+    /// Load data written by [`BettingData::save`] (or any older cache version of it)
+    pub fn load(path: &str) -> Result<Self> {
+        load_from_cache(path)
+    }
+
+    /// Grade the CFB recommendations against `cfb_game_results`, replacing any earlier
+    /// grades
+    /// The closing line is whichever of the current and previous odds snapshots, and any
+    /// captured closing odds, was taken last before kickoff.
+    pub fn grade_bets(&mut self) {
+        let mut moneyline_results =
+            compare_ev_bets_to_results(&self.cfb_moneyline_bets, &self.cfb_game_results);
+        let mut spread_results =
+            compare_spread_ev_bets_to_results(&self.cfb_spread_bets, &self.cfb_game_results);
+
+        let mut snapshots = vec![OddsSnapshot::from_games(self.cfb_games_with_odds.clone())];
+        if let Ok(previous) = load_from_cache(PREVIOUS_ODDS_CACHE_FILE) {
+            snapshots.push(OddsSnapshot::from_games(previous));
+        }
+        if Path::new(CLOSING_ODDS_FILE).exists() {
+            match load_all_snapshots(CLOSING_ODDS_FILE) {
+                Ok(closing) => snapshots.extend(closing),
+                Err(e) => {
+                    warn!(file = CLOSING_ODDS_FILE, error = %e, "Failed to load closing odds")
+                }
+            }
+        }
+        let closing = closing_lines(&snapshots);
+        add_closing_lines(&mut moneyline_results, &closing);
+        add_spread_closing_lines(&mut spread_results, &closing);
+
+        self.cfb_moneyline_bet_results = moneyline_results;
+        self.cfb_spread_bet_results = spread_results;
+    }
+
+    /// Win/loss record of the graded CFB bets, moneyline and spread together
+    pub fn grading_summary(&self) -> GradingSummary {
+        GradingSummary::from_graded(
+            self.cfb_moneyline_bet_results
+                .iter()
+                .filter_map(|result| Some((result.stake, result.profit()?)))
+                .chain(
+                    self.cfb_spread_bet_results
+                        .iter()
+                        .filter_map(|result| Some((result.stake, result.profit()?))),
+                ),
+        )
+    }
+
+    /// CFB moneyline EV bets recomputed from the stored odds and predictions with `filter`
+    /// Lets a page narrow the bets (e.g. to one conference) before the top N are taken.
+    /// Data without stored odds, like the offline fixture, screens the stored bets instead.
+    pub async fn cfb_moneyline_bets_matching(
+        &self,
+        filter: &EvFilter,
+        top_n: Option<usize>,
+    ) -> Vec<EvBetRecommendation> {
+        if self.cfb_games_with_odds.is_empty() {
+            return screen_stored_bets(&self.cfb_moneyline_bets, filter, top_n, |bet| {
+                (&bet.home_team, &bet.away_team, bet.effective_ev(), bet.edge)
+            });
+        }
+        find_top_ev_bets_with_diagnostics(
+            &self.cfb_games_with_odds,
+            &merge_predictions(self.predictions.clone()),
+            top_n,
+            None,
+            filter,
+            &configured_boosts(),
+        )
+        .await
+        .map(|(bets, _)| bets)
+        .unwrap_or_default()
+    }
+
+    /// CFB spread EV bets recomputed from the stored odds and predictions with `filter`
+    /// (see [`BettingData::cfb_moneyline_bets_matching`])
+    pub async fn cfb_spread_bets_matching(
+        &self,
+        filter: &EvFilter,
+        top_n: Option<usize>,
+    ) -> Vec<SpreadEvBetRecommendation> {
+        if self.cfb_games_with_odds.is_empty() {
+            return screen_stored_bets(&self.cfb_spread_bets, filter, top_n, |bet| {
+                (&bet.home_team, &bet.away_team, bet.expected_value, bet.edge)
+            });
+        }
+        find_top_spread_ev_bets(
+            &self.cfb_games_with_odds,
+            &merge_predictions(self.predictions.clone()),
+            top_n,
+            None,
+            filter,
+            configured_spread_model(),
+        )
+        .await
+        .unwrap_or_default()
+    }
+}
+
+/// Stored bets (already sorted by EV) that `filter` keeps, truncated to `top_n`
+/// `fields` gives a bet's home team, away team, EV and edge.
+fn screen_stored_bets<T: Clone>(
+    bets: &[T],
+    filter: &EvFilter,
+    top_n: Option<usize>,
+    fields: impl Fn(&T) -> (&String, &String, f64, f64),
+) -> Vec<T> {
+    bets.iter()
+        .filter(|bet| {
+            let (home_team, away_team, expected_value, edge) = fields(bet);
+            filter.keeps(expected_value, edge) && filter.includes_matchup(home_team, away_team)
+        })
+        .take(top_n.unwrap_or(usize::MAX))
+        .cloned()
+        .collect()
+}
+
+/// Odds boosts from `BOOSTS_FILE`
+/// A bad boosts file shouldn't take the page down; price without boosts instead.
+fn configured_boosts() -> BoostConfig {
+    match config::boosts_file() {
+        Some(path) => load_boosts_from_csv(&path).unwrap_or_else(|e| {
+            warn!(error = %e, file = %path, "Failed to load odds boosts, ignoring them");
+            BoostConfig::default()
+        }),
+        None => BoostConfig::default(),
+    }
+}
+
+/// Spread model from the defaults and `SCRAPED_COVER`
+fn configured_spread_model() -> SpreadModel {
+    SpreadModel {
+        margin_std_dev: MarginStdDev::default(),
+        scraped_cover: config::scraped_cover(),
+    }
+}
+
+/// Set up logging for the binaries
+/// `RUST_LOG` takes comma-separated `target=level` directives, e.g.
+/// `RUST_LOG=cfb_betting_ev::api=debug,info`. Defaults to `info`.
+pub fn init_logging() {
+    let filter = std::env::var("RUST_LOG")
+        .ok()
+        .and_then(|directives| directives.parse::<Targets>().ok())
+        .unwrap_or_else(|| Targets::new().with_default(Level::INFO));
+
+    tracing_subscriber::registry()
+        .with(tracing_subscriber::fmt::layer())
+        .with(filter)
+        .init();
+}
+
+/// Data from [`load_or_fetch`], and whether it was just fetched rather than read from the
+/// cache
+struct Loaded<T> {
+    data: T,
+    fresh: bool,
+}
+
+impl<T> Loaded<T> {
+    fn cached(data: T) -> Self {
+        Self { data, fresh: false }
+    }
+}
+
+/// Load data from the cache file when allowed, otherwise fetch it and refresh the cache
+/// A failed fetch is retried once unless it timed out. If the retry fails too, the cache is
+/// served stale (and logged) so an outage doesn't take the site down; with no cache it's an
+/// error. Fresh data first copies the old cache to `previous_file`, if one is given.
+#[instrument(skip(fetch))]
+async fn load_or_fetch<T, F, Fut>(
+    use_cache: bool,
+    cache_file: &str,
+    previous_file: Option<&str>,
+    fetch: F,
+) -> Result<Loaded<T>>
+where
+    T: Serialize + DeserializeOwned,
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    if use_cache && Path::new(cache_file).exists() {
+        debug!("Loading from cache");
+        return load_from_cache(cache_file).map(Loaded::cached);
+    }
+
+    let start = Instant::now();
+    let fetched = match fetch().await {
+        Ok(data) => Ok(data),
+        Err(e) if e.is_timeout() => Err(e),
+        Err(e) => {
+            warn!(error = %e, "Fetch failed, retrying once");
+            fetch().await
+        }
+    };
+    let data = match fetched {
+        Ok(data) => data,
+        Err(e) if Path::new(cache_file).exists() => {
+            warn!(error = %e, "Fetch failed, serving stale data from cache");
+            return load_from_cache(cache_file).map(Loaded::cached);
+        }
+        Err(e) => return Err(e),
+    };
+    if let Some(previous_file) = previous_file {
+        if Path::new(cache_file).exists() {
+            std::fs::copy(cache_file, previous_file)
+                .map_err(|e| CfbBettingError::cache(previous_file, e))?;
+        }
+    }
+    save_to_cache(&data, cache_file)?;
+    debug!(
+        elapsed_ms = start.elapsed().as_millis() as u64,
+        "Fetched and cached"
+    );
+    Ok(Loaded { data, fresh: true })
+}
+
+/// CFB odds cache file
+pub const ODDS_CACHE_FILE: &str = "cache/odds_cache.json";
+
+/// This week's CFB game results cache file
+pub const CFB_RESULTS_CACHE_FILE: &str = "cache/cfb_results_cache.json";
+
+/// Every fresh CFB odds fetch, appended as JSON Lines (see `data::append_snapshot`)
+pub const ODDS_HISTORY_FILE: &str = "cache/odds_history.jsonl";
+
+/// Every fresh CBB odds fetch, appended as JSON Lines
+pub const CBB_ODDS_HISTORY_FILE: &str = "cache/cbb_odds_history.jsonl";
+
+/// CFB odds captured just before each kickoff by the CLI's `closing` command, as JSON Lines
+pub const CLOSING_ODDS_FILE: &str = "cache/closing_odds.jsonl";
+
+/// The CFB odds cache as it was before the last fresh fetch replaced it
+pub const PREVIOUS_ODDS_CACHE_FILE: &str = "cache/odds_cache_previous.json";
+
+/// Everything the web server last rendered, written after each successful fetch
+pub const BETTING_DATA_FILE: &str = "cache/betting_data.json";
+
+/// Every settled bet of the season, updated after each grading (see `ledger::update_ledger`)
+pub const LEDGER_FILE: &str = "cache/ledger.json";
+
+/// Bundled betting data served in offline mode
+pub const OFFLINE_FIXTURE_FILE: &str = "fixtures/betting_data.json";
+
+/// Keep only the games on the soonest day that has any, with days taken in `timezone` so a
+/// late Saturday kickoff (early Sunday UTC) still counts as Saturday
+pub fn retain_next_game_day(games_with_odds: &mut Vec<(Game, Vec<BettingOdds>)>, timezone: Tz) {
+    let game_day = |game: &Game| game.commence_time.with_timezone(&timezone).date_naive();
+    if let Some(next_day) = games_with_odds.iter().map(|(game, _)| game_day(game)).min() {
+        games_with_odds.retain(|(game, _)| game_day(game) == next_day);
+    }
+}
+
+/// Format a kickoff time in the given timezone, e.g. "Sat Sep 6, 7:30 PM EDT"
+pub fn format_kickoff(time: DateTime<Utc>, timezone: Tz) -> String {
+    time.with_timezone(&timezone)
+        .format("%a %b %-d, %-I:%M %p %Z")
+        .to_string()
+}
+
+/// HTTP client builder with the request timeout set, used by every API client and scraper
+/// so a hung connection can't block a fetch forever
+pub(crate) fn http_client_builder() -> reqwest::ClientBuilder {
+    reqwest::Client::builder().timeout(request_timeout())
+}
+
+/// Whether enough Odds API credits are left to spend some on optional fetches
+/// A failed usage check is logged and treated as enough, so it never blocks a run.
+async fn has_credits_for_optional_fetches(odds_client: &OddsApiClient) -> bool {
+    let threshold = min_odds_credits();
+    match odds_client.check_usage().await {
+        Ok(usage) => usage.has_at_least(threshold),
+        Err(error) => {
+            warn!(error = %error, "Failed to check Odds API usage, not enforcing the budget");
+            true
+        }
+    }
+}
+
+/// Number of EV bets kept for each market unless `TOP_N` or `--top` says otherwise
+pub const DEFAULT_TOP_N: usize = 30;
+
+/// The `top_n` argument of `find_top_ev_bets` for a configured count, where 0 means every
+/// positive EV bet (`None`)
+pub fn top_n_limit(count: usize) -> Option<usize> {
+    (count > 0).then_some(count)
+}
+
+/// Read a required API key from the environment
+/// Returns a setup hint instead of panicking when the key is missing.
+pub fn required_env_var(name: &str) -> Result<String> {
+    match std::env::var(name) {
+        Ok(value) if !value.trim().is_empty() => Ok(value),
+        _ => Err(CfbBettingError::MissingApiKey(name.to_string())),
+    }
 }
 
-/// Fetch all betting data from APIs or cache
-pub async fn fetch_all_betting_data(use_cache: bool) -> Result<BettingData> {
+/// Append freshly fetched odds to a line history file, logging instead of failing
+pub fn record_odds_history(games_with_odds: &[(Game, Vec<BettingOdds>)], history_file: &str) {
+    let snapshot = OddsSnapshot::from_games(games_with_odds.to_vec());
+    if let Err(e) = append_snapshot(&snapshot, history_file) {
+        warn!(file = history_file, error = %e, "Failed to append odds history");
+    }
+}
+
+/// Fetch all betting data from APIs or cache (or the bundled fixture when `OFFLINE=1`)
+/// Only EV bets `filter` keeps are listed, at most `top_n` per market (`None` keeps them
+/// all).
+#[instrument]
+pub async fn fetch_all_betting_data(
+    use_cache: bool,
+    filter: &EvFilter,
+    top_n: Option<usize>,
+) -> Result<BettingData> {
+    let start = Instant::now();
+
     // Load .env file
     dotenv::dotenv().ok();
 
+    if offline_mode() {
+        info!(
+            fixture = OFFLINE_FIXTURE_FILE,
+            "Offline mode, loading fixture"
+        );
+        let mut data: BettingData = load_from_cache(OFFLINE_FIXTURE_FILE)?;
+        data.cfb_moneyline_bets =
+            screen_stored_bets(&data.cfb_moneyline_bets, filter, top_n, |bet| {
+                (&bet.home_team, &bet.away_team, bet.effective_ev(), bet.edge)
+            });
+        data.cfb_spread_bets = screen_stored_bets(&data.cfb_spread_bets, filter, top_n, |bet| {
+            (&bet.home_team, &bet.away_team, bet.expected_value, bet.edge)
+        });
+        return Ok(data);
+    }
+
     // Get API key from environment
-    let odds_api_key = std::env::var("ODDS_API_KEY").expect("ODDS_API_KEY not set in .env file");
-    let cfb_api_key = std::env::var("COLLEGE_FOOTBALL_DATA_API_KEY")
-        .expect("COLLEGE_FOOTBALL_DATA_API_KEY not set in .env file");
+    let odds_api_key = required_env_var("ODDS_API_KEY")?;
+    let cfb_api_key = required_env_var("COLLEGE_FOOTBALL_DATA_API_KEY")?;
 
     // Create clients
     let odds_client = OddsApiClient::new(odds_api_key);
-    let prediction_scraper = PredictionTrackerScraper::new();
+    let prediction_sources = default_prediction_sources();
     let game_results_client = GameResultsApiClient::new(cfb_api_key);
 
     // Cache file paths
-    let odds_cache_file = "cache/odds_cache.json";
+    let odds_cache_file = ODDS_CACHE_FILE;
     let predictions_cache_file = "cache/predictions_cache.json";
     let cbb_cache_file = "cache/cbb_odds_cache.json";
-    let cfb_results_cache_file = "cache/cfb_results_cache.json";
     //let cbb_results_cache_file = "cache/cbb_results_cache.json";
 
-    // Fetch predictions
-    let predictions = if use_cache && Path::new(predictions_cache_file).exists() {
-        load_from_cache(predictions_cache_file)?
-    } else {
-        let predictions = prediction_scraper
-            .fetch_game_predictions()
-            .await
-            .context("Failed to fetch predictions")?;
-        save_to_cache(&predictions, predictions_cache_file)?;
-        predictions
-    };
+    let now = Local::now();
+    let year = now.year() as u32;
+    let week = now.iso_week().week() as u8;
 
-    // Fetch college football odds
-    let cfb_games_with_odds = if use_cache && Path::new(odds_cache_file).exists() {
-        load_from_cache(odds_cache_file)?
-    } else {
-        let games_with_odds = odds_client
-            .fetch_games(Sport::CollegeFootball)
-            .await
-            .context("Failed to fetch CFB odds")?;
-        save_to_cache(&games_with_odds, odds_cache_file)?;
-        games_with_odds
-    };
+    // CBB odds are optional, so only spend credits on them while we're above the reserve
+    let cbb_needs_fetch = !(use_cache && Path::new(cbb_cache_file).exists());
+    let skip_cbb_odds = cbb_needs_fetch && !has_credits_for_optional_fetches(&odds_client).await;
+    if skip_cbb_odds {
+        warn!(
+            min_credits = min_odds_credits(),
+            "Odds API credits are below the reserve, skipping CBB odds"
+        );
+    }
 
-    // Fetch college basketball odds
-    let cbb_games_with_odds = if use_cache && Path::new(cbb_cache_file).exists() {
-        load_from_cache(cbb_cache_file)?
-    } else {
-        let games_with_odds = odds_client
-            .fetch_games(Sport::CollegeBasketball)
+    // The sources are independent, so fetch them concurrently
+    // Fresh CFB odds keep the odds they replace, so line movement has something to compare
+    // against
+    let (all_predictions, cfb_odds, cbb_odds, cfb_game_results) = tokio::try_join!(
+        load_or_fetch(use_cache, predictions_cache_file, None, || {
+            fetch_all_sources(&prediction_sources)
+        }),
+        load_or_fetch(
+            use_cache,
+            odds_cache_file,
+            Some(PREVIOUS_ODDS_CACHE_FILE),
+            || odds_client.fetch_games(Sport::CollegeFootball)
+        ),
+        async {
+            if skip_cbb_odds {
+                return Ok(Loaded::cached(Vec::new()));
+            }
+            load_or_fetch(use_cache, cbb_cache_file, None, || {
+                odds_client.fetch_games(Sport::CollegeBasketball)
+            })
             .await
-            .context("Failed to fetch CBB odds")?;
-        save_to_cache(&games_with_odds, cbb_cache_file)?;
-        games_with_odds
-    };
+        },
+        load_or_fetch(use_cache, CFB_RESULTS_CACHE_FILE, None, || {
+            game_results_client.fetch_cfb_game_results(year, week)
+        }),
+    )?;
+    let (all_predictions, cfb_game_results) = (all_predictions.data, cfb_game_results.data);
+    let mut cfb_games_with_odds = dedupe_games(cfb_odds.data);
+    let mut cbb_games_with_odds = dedupe_games(cbb_odds.data);
+    // Only freshly fetched odds go into the line history (a stale cache served after a failed
+    // fetch is already there); losing a snapshot isn't worth failing over
+    if cfb_odds.fresh {
+        record_odds_history(&cfb_games_with_odds, ODDS_HISTORY_FILE);
+    }
+    if cbb_odds.fresh {
+        record_odds_history(&cbb_games_with_odds, CBB_ODDS_HISTORY_FILE);
+    }
+    if next_game_day_only() {
+        let timezone = display_timezone();
+        retain_next_game_day(&mut cfb_games_with_odds, timezone);
+        retain_next_game_day(&mut cbb_games_with_odds, timezone);
+    }
 
-    // Fetch college football game results
-    let cfb_game_results = if use_cache && Path::new(cfb_results_cache_file).exists() {
-        load_from_cache(cfb_results_cache_file)?
-    } else {
-        let now = Local::now();
-        let year = now.year() as u32;
-        let week = now.iso_week().week() as u8;
-        let game_results = game_results_client
-            .fetch_cfb_game_results(year, week)
-            .await
-            .context("Failed to fetch CFB game results")?;
-        save_to_cache(&game_results, cfb_results_cache_file)?;
-        game_results
-    };
+    // The EV model uses the most trusted source's prediction for each game
+    let predictions = merge_predictions(all_predictions.clone());
 
     // Fetch college basketball game results
     // let cbb_game_results = if use_cache && Path::new(cbb_results_cache_file).exists() {
@@ -129,36 +508,294 @@ pub async fn fetch_all_betting_data(use_cache: bool) -> Result<BettingData> {
     // };
     let cbb_game_results = vec![];
 
-    // Calculate EV bets and arbitrage opportunities (None = all positive EV bets)
-    let cfb_moneyline_bets = find_top_ev_bets(&cfb_games_with_odds, &predictions, None)
-        .await
-        .unwrap_or_default();
+    let boosts = configured_boosts();
 
-    let cfb_spread_bets = find_top_spread_ev_bets(&cfb_games_with_odds, &predictions, None)
+    // Calculate EV bets and arbitrage opportunities (top_n of None = all bets the filter keeps)
+    let (cfb_moneyline_bets, cfb_skipped_games) = find_top_ev_bets_with_diagnostics(
+        &cfb_games_with_odds,
+        &predictions,
+        top_n,
+        None,
+        filter,
+        &boosts,
+    )
+    .await
+    .unwrap_or_default();
+
+    let cfb_spread_bets = find_top_spread_ev_bets(
+        &cfb_games_with_odds,
+        &predictions,
+        top_n,
+        None,
+        filter,
+        configured_spread_model(),
+    )
+    .await
+    .unwrap_or_default();
+
+    // Arbitrage only counts on markets enough books agree on
+    let arb_filter = config::arb_filter();
+    let cfb_arb_games = arb_filter.screen(&cfb_games_with_odds);
+    let cbb_arb_games = arb_filter.screen(&cbb_games_with_odds);
+    let cfb_moneyline_arbs = find_moneyline_arbitrage(&cfb_arb_games, &arb_filter)?;
+    let cfb_spread_arbs = find_spread_arbitrage(&cfb_arb_games, &arb_filter)?;
+    let cfb_spread_middles = find_spread_middles(&cfb_arb_games, &arb_filter)?;
+    let cbb_moneyline_arbs = find_moneyline_arbitrage(&cbb_arb_games, &arb_filter)?;
+    let cbb_spread_arbs = find_spread_arbitrage(&cbb_arb_games, &arb_filter)?;
+    let cbb_moneyline_bets = find_market_value_bets(&cbb_games_with_odds, top_n, None, filter)
         .await
         .unwrap_or_default();
 
-    let cfb_moneyline_arbs = find_moneyline_arbitrage(&cfb_games_with_odds)?;
-    let cfb_spread_arbs = find_spread_arbitrage(&cfb_games_with_odds)?;
-    let cbb_moneyline_arbs = find_moneyline_arbitrage(&cbb_games_with_odds)?;
-    let cbb_spread_arbs = find_spread_arbitrage(&cbb_games_with_odds)?;
-
-    // Compare bets to actual game results
-    let cfb_moneyline_bet_results =
-        compare_ev_bets_to_results(&cfb_moneyline_bets, &cfb_game_results);
-    let cfb_spread_bet_results =
-        compare_spread_ev_bets_to_results(&cfb_spread_bets, &cfb_game_results);
+    info!(
+        cfb_moneyline_bets = cfb_moneyline_bets.len(),
+        cfb_spread_bets = cfb_spread_bets.len(),
+        cfb_arbs = cfb_moneyline_arbs.len() + cfb_spread_arbs.len(),
+        cbb_arbs = cbb_moneyline_arbs.len() + cbb_spread_arbs.len(),
+        cbb_moneyline_bets = cbb_moneyline_bets.len(),
+        elapsed_ms = start.elapsed().as_millis() as u64,
+        "Loaded betting data"
+    );
 
-    Ok(BettingData {
+    let mut data = BettingData {
         cfb_moneyline_bets,
         cfb_spread_bets,
         cfb_moneyline_arbs,
         cfb_spread_arbs,
         cbb_moneyline_arbs,
         cbb_spread_arbs,
+        cfb_spread_middles,
+        cbb_moneyline_bets,
         cfb_game_results,
         cbb_game_results,
-        cfb_moneyline_bet_results,
-        cfb_spread_bet_results,
-    })
+        cfb_moneyline_bet_results: Vec::new(),
+        cfb_spread_bet_results: Vec::new(),
+        cfb_games_with_odds,
+        predictions: all_predictions,
+        cfb_skipped_games,
+    };
+    // Compare bets to actual game results
+    data.grade_bets();
+    Ok(data)
+}
+
+/// Fetch this week's CFB game results and refresh the results cache with them
+/// Before the season starts this is an `OutOfSeason` error and the cache is left alone.
+pub async fn fetch_latest_cfb_results() -> Result<Vec<GameResult>> {
+    let client = GameResultsApiClient::new(required_env_var("COLLEGE_FOOTBALL_DATA_API_KEY")?);
+    let now = Local::now();
+    let results = client
+        .fetch_cfb_game_results(now.year() as u32, now.iso_week().week() as u8)
+        .await?;
+    save_to_cache(&results, CFB_RESULTS_CACHE_FILE)?;
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn test_load_or_fetch_falls_back_to_stale_cache() {
+        let cache_file = std::env::temp_dir().join(format!(
+            "cfb_betting_stale_cache_{}.json",
+            std::process::id()
+        ));
+        let cache_file = cache_file.to_str().unwrap();
+        let attempts = AtomicUsize::new(0);
+        let failing_fetch = || async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err::<Vec<u32>, _>(CfbBettingError::ApiError {
+                service: "Odds API",
+                status: Some(503),
+                message: "outage".to_string(),
+            })
+        };
+
+        // Nothing cached yet: the error comes through after one retry
+        let _ = std::fs::remove_file(cache_file);
+        assert!(load_or_fetch(false, cache_file, None, failing_fetch)
+            .await
+            .is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+
+        // With a cache, the stale data is served instead, and isn't reported as fresh
+        save_to_cache(&vec![1u32, 2, 3], cache_file).unwrap();
+        let loaded = load_or_fetch(false, cache_file, None, failing_fetch)
+            .await
+            .unwrap();
+        assert_eq!(loaded.data, vec![1, 2, 3]);
+        assert!(!loaded.fresh);
+
+        std::fs::remove_file(cache_file).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_load_or_fetch_keeps_previous_cache_only_when_fresh() {
+        let temp_file = |name: &str| {
+            std::env::temp_dir()
+                .join(format!("cfb_betting_{}_{}.json", name, std::process::id()))
+                .to_str()
+                .unwrap()
+                .to_string()
+        };
+        let (cache_file, previous_file) = (temp_file("rotate_cache"), temp_file("rotate_prev"));
+        let _ = std::fs::remove_file(&previous_file);
+        save_to_cache(&vec![1u32], &cache_file).unwrap();
+
+        // A failed fetch leaves the previous snapshot alone
+        let failing_fetch = || async {
+            Err::<Vec<u32>, _>(CfbBettingError::ApiError {
+                service: "Odds API",
+                status: Some(503),
+                message: "outage".to_string(),
+            })
+        };
+        let loaded = load_or_fetch(false, &cache_file, Some(&previous_file), failing_fetch)
+            .await
+            .unwrap();
+        assert!(!loaded.fresh);
+        assert!(!Path::new(&previous_file).exists());
+
+        // A successful one moves the replaced cache there
+        let loaded = load_or_fetch(false, &cache_file, Some(&previous_file), || async {
+            Ok(vec![2u32])
+        })
+        .await
+        .unwrap();
+        assert!(loaded.fresh);
+        assert_eq!(
+            load_from_cache::<Vec<u32>>(&previous_file).unwrap(),
+            vec![1]
+        );
+        assert_eq!(load_from_cache::<Vec<u32>>(&cache_file).unwrap(), vec![2]);
+
+        std::fs::remove_file(cache_file).unwrap();
+        std::fs::remove_file(previous_file).unwrap();
+    }
+
+    #[test]
+    fn test_grade_bets_before_any_game_is_final() {
+        let mut data: BettingData = serde_json::from_value(serde_json::json!({
+            "cfb_moneyline_bets": [{
+                "home_team": "Iowa Hawkeyes",
+                "away_team": "Nebraska Cornhuskers",
+                "team": "Iowa Hawkeyes",
+                "bookmaker": "FanDuel",
+                "odds": 100,
+                "model_prob": 0.6,
+                "implied_prob": 0.5,
+                "expected_value": 0.2,
+                "edge": 0.1,
+            }],
+            "cfb_spread_bets": [],
+            "cfb_moneyline_arbs": [],
+            "cfb_spread_arbs": [],
+            "cbb_moneyline_arbs": [],
+            "cbb_spread_arbs": [],
+            "cfb_game_results": [],
+            "cbb_game_results": [],
+            "cfb_moneyline_bet_results": [],
+            "cfb_spread_bet_results": [],
+        }))
+        .unwrap();
+
+        // No results yet: the bet is listed, ungraded
+        data.grade_bets();
+        assert_eq!(data.cfb_moneyline_bet_results.len(), 1);
+        assert_eq!(data.cfb_moneyline_bet_results[0].bet_won, None);
+        assert_eq!(data.grading_summary(), GradingSummary::default());
+
+        data.cfb_game_results = vec![serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "completed": true,
+            "homeTeam": "Iowa",
+            "homePoints": 24,
+            "awayTeam": "Nebraska",
+            "awayPoints": 14,
+        }))
+        .unwrap()];
+        data.grade_bets();
+        assert_eq!(data.cfb_moneyline_bet_results[0].bet_won, Some(true));
+        assert_eq!(data.grading_summary().wins, 1);
+    }
+
+    #[test]
+    fn test_format_kickoff() {
+        let kickoff = Utc.with_ymd_and_hms(2025, 9, 6, 23, 30, 0).unwrap();
+        assert_eq!(
+            format_kickoff(kickoff, DEFAULT_DISPLAY_TZ),
+            "Sat Sep 6, 7:30 PM EDT"
+        );
+        assert_eq!(
+            format_kickoff(kickoff, chrono_tz::America::Los_Angeles),
+            "Sat Sep 6, 4:30 PM PDT"
+        );
+    }
+
+    #[test]
+    fn test_retain_next_game_day_uses_display_timezone() {
+        let game = |id: &str, commence_time: &str| {
+            let game: Game = serde_json::from_value(serde_json::json!({
+                "id": id,
+                "home_team": "Iowa Hawkeyes",
+                "away_team": "Nebraska Cornhuskers",
+                "commence_time": commence_time,
+                "sport_title": "NCAAF",
+            }))
+            .unwrap();
+            (game, Vec::new())
+        };
+        // Noon and 10:30 PM Eastern on Saturday, then Sunday afternoon
+        let games = vec![
+            game("late", "2025-11-23T03:30:00Z"),
+            game("sunday", "2025-11-23T18:00:00Z"),
+            game("noon", "2025-11-22T17:00:00Z"),
+        ];
+        let ids = |games: &[(Game, Vec<BettingOdds>)]| -> Vec<String> {
+            games.iter().map(|(game, _)| game.id.clone()).collect()
+        };
+
+        let mut eastern = games.clone();
+        retain_next_game_day(&mut eastern, chrono_tz::America::New_York);
+        assert_eq!(ids(&eastern), ["late", "noon"]);
+
+        // In UTC the late kickoff falls on Sunday
+        let mut utc = games;
+        retain_next_game_day(&mut utc, chrono_tz::UTC);
+        assert_eq!(ids(&utc), ["noon"]);
+    }
+
+    #[test]
+    fn test_betting_data_save_and_load() {
+        let file = std::env::temp_dir().join(format!(
+            "cfb_betting_betting_data_{}.json",
+            std::process::id()
+        ));
+        let file = file.to_str().unwrap();
+        let data = BettingData::load(OFFLINE_FIXTURE_FILE).unwrap();
+
+        data.save(file).unwrap();
+        let loaded = BettingData::load(file).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&loaded).unwrap(),
+            serde_json::to_value(&data).unwrap()
+        );
+        std::fs::remove_file(file).unwrap();
+    }
+
+    #[test]
+    fn test_offline_fixture_loads() {
+        let data: BettingData = load_from_cache(OFFLINE_FIXTURE_FILE).unwrap();
+
+        assert!(!data.cfb_moneyline_bets.is_empty());
+        assert!(!data.cfb_spread_bets.is_empty());
+        assert!(!data.cfb_moneyline_arbs.is_empty());
+        assert!(!data.cfb_game_results.is_empty());
+        assert!(data
+            .cfb_moneyline_bet_results
+            .iter()
+            .all(|result| result.bet_won.is_some()));
+    }
 }
@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum Sport {
@@ -15,6 +16,9 @@ pub struct Game {
     pub away_team: String,
     pub commence_time: DateTime<Utc>,
     pub sport_title: String,
+    /// Whether the game is in progress and priced with live (in-play) odds
+    #[serde(default)]
+    pub is_live: bool,
 }
 
 /// Moneyline odds for a team
@@ -32,6 +36,143 @@ pub struct SpreadOdds {
     pub price: i32, // American odds format (e.g., -110, +150)
 }
 
+/// A sportsbook or exchange, normalized from the free-form titles the odds sources report
+/// Titles are matched ignoring case, spaces and punctuation, so "BetOnline.ag", "betonline"
+/// and "BETONLINE AG" are the same book. Unknown books keep their original title.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum Bookmaker {
+    DraftKings,
+    FanDuel,
+    BetMgm,
+    Caesars,
+    BetRivers,
+    Fanatics,
+    EspnBet,
+    HardRock,
+    Pinnacle,
+    Bovada,
+    BetOnline,
+    LowVig,
+    MyBookie,
+    BetUs,
+    Kalshi,
+    Other(String),
+}
+
+/// Known books by normalized key, including older titles for the same book
+const BOOKMAKER_KEYS: &[(&str, Bookmaker)] = &[
+    ("draftkings", Bookmaker::DraftKings),
+    ("fanduel", Bookmaker::FanDuel),
+    ("betmgm", Bookmaker::BetMgm),
+    ("caesars", Bookmaker::Caesars),
+    ("williamhillus", Bookmaker::Caesars),
+    ("betrivers", Bookmaker::BetRivers),
+    ("fanatics", Bookmaker::Fanatics),
+    ("espnbet", Bookmaker::EspnBet),
+    ("hardrockbet", Bookmaker::HardRock),
+    ("pinnacle", Bookmaker::Pinnacle),
+    ("bovada", Bookmaker::Bovada),
+    ("betonlineag", Bookmaker::BetOnline),
+    ("betonline", Bookmaker::BetOnline),
+    ("lowvigag", Bookmaker::LowVig),
+    ("lowvig", Bookmaker::LowVig),
+    ("mybookieag", Bookmaker::MyBookie),
+    ("mybookie", Bookmaker::MyBookie),
+    ("betus", Bookmaker::BetUs),
+    ("kalshi", Bookmaker::Kalshi),
+];
+
+/// Lowercase a bookmaker title and drop everything but letters and digits
+fn normalize_bookmaker_key(title: &str) -> String {
+    title
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+impl Bookmaker {
+    /// Map a bookmaker title (e.g. "DraftKings", "BetOnline.ag", "kalshi") to a known book
+    pub fn from_title(title: &str) -> Self {
+        let key = normalize_bookmaker_key(title);
+        BOOKMAKER_KEYS
+            .iter()
+            .find(|(known, _)| *known == key)
+            .map(|(_, bookmaker)| bookmaker.clone())
+            .unwrap_or_else(|| Bookmaker::Other(title.trim().to_string()))
+    }
+
+    /// Stable lowercase key, suitable for config files and query parameters
+    pub fn key(&self) -> String {
+        match self {
+            Bookmaker::Other(title) => normalize_bookmaker_key(title),
+            known => normalize_bookmaker_key(known.name()),
+        }
+    }
+
+    /// Display name of the book
+    pub fn name(&self) -> &str {
+        match self {
+            Bookmaker::DraftKings => "DraftKings",
+            Bookmaker::FanDuel => "FanDuel",
+            Bookmaker::BetMgm => "BetMGM",
+            Bookmaker::Caesars => "Caesars",
+            Bookmaker::BetRivers => "BetRivers",
+            Bookmaker::Fanatics => "Fanatics",
+            Bookmaker::EspnBet => "ESPN BET",
+            Bookmaker::HardRock => "Hard Rock Bet",
+            Bookmaker::Pinnacle => "Pinnacle",
+            Bookmaker::Bovada => "Bovada",
+            Bookmaker::BetOnline => "BetOnline.ag",
+            Bookmaker::LowVig => "LowVig.ag",
+            Bookmaker::MyBookie => "MyBookie.ag",
+            Bookmaker::BetUs => "BetUS",
+            Bookmaker::Kalshi => "Kalshi",
+            Bookmaker::Other(title) => title,
+        }
+    }
+
+    /// How much the book's line counts toward the market consensus
+    /// Sharp, high-limit books move first on new information, so they count for more.
+    pub fn market_weight(&self) -> f64 {
+        match self {
+            Bookmaker::Pinnacle => 3.0,
+            Bookmaker::BetOnline | Bookmaker::LowVig => 2.0,
+            _ => 1.0,
+        }
+    }
+
+    /// Whether a bookmaker title refers to this book
+    pub fn matches(&self, title: &str) -> bool {
+        Bookmaker::from_title(title).key() == self.key()
+    }
+}
+
+impl fmt::Display for Bookmaker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl From<&str> for Bookmaker {
+    fn from(title: &str) -> Self {
+        Bookmaker::from_title(title)
+    }
+}
+
+impl From<String> for Bookmaker {
+    fn from(title: String) -> Self {
+        Bookmaker::from_title(&title)
+    }
+}
+
+impl From<Bookmaker> for String {
+    fn from(bookmaker: Bookmaker) -> Self {
+        bookmaker.name().to_string()
+    }
+}
+
 /// Betting odds from a sportsbook
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BettingOdds {
@@ -41,3 +182,161 @@ pub struct BettingOdds {
     pub moneyline: Vec<MoneylineOdds>,
     pub spreads: Vec<SpreadOdds>,
 }
+
+impl BettingOdds {
+    /// The normalized bookmaker these odds came from
+    pub fn book(&self) -> Bookmaker {
+        Bookmaker::from_title(&self.bookmaker)
+    }
+
+    /// Whether the moneyline prices both teams in the game
+    /// A suspended market comes back with missing outcomes, leaving only one side (or none).
+    pub fn has_complete_moneyline(&self, game: &Game) -> bool {
+        let prices = |team: &str| self.moneyline.iter().any(|odds| odds.team == team);
+        prices(&game.home_team) && prices(&game.away_team)
+    }
+
+    /// Whether the spreads are either absent or price both teams in the game
+    pub fn has_complete_spreads(&self, game: &Game) -> bool {
+        let prices = |team: &str| self.spreads.iter().any(|odds| odds.team == team);
+        self.spreads.is_empty() || (prices(&game.home_team) && prices(&game.away_team))
+    }
+}
+
+/// The best price on one bet across the bookmakers pricing a game
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BestOdds<'a> {
+    pub price: i32,
+    pub bookmaker: &'a str,
+    /// When that bookmaker last updated its odds
+    pub last_update: DateTime<Utc>,
+}
+
+impl<'a> BestOdds<'a> {
+    /// Keep whichever price pays more; ties keep the current book
+    pub(crate) fn consider(best: &mut Option<Self>, odds: &'a BettingOdds, price: i32) {
+        if best.is_none_or(|current| price > current.price) {
+            *best = Some(Self {
+                price,
+                bookmaker: &odds.bookmaker,
+                last_update: odds.last_update,
+            });
+        }
+    }
+}
+
+/// Best moneyline price on `team` across bookmakers, or `None` if no book prices it
+/// Ties keep the first bookmaker listed.
+pub fn best_moneyline<'a>(
+    odds_list: impl IntoIterator<Item = &'a BettingOdds>,
+    team: &str,
+) -> Option<BestOdds<'a>> {
+    let mut best = None;
+    for odds in odds_list {
+        for moneyline in odds
+            .moneyline
+            .iter()
+            .filter(|moneyline| moneyline.team == team)
+        {
+            BestOdds::consider(&mut best, odds, moneyline.price);
+        }
+    }
+    best
+}
+
+/// Best price on `team` at spread `point` across bookmakers, or `None` if no book offers
+/// that line
+/// Lines within a tenth of a point are the same line. Ties keep the first bookmaker listed.
+pub fn best_spread<'a>(
+    odds_list: impl IntoIterator<Item = &'a BettingOdds>,
+    team: &str,
+    point: f64,
+) -> Option<BestOdds<'a>> {
+    let mut best = None;
+    for odds in odds_list {
+        for spread in odds
+            .spreads
+            .iter()
+            .filter(|spread| spread.team == team && (spread.point - point).abs() < 0.1)
+        {
+            BestOdds::consider(&mut best, odds, spread.price);
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_odds(
+        bookmaker: &str,
+        minutes_ago: i64,
+        iowa_ml: i32,
+        iowa_spread: (f64, i32),
+    ) -> BettingOdds {
+        BettingOdds {
+            game_id: "game".to_string(),
+            bookmaker: bookmaker.to_string(),
+            last_update: Utc::now() - chrono::Duration::minutes(minutes_ago),
+            moneyline: vec![MoneylineOdds {
+                team: "Iowa Hawkeyes".to_string(),
+                price: iowa_ml,
+            }],
+            spreads: vec![SpreadOdds {
+                team: "Iowa Hawkeyes".to_string(),
+                point: iowa_spread.0,
+                price: iowa_spread.1,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_best_odds_across_books() {
+        let odds = vec![
+            create_odds("DraftKings", 5, -150, (-3.5, -110)),
+            create_odds("FanDuel", 1, -140, (-3.5, -105)),
+            create_odds("BetMGM", 2, -140, (-2.5, 100)),
+        ];
+
+        // FanDuel is listed before BetMGM at the same moneyline price
+        let moneyline = best_moneyline(&odds, "Iowa Hawkeyes").unwrap();
+        assert_eq!((moneyline.price, moneyline.bookmaker), (-140, "FanDuel"));
+        assert_eq!(moneyline.last_update, odds[1].last_update);
+        assert_eq!(best_moneyline(&odds, "Nebraska Cornhuskers"), None);
+
+        let spread = best_spread(&odds, "Iowa Hawkeyes", -3.5).unwrap();
+        assert_eq!((spread.price, spread.bookmaker), (-105, "FanDuel"));
+        let alternate = best_spread(&odds, "Iowa Hawkeyes", -2.5).unwrap();
+        assert_eq!((alternate.price, alternate.bookmaker), (100, "BetMGM"));
+        assert_eq!(best_spread(&odds, "Iowa Hawkeyes", 3.5), None);
+
+        // Any iterator of odds works, e.g. after filtering out a book
+        let without_fanduel = odds.iter().filter(|odds| odds.bookmaker != "FanDuel");
+        assert_eq!(
+            best_moneyline(without_fanduel, "Iowa Hawkeyes").map(|best| best.bookmaker),
+            Some("BetMGM")
+        );
+    }
+
+    #[test]
+    fn test_bookmaker_from_title() {
+        assert_eq!(Bookmaker::from_title("DraftKings"), Bookmaker::DraftKings);
+        assert_eq!(Bookmaker::from_title("draftkings"), Bookmaker::DraftKings);
+        assert_eq!(Bookmaker::from_title("BetOnline.ag"), Bookmaker::BetOnline);
+        assert_eq!(Bookmaker::from_title("ESPN BET"), Bookmaker::EspnBet);
+        assert_eq!(
+            Bookmaker::from_title("William Hill (US)"),
+            Bookmaker::Caesars
+        );
+        assert_eq!(
+            Bookmaker::from_title("Some New Book"),
+            Bookmaker::Other("Some New Book".to_string())
+        );
+
+        assert_eq!(Bookmaker::EspnBet.key(), "espnbet");
+        assert_eq!(Bookmaker::from_title("Some New Book").key(), "somenewbook");
+        assert!(Bookmaker::Kalshi.matches("KALSHI"));
+        assert!(Bookmaker::from_title("some new book").matches("Some New Book"));
+    }
+}
@@ -0,0 +1,311 @@
+use crate::api::game_results_api::{GameResult, GameResultsApiClient};
+use crate::error::Result;
+use crate::scrapers::prediction_tracker::GamePrediction;
+use crate::scrapers::ratings::Matchup;
+use crate::scrapers::PredictionSource;
+use crate::utils::ev_analysis::extract_school_name;
+use async_trait::async_trait;
+use chrono::{Datelike, Local};
+use std::collections::HashMap;
+use tracing::info;
+
+/// Rating a team starts at when the API has no Elo for it
+pub const DEFAULT_INITIAL_ELO: f64 = 1500.0;
+
+/// How far one game moves the two teams' ratings, at most
+pub const DEFAULT_K_FACTOR: f64 = 25.0;
+
+/// Elo points a true home game is worth to the home team
+pub const DEFAULT_ELO_HOME_ADVANTAGE: f64 = 55.0;
+
+/// Elo points per point of spread
+pub const ELO_POINTS_PER_POINT: f64 = 25.0;
+
+/// Name this source goes by in logs and model accuracy reports
+const ELO_SOURCE: &str = "elo";
+
+/// How results move the ratings
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EloConfig {
+    pub k_factor: f64,
+    /// Elo points added to the home team, except at neutral sites
+    pub home_advantage: f64,
+    pub initial_rating: f64,
+}
+
+impl Default for EloConfig {
+    fn default() -> Self {
+        Self {
+            k_factor: DEFAULT_K_FACTOR,
+            home_advantage: DEFAULT_ELO_HOME_ADVANTAGE,
+            initial_rating: DEFAULT_INITIAL_ELO,
+        }
+    }
+}
+
+/// Chance the team `elo_diff` points better wins, by the standard Elo formula
+pub fn elo_win_probability(elo_diff: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf(-elo_diff / 400.0))
+}
+
+/// Running Elo ratings, keyed by school
+/// A team is seeded from the API's pregame Elo the first time it's seen (so a table built
+/// from part of a season still starts from sensible numbers), then moves with each result.
+#[derive(Debug, Clone)]
+pub struct EloTable {
+    ratings: HashMap<String, f64>,
+    config: EloConfig,
+}
+
+impl EloTable {
+    pub fn new(config: EloConfig) -> Self {
+        Self {
+            ratings: HashMap::new(),
+            config,
+        }
+    }
+
+    /// Ratings after every completed game in `results`, applied in kickoff order
+    pub fn from_results(results: &[GameResult], config: EloConfig) -> Self {
+        let mut completed: Vec<&GameResult> =
+            results.iter().filter(|result| result.completed).collect();
+        completed.sort_by(|a, b| a.start_date.cmp(&b.start_date));
+
+        let mut table = Self::new(config);
+        for result in completed {
+            table.update(result);
+        }
+        table
+    }
+
+    /// A team's current rating, or the initial rating if it hasn't played
+    pub fn rating(&self, team: &str) -> f64 {
+        self.ratings
+            .get(&extract_school_name(team))
+            .copied()
+            .unwrap_or(self.config.initial_rating)
+    }
+
+    /// Number of teams rated
+    pub fn len(&self) -> usize {
+        self.ratings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ratings.is_empty()
+    }
+
+    /// Home team's Elo edge in a game, home advantage included
+    fn home_edge(&self, home_team: &str, away_team: &str, neutral_site: bool) -> f64 {
+        let advantage = if neutral_site {
+            0.0
+        } else {
+            self.config.home_advantage
+        };
+        self.rating(home_team) - self.rating(away_team) + advantage
+    }
+
+    /// Move both teams' ratings by a completed game's result
+    /// Games without a final score are ignored; a tie counts as half a win each.
+    pub fn update(&mut self, result: &GameResult) {
+        let (Some(home_points), Some(away_points)) = (result.home_points, result.away_points)
+        else {
+            return;
+        };
+
+        for (team, api_elo) in [
+            (&result.home_team, result.home_pregame_elo),
+            (&result.away_team, result.away_pregame_elo),
+        ] {
+            let initial = api_elo.map_or(self.config.initial_rating, f64::from);
+            self.ratings
+                .entry(extract_school_name(team))
+                .or_insert(initial);
+        }
+
+        let expected = elo_win_probability(self.home_edge(
+            &result.home_team,
+            &result.away_team,
+            result.neutral_site,
+        ));
+        let actual = match home_points.cmp(&away_points) {
+            std::cmp::Ordering::Greater => 1.0,
+            std::cmp::Ordering::Less => 0.0,
+            std::cmp::Ordering::Equal => 0.5,
+        };
+        let change = self.config.k_factor * (actual - expected);
+
+        *self
+            .ratings
+            .entry(extract_school_name(&result.home_team))
+            .or_default() += change;
+        *self
+            .ratings
+            .entry(extract_school_name(&result.away_team))
+            .or_default() -= change;
+    }
+
+    /// Predict a matchup from the current ratings
+    /// The spread is the Elo edge converted at `ELO_POINTS_PER_POINT`.
+    pub fn predict(&self, matchup: &Matchup) -> GamePrediction {
+        let edge = self.home_edge(&matchup.home_team, &matchup.away_team, matchup.neutral_site);
+        let home_win_prob = elo_win_probability(edge);
+        let spread = edge / ELO_POINTS_PER_POINT;
+
+        GamePrediction {
+            home_team: matchup.home_team.clone(),
+            away_team: matchup.away_team.clone(),
+            spread,
+            opening_spread: None,
+            home_win_prob,
+            away_win_prob: 1.0 - home_win_prob,
+            _prediction_avg: spread,
+            spread_derived: false,
+            home_cover_prob: None,
+            source: String::new(),
+        }
+    }
+}
+
+/// In-house predictions from Elo ratings built on this season's CFBD results
+/// Every week played so far is fetched to build the table, then this week's unplayed
+/// games are predicted. Matchups with a team that hasn't played yet are skipped.
+pub struct EloSource {
+    schedule: GameResultsApiClient,
+    config: EloConfig,
+}
+
+impl EloSource {
+    pub fn new(schedule: GameResultsApiClient) -> Self {
+        Self {
+            schedule,
+            config: EloConfig::default(),
+        }
+    }
+
+    /// Use a different K-factor, home advantage or starting rating
+    pub fn with_config(mut self, config: EloConfig) -> Self {
+        self.config = config;
+        self
+    }
+}
+
+#[async_trait]
+impl PredictionSource for EloSource {
+    fn name(&self) -> &str {
+        ELO_SOURCE
+    }
+
+    async fn fetch(&self) -> Result<Vec<GamePrediction>> {
+        let now = Local::now();
+        let week = now.iso_week().week() as u8;
+        let results = self
+            .schedule
+            .fetch_cfb_game_results_range(now.year() as u32, 1, week)
+            .await?;
+
+        let table = EloTable::from_results(&results, self.config);
+        info!(teams = table.len(), "Built Elo ratings");
+
+        // Only this week's games: earlier unplayed ones were postponed or canceled
+        let current_week = results.iter().map(|result| result.week).max();
+        Ok(results
+            .iter()
+            .filter(|result| !result.completed && Some(result.week) == current_week)
+            .filter(|result| {
+                table
+                    .ratings
+                    .contains_key(&extract_school_name(&result.home_team))
+                    && table
+                        .ratings
+                        .contains_key(&extract_school_name(&result.away_team))
+            })
+            .map(|result| table.predict(&Matchup::from(result)))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn create_result(
+        home: &str,
+        away: &str,
+        start_date: &str,
+        points: Option<(i32, i32)>,
+        pregame_elo: Option<(i32, i32)>,
+    ) -> GameResult {
+        serde_json::from_value(json!({
+            "id": 1,
+            "season": 2025,
+            "week": 1,
+            "seasonType": "regular",
+            "startDate": start_date,
+            "startTimeTBD": false,
+            "completed": points.is_some(),
+            "neutralSite": false,
+            "conferenceGame": true,
+            "homeId": 1,
+            "homeTeam": home,
+            "homePoints": points.map(|(home, _)| home),
+            "homePregameElo": pregame_elo.map(|(home, _)| home),
+            "awayId": 2,
+            "awayTeam": away,
+            "awayPoints": points.map(|(_, away)| away),
+            "awayPregameElo": pregame_elo.map(|(_, away)| away),
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_elo_table_updates_from_results() {
+        assert_eq!(elo_win_probability(0.0), 0.5);
+        assert!((elo_win_probability(400.0) - 10.0 / 11.0).abs() < 1e-12);
+
+        let config = EloConfig {
+            home_advantage: 0.0,
+            ..EloConfig::default()
+        };
+        let results = [
+            // Listed out of order: the later game must be applied second
+            create_result(
+                "Alabama",
+                "Auburn",
+                "2025-09-13T19:30:00.000Z",
+                Some((24, 27)),
+                None,
+            ),
+            create_result(
+                "Georgia",
+                "Alabama",
+                "2025-09-06T19:30:00.000Z",
+                Some((31, 17)),
+                Some((1600, 1600)),
+            ),
+            // Not played yet, so it doesn't count
+            create_result("Georgia", "Auburn", "2025-09-20T19:30:00.000Z", None, None),
+        ];
+
+        let table = EloTable::from_results(&results, config);
+
+        // Even teams: the winner gains half the K-factor and the loser drops the same
+        assert_eq!(table.rating("Georgia Bulldogs"), 1612.5);
+        // Alabama (1587.5) then loses to unrated Auburn (1500) at home
+        let expected = elo_win_probability(87.5);
+        assert!((table.rating("Alabama") - (1587.5 - 25.0 * expected)).abs() < 1e-9);
+        assert!((table.rating("Auburn") - (1500.0 + 25.0 * expected)).abs() < 1e-9);
+        assert_eq!(table.len(), 3);
+
+        let prediction = table.predict(&Matchup {
+            home_team: "Georgia Bulldogs".to_string(),
+            away_team: "Auburn Tigers".to_string(),
+            neutral_site: false,
+        });
+        let edge = table.rating("Georgia") - table.rating("Auburn");
+        assert!((prediction.home_win_prob - elo_win_probability(edge)).abs() < 1e-12);
+        assert!((prediction.spread - edge / ELO_POINTS_PER_POINT).abs() < 1e-12);
+        assert!(prediction.spread > 0.0);
+    }
+}
@@ -0,0 +1,163 @@
+use crate::api::game_results_api::GameResultsApiClient;
+use crate::error::{CfbBettingError, Result};
+use crate::scrapers::prediction_tracker::GamePrediction;
+use crate::scrapers::ratings::{generate_predictions, Matchup, RatingsConfig};
+use crate::scrapers::PredictionSource;
+use async_trait::async_trait;
+use chrono::{Datelike, Local};
+use std::collections::HashMap;
+use std::time::Instant;
+use tracing::{info, instrument};
+
+/// Plain-text FBS ratings table
+const MASSEY_RATINGS_URL: &str = "https://masseyratings.com/cf/fbs/ratings.txt";
+
+/// Service name used in errors
+const MASSEY: &str = "Massey Ratings";
+
+/// Name this source goes by in logs and model accuracy reports
+const MASSEY_SOURCE: &str = "massey";
+
+/// Predictions from Kenneth Massey's college football power ratings
+/// Ratings are in points, so the rating difference (plus home field) is the projected
+/// spread. Matchups come from this week's CFBD schedule.
+pub struct MasseyScraper {
+    client: reqwest::Client,
+    schedule: GameResultsApiClient,
+    config: RatingsConfig,
+}
+
+impl MasseyScraper {
+    pub fn new(schedule: GameResultsApiClient) -> Self {
+        Self {
+            client: crate::http_client_builder()
+                .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36")
+                .build()
+                .unwrap(),
+            schedule,
+            config: RatingsConfig::default(),
+        }
+    }
+
+    /// Use a different home advantage or spread factor, e.g. one fitted with
+    /// `ratings::calibrate_spread_factor`
+    pub fn with_config(mut self, config: RatingsConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Fetch the current power rating of every FBS team, keyed by team name
+    #[instrument(skip(self), fields(source = "massey"))]
+    pub async fn fetch_ratings(&self) -> Result<HashMap<String, f64>> {
+        let start = Instant::now();
+        let text = self
+            .client
+            .get(MASSEY_RATINGS_URL)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| CfbBettingError::api(MASSEY, e))?
+            .text()
+            .await
+            .map_err(|e| CfbBettingError::api(MASSEY, e))?;
+
+        let ratings = parse_ratings(&text);
+        if ratings.is_empty() {
+            return Err(CfbBettingError::parse(
+                "Massey ratings",
+                "no rating rows found; the page layout may have changed",
+            ));
+        }
+        info!(
+            teams = ratings.len(),
+            elapsed_ms = start.elapsed().as_millis() as u64,
+            "Fetched Massey ratings"
+        );
+        Ok(ratings)
+    }
+}
+
+/// Parse every team's power rating from the ratings table
+/// Lines that aren't team rows (headers, separators) are skipped.
+fn parse_ratings(text: &str) -> HashMap<String, f64> {
+    text.lines().filter_map(parse_rating_line).collect()
+}
+
+/// Parse one row of the ratings table
+/// Format: "rank  team  conference  W-L  rating  power  offense  defense ...", where the team
+/// name may contain spaces and `power` is the rating in points.
+fn parse_rating_line(line: &str) -> Option<(String, f64)> {
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+    tokens.first()?.parse::<u32>().ok()?;
+
+    // The W-L record anchors the row: the conference is just before it and the team name
+    // runs from the rank to the conference
+    let record = tokens.iter().position(|token| is_record(token))?;
+    if record < 3 {
+        return None;
+    }
+    let team = tokens[1..record - 1].join(" ");
+    let power = tokens.get(record + 2)?.parse::<f64>().ok()?;
+
+    Some((team, power))
+}
+
+/// Whether a token is a win-loss record like "11-2"
+fn is_record(token: &str) -> bool {
+    token.split_once('-').is_some_and(|(wins, losses)| {
+        !wins.is_empty()
+            && !losses.is_empty()
+            && wins.chars().all(|c| c.is_ascii_digit())
+            && losses.chars().all(|c| c.is_ascii_digit())
+    })
+}
+
+#[async_trait]
+impl PredictionSource for MasseyScraper {
+    fn name(&self) -> &str {
+        MASSEY_SOURCE
+    }
+
+    async fn fetch(&self) -> Result<Vec<GamePrediction>> {
+        let ratings = self.fetch_ratings().await?;
+
+        let now = Local::now();
+        let schedule = self
+            .schedule
+            .fetch_cfb_game_results(now.year() as u32, now.iso_week().week() as u8)
+            .await?;
+        let matchups: Vec<Matchup> = schedule
+            .iter()
+            .filter(|game| !game.completed)
+            .map(Matchup::from)
+            .collect();
+
+        Ok(generate_predictions(&ratings, &matchups, &self.config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rating_line() {
+        let text = "\
+  Rank  Team                 Conf   W-L     Rat     Pwr     Off     Def
+--------------------------------------------------------------------------
+     1  Ohio St              B10    12-1   9.52   68.41   38.10   30.31
+     2  Texas A&M            SEC    11-2   9.31   64.87   37.55   27.32
+    88  Miami OH             MAC     7-6   4.03   42.15   24.90   17.25
+   130  Kent                 MAC     1-11  0.62   24.33   15.02    9.31
+";
+
+        let ratings = parse_ratings(text);
+
+        assert_eq!(ratings.len(), 4);
+        assert_eq!(ratings["Ohio St"], 68.41);
+        assert_eq!(ratings["Texas A&M"], 64.87);
+        assert_eq!(ratings["Miami OH"], 42.15);
+        assert_eq!(ratings["Kent"], 24.33);
+        assert_eq!(parse_rating_line("     5  Oregon  B10  12-1"), None);
+    }
+}
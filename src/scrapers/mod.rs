@@ -1 +1,187 @@
+pub mod elo;
+pub mod massey;
 pub mod prediction_tracker;
+pub mod ratings;
+
+use crate::api::game_results_api::GameResultsApiClient;
+use crate::config;
+use crate::error::Result;
+use crate::utils::ev_analysis::extract_school_name;
+use crate::utils::monitoring::record_fetch;
+use async_trait::async_trait;
+use prediction_tracker::GamePrediction;
+use std::collections::HashSet;
+use tracing::warn;
+
+/// Anything that produces game predictions for the EV model
+#[async_trait]
+pub trait PredictionSource: Send + Sync {
+    /// Short name used in logs
+    fn name(&self) -> &str;
+
+    /// Fetch the current predictions, with win probabilities and spreads from the home
+    /// team's perspective
+    /// Predictions without a `source` are labeled with `name()` by `fetch_all_sources`.
+    async fn fetch(&self) -> Result<Vec<GamePrediction>>;
+}
+
+/// The prediction sources used by default, most trusted first
+/// Massey needs the CFBD schedule for its matchups, so it's only included when
+/// `COLLEGE_FOOTBALL_DATA_API_KEY` is set. The in-house Elo model also needs a request
+/// per week played, so it's only added with `ELO_PREDICTIONS=1`.
+pub fn default_prediction_sources() -> Vec<Box<dyn PredictionSource>> {
+    let mut sources: Vec<Box<dyn PredictionSource>> =
+        vec![Box::new(prediction_tracker::PredictionTrackerScraper::new())];
+    if let Ok(api_key) = crate::required_env_var("COLLEGE_FOOTBALL_DATA_API_KEY") {
+        sources.push(Box::new(massey::MasseyScraper::new(
+            GameResultsApiClient::new(api_key.clone()),
+        )));
+        if config::elo_predictions() {
+            sources.push(Box::new(
+                elo::EloSource::new(GameResultsApiClient::new(api_key)).with_config(
+                    elo::EloConfig {
+                        k_factor: config::elo_k_factor(),
+                        ..elo::EloConfig::default()
+                    },
+                ),
+            ));
+        }
+    }
+    sources
+}
+
+/// Fetch predictions from every source, in order, each labeled with its source
+/// Every source's prediction for a game is kept, so models can be compared against each
+/// other. Sources that fail are logged and skipped; it's only an error if all fail.
+pub async fn fetch_all_sources(
+    sources: &[Box<dyn PredictionSource>],
+) -> Result<Vec<GamePrediction>> {
+    let mut predictions = Vec::new();
+    let mut last_error = None;
+
+    for source in sources {
+        let result = source.fetch().await;
+        record_fetch(source.name(), &result);
+        match result {
+            Ok(source_predictions) => {
+                predictions.extend(source_predictions.into_iter().map(|mut prediction| {
+                    if prediction.source.is_empty() {
+                        prediction.source = source.name().to_string();
+                    }
+                    prediction
+                }));
+            }
+            Err(e) => {
+                warn!(source = source.name(), error = %e, "Prediction source failed");
+                last_error = Some(e);
+            }
+        }
+    }
+
+    match last_error {
+        Some(e) if predictions.is_empty() => Err(e),
+        _ => Ok(predictions),
+    }
+}
+
+/// Keep one prediction per game, from whichever source came first
+pub fn merge_predictions(predictions: Vec<GamePrediction>) -> Vec<GamePrediction> {
+    let mut seen = HashSet::new();
+    predictions
+        .into_iter()
+        .filter(|prediction| {
+            seen.insert((
+                extract_school_name(&prediction.home_team),
+                extract_school_name(&prediction.away_team),
+            ))
+        })
+        .collect()
+}
+
+/// Fetch predictions from every source, in order
+/// A game already predicted by an earlier source is skipped, so list the most trusted
+/// source first. Sources that fail are logged and skipped; it's only an error if all fail.
+pub async fn fetch_from_sources(
+    sources: &[Box<dyn PredictionSource>],
+) -> Result<Vec<GamePrediction>> {
+    Ok(merge_predictions(fetch_all_sources(sources).await?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::CfbBettingError;
+
+    struct StaticSource {
+        name: &'static str,
+        predictions: Vec<GamePrediction>,
+    }
+
+    #[async_trait]
+    impl PredictionSource for StaticSource {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        async fn fetch(&self) -> Result<Vec<GamePrediction>> {
+            if self.predictions.is_empty() {
+                return Err(CfbBettingError::ApiError {
+                    service: self.name,
+                    status: Some(503),
+                    message: "down".to_string(),
+                });
+            }
+            Ok(self.predictions.clone())
+        }
+    }
+
+    fn create_prediction(home: &str, away: &str, spread: f64) -> GamePrediction {
+        GamePrediction {
+            home_team: home.to_string(),
+            away_team: away.to_string(),
+            spread,
+            opening_spread: None,
+            home_win_prob: 0.5,
+            away_win_prob: 0.5,
+            _prediction_avg: 0.0,
+            spread_derived: false,
+            home_cover_prob: None,
+            source: String::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_from_sources_prefers_earlier_sources() {
+        let sources: Vec<Box<dyn PredictionSource>> = vec![
+            Box::new(StaticSource {
+                name: "down",
+                predictions: vec![],
+            }),
+            Box::new(StaticSource {
+                name: "primary",
+                predictions: vec![create_prediction("Iowa", "Nebraska", 3.0)],
+            }),
+            Box::new(StaticSource {
+                name: "backup",
+                predictions: vec![
+                    create_prediction("Iowa", "Nebraska", 7.0),
+                    create_prediction("Purdue", "Indiana", -10.0),
+                ],
+            }),
+        ];
+
+        let predictions = fetch_from_sources(&sources).await.unwrap();
+
+        assert_eq!(predictions.len(), 2);
+        assert_eq!(predictions[0].spread, 3.0);
+        assert_eq!(predictions[1].home_team, "Purdue");
+        assert_eq!(predictions[0].source, "primary");
+        assert_eq!(predictions[1].source, "backup");
+
+        let all_down: Vec<Box<dyn PredictionSource>> = vec![Box::new(StaticSource {
+            name: "down",
+            predictions: vec![],
+        })];
+        assert!(fetch_from_sources(&all_down).await.is_err());
+    }
+}
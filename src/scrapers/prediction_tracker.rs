@@ -1,17 +1,68 @@
-use anyhow::{Context, Result};
+use crate::error::{CfbBettingError, Result};
+use crate::scrapers::PredictionSource;
+use crate::utils::ev_analysis::SPREAD_STD_DEV;
+use crate::utils::ev_calculator::win_prob_to_spread;
+use async_trait::async_trait;
 use scraper::{Html, Selector};
 use serde::{Deserialize, Serialize};
+use std::time::Instant;
+use tracing::{info, instrument, warn};
 
 const PREDICTION_TRACKER_URL: &str = "https://www.thepredictiontracker.com/predncaa.html";
 
+/// Service name used in errors
+const PREDICTION_TRACKER: &str = "Prediction Tracker";
+
+/// Name this source goes by in logs and model accuracy reports
+const PREDICTION_TRACKER_SOURCE: &str = "prediction_tracker";
+
+/// Share of the table's game rows that must parse for the page layout to be considered
+/// intact; below this a column has most likely moved
+const MIN_PARSED_SHARE: f64 = 0.5;
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct GamePrediction {
     pub home_team: String,
     pub away_team: String,
     pub spread: f64,
+    /// Opening line (home perspective), when the page lists one
+    #[serde(default)]
+    pub opening_spread: Option<f64>,
     pub home_win_prob: f64,
     pub away_win_prob: f64,
     pub _prediction_avg: f64,
+    /// Name of the prediction source (model) this came from
+    #[serde(default)]
+    pub source: String,
+    /// The spread column didn't parse, so `spread` was derived from the win probability
+    #[serde(default)]
+    pub spread_derived: bool,
+    /// The source's own chance that the home team covers `spread`, when it lists one
+    #[serde(default)]
+    pub home_cover_prob: Option<f64>,
+}
+
+impl GamePrediction {
+    /// The source's chance that a bet on the home (or away) team at `point` covers
+    /// Only known for the line the source computed it against (`spread`, from the betting
+    /// side's perspective); pushes are ignored, as in the normal model.
+    pub fn scraped_cover_prob(&self, is_home_team: bool, point: f64) -> Option<f64> {
+        let home_cover_prob = self.home_cover_prob?;
+        // `spread` is positive when the home team is favored, so its line is the negative
+        let line = if is_home_team {
+            -self.spread
+        } else {
+            self.spread
+        };
+        if (point - line).abs() >= 0.01 {
+            return None;
+        }
+        Some(if is_home_team {
+            home_cover_prob
+        } else {
+            1.0 - home_cover_prob
+        })
+    }
 }
 
 pub struct PredictionTrackerScraper {
@@ -21,7 +72,7 @@ pub struct PredictionTrackerScraper {
 impl PredictionTrackerScraper {
     pub fn new() -> Self {
         Self {
-            client: reqwest::Client::builder()
+            client: crate::http_client_builder()
                 .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36")
                 .build()
                 .unwrap(),
@@ -29,39 +80,54 @@ impl PredictionTrackerScraper {
     }
 
     /// Scrape game predictions (with spread data) from The Prediction Tracker
+    #[instrument(skip(self), fields(source = "prediction_tracker"))]
     pub async fn fetch_game_predictions(&self) -> Result<Vec<GamePrediction>> {
+        let start = Instant::now();
         let html = self
             .client
             .get(PREDICTION_TRACKER_URL)
             .send()
             .await
-            .context("Failed to fetch Prediction Tracker page")?
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| CfbBettingError::api(PREDICTION_TRACKER, e))?
             .text()
-            .await?;
+            .await
+            .map_err(|e| CfbBettingError::api(PREDICTION_TRACKER, e))?;
+
+        let predictions = self.parse_html_to_game_predictions(&html)?;
+        info!(
+            predictions = predictions.len(),
+            elapsed_ms = start.elapsed().as_millis() as u64,
+            "Fetched predictions"
+        );
 
-        self.parse_html_to_game_predictions(&html)
+        Ok(predictions)
     }
 
     fn parse_html_to_game_predictions(&self, html: &str) -> Result<Vec<GamePrediction>> {
         let document = Html::parse_document(html);
         let mut game_predictions = Vec::new();
+        let mut game_rows = 0;
 
         // The Prediction Tracker uses plain text tables within <pre> tags
-        let pre_selector = Selector::parse("pre")
-            .ok()
-            .context("Invalid pre selector")?;
+        let pre_selector =
+            Selector::parse("pre").map_err(|e| CfbBettingError::parse("pre selector", e))?;
 
         for pre_elem in document.select(&pre_selector) {
             let text = pre_elem.text().collect::<String>();
 
             // Parse the plain text table
             for line in text.lines() {
+                if is_game_row(line) {
+                    game_rows += 1;
+                }
                 if let Some(game) = self.parse_text_line(line) {
                     game_predictions.push(game);
                 }
             }
         }
 
+        check_layout(game_predictions.len(), game_rows);
         Ok(game_predictions)
     }
 
@@ -75,10 +141,15 @@ impl PredictionTrackerScraper {
         // Format: "Home Team      Away Team         num1  num2  num3  ... prob1 prob2"
 
         // Find where the numeric data starts (first occurrence of a digit or negative sign followed by digit)
-        let numeric_start = line
+        let first_digit = line
             .char_indices()
             .find(|(_, c)| c.is_ascii_digit())
             .map(|(i, _)| i)?;
+        let numeric_start = if line[..first_digit].ends_with('-') {
+            first_digit - 1
+        } else {
+            first_digit
+        };
 
         // Extract the team names portion (everything before the numbers)
         let teams_str = line[..numeric_start].trim_end();
@@ -106,35 +177,99 @@ impl PredictionTrackerScraper {
 
         // Parse numeric values
         // Expected columns: Opening, Updated, Midweek, Pred Avg, Pred Median, Std Dev, Min, Max, Prob Win, Prob Cover
-        let spread = numeric_parts.get(1)?.parse::<f64>().ok()?; // Updated line
+        let opening_spread = numeric_parts.first()?.parse::<f64>().ok(); // Opening line
+        let spread = numeric_parts.get(1)?.parse::<f64>().ok(); // Updated line
         let prediction_avg = numeric_parts.get(3)?.parse::<f64>().ok().unwrap_or(0.0);
         let home_win_prob = numeric_parts
             .get(numeric_parts.len() - 2)? // Second to last is win probability
             .parse::<f64>()
             .ok()?;
+        // Last is the chance the home team covers the updated line
+        let home_cover_prob = numeric_parts
+            .last()?
+            .parse::<f64>()
+            .ok()
+            .filter(|prob| (0.0..=1.0).contains(prob));
 
         // Validate that win probability is reasonable
         if !(0.0..=1.0).contains(&home_win_prob) {
             return None;
         }
 
+        // Keep the row when only the spread is unreadable, approximating it from the win
+        // probability with the normal margin model. The cover probability is for the line
+        // that didn't parse, so it goes too.
+        let spread_derived = spread.is_none();
+        let home_cover_prob = home_cover_prob.filter(|_| !spread_derived);
+        let spread = spread.unwrap_or_else(|| win_prob_to_spread(home_win_prob, SPREAD_STD_DEV));
+
         Some(GamePrediction {
             home_team,
             away_team,
             spread,
+            opening_spread,
             home_win_prob, // Convert percentage to decimal
             away_win_prob: 1.0 - home_win_prob,
             _prediction_avg: prediction_avg,
+            source: PREDICTION_TRACKER_SOURCE.to_string(),
+            spread_derived,
+            home_cover_prob,
         })
     }
 }
 
+/// Whether a line of the prediction table looks like a game row (as opposed to a blank or
+/// header line), whether or not it parses
+fn is_game_row(line: &str) -> bool {
+    !line.trim().is_empty()
+        && !line.contains("Home")
+        && !line.contains("Visitor")
+        && line.chars().any(|c| c.is_ascii_digit())
+}
+
+/// Warn loudly when few of the page's game rows parsed, rather than letting an empty result
+/// pass as "no bets found". Returns whether the layout looks intact.
+fn check_layout(parsed: usize, game_rows: usize) -> bool {
+    if game_rows == 0 {
+        warn!(
+            url = PREDICTION_TRACKER_URL,
+            "No prediction table rows found on The Prediction Tracker; the page layout may have changed"
+        );
+        return false;
+    }
+
+    let parsed_share = parsed as f64 / game_rows as f64;
+    if parsed_share < MIN_PARSED_SHARE {
+        warn!(
+            parsed,
+            game_rows,
+            url = PREDICTION_TRACKER_URL,
+            "Only {:.0}% of Prediction Tracker rows parsed; the page layout has likely changed",
+            parsed_share * 100.0
+        );
+        return false;
+    }
+
+    true
+}
+
 impl Default for PredictionTrackerScraper {
     fn default() -> Self {
         Self::new()
     }
 }
 
+#[async_trait]
+impl PredictionSource for PredictionTrackerScraper {
+    fn name(&self) -> &str {
+        PREDICTION_TRACKER_SOURCE
+    }
+
+    async fn fetch(&self) -> Result<Vec<GamePrediction>> {
+        self.fetch_game_predictions().await
+    }
+}
+
 /// Helper function to normalize team names for consistent matching
 pub fn normalize_team_name(name: &str) -> String {
     name.trim()
@@ -147,6 +282,7 @@ pub fn normalize_team_name(name: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::ev_calculator::calculate_spread_cover_probability;
 
     #[test]
     fn test_normalize_team_name() {
@@ -158,6 +294,88 @@ mod tests {
         assert_eq!(normalize_team_name("Texas Tech"), "texas_tech");
     }
 
+    #[test]
+    fn test_parse_text_line_reads_opening_and_updated_lines() {
+        let scraper = PredictionTrackerScraper::new();
+        let line = "Air Force          New Mexico         7.5    9.0    9.0   10.12   10.00   3.21    2.00   16.50  0.761  0.552";
+
+        let prediction = scraper.parse_text_line(line).unwrap();
+
+        assert_eq!(prediction.home_team, "Air Force");
+        assert_eq!(prediction.away_team, "New Mexico");
+        assert_eq!(prediction.opening_spread, Some(7.5));
+        assert_eq!(prediction.spread, 9.0);
+        assert_eq!(prediction._prediction_avg, 10.12);
+        assert!((prediction.home_win_prob - 0.761).abs() < 1e-9);
+        assert!(!prediction.spread_derived);
+        assert_eq!(prediction.home_cover_prob, Some(0.552));
+        // The scraped cover probability only applies to the updated line
+        assert_eq!(prediction.scraped_cover_prob(true, -9.0), Some(0.552));
+        assert!((prediction.scraped_cover_prob(false, 9.0).unwrap() - 0.448).abs() < 1e-9);
+        assert_eq!(prediction.scraped_cover_prob(true, -7.5), None);
+
+        // An out-of-range cover probability is dropped, keeping the row
+        let bad_cover = line.replace("0.552", "55.2");
+        let prediction = scraper.parse_text_line(&bad_cover).unwrap();
+        assert_eq!(prediction.home_cover_prob, None);
+    }
+
+    #[test]
+    fn test_parse_text_line_derives_missing_spread_from_win_prob() {
+        let scraper = PredictionTrackerScraper::new();
+        let line = "Air Force          New Mexico         7.5    n/a    9.0   10.12   10.00   3.21    2.00   16.50  0.761  0.552";
+
+        let prediction = scraper.parse_text_line(line).unwrap();
+
+        assert!(prediction.spread_derived);
+        assert_eq!(prediction.home_cover_prob, None);
+        // A 76.1% favorite is about a 8.5-point favorite with a 12-point std dev
+        assert!((prediction.spread - 8.5).abs() < 0.1);
+        assert!(
+            (calculate_spread_cover_probability(prediction.spread, 0.0, SPREAD_STD_DEV) - 0.761)
+                .abs()
+                < 1e-6
+        );
+    }
+
+    #[test]
+    fn test_parse_saved_page() {
+        let scraper = PredictionTrackerScraper::new();
+        let html = include_str!("../../fixtures/prediction_tracker_sample.html");
+
+        let predictions = scraper.parse_html_to_game_predictions(html).unwrap();
+
+        assert_eq!(predictions.len(), 12);
+        let michigan = predictions
+            .iter()
+            .find(|prediction| prediction.home_team == "Michigan")
+            .unwrap();
+        assert_eq!(michigan.away_team, "Ohio St");
+        assert_eq!(michigan.opening_spread, Some(-9.5));
+        assert_eq!(michigan.spread, -10.5);
+        assert!((michigan.home_win_prob - 0.259).abs() < 1e-9);
+        assert!(predictions
+            .iter()
+            .any(|prediction| prediction.home_team == "Texas A&M"));
+    }
+
+    #[test]
+    fn test_check_layout_flags_shifted_columns() {
+        let html = include_str!("../../fixtures/prediction_tracker_sample.html");
+        let table = html.split("<pre>").nth(1).unwrap();
+        let rows = table.lines().filter(|line| is_game_row(line)).count();
+        assert_eq!(rows, 12);
+        assert!(check_layout(12, rows));
+
+        // Win probabilities moved out of the second-to-last column: nothing parses
+        let scraper = PredictionTrackerScraper::new();
+        let shifted = html.replace("  0.", "  9.");
+        let predictions = scraper.parse_html_to_game_predictions(&shifted).unwrap();
+        assert!(predictions.is_empty());
+        assert!(!check_layout(predictions.len(), rows));
+        assert!(!check_layout(0, 0));
+    }
+
     #[tokio::test]
     async fn test_fetch_predictions() {
         let scraper = PredictionTrackerScraper::new();
@@ -0,0 +1,339 @@
+use crate::api::game_results_api::GameResult;
+use crate::models::Game;
+use crate::scrapers::prediction_tracker::GamePrediction;
+use crate::utils::ev_analysis::extract_school_name;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Points a true home game is worth to the home team
+pub const DEFAULT_HOME_ADVANTAGE: f64 = 3.0;
+
+/// Rating difference at which the better team is a 10-to-1 favorite
+/// Used until a factor has been fitted with `calibrate_spread_factor`.
+pub const DEFAULT_SPREAD_FACTOR: f64 = 25.0;
+
+/// Range searched when fitting the spread factor
+const SPREAD_FACTOR_BOUNDS: (f64, f64) = (5.0, 200.0);
+
+/// Probabilities are clamped this far from 0 and 1 so one upset can't make the log-loss
+/// infinite
+const LOG_LOSS_EPSILON: f64 = 1e-9;
+
+/// How ratings are turned into spreads and win probabilities
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RatingsConfig {
+    /// Points added to the home team's rating, except at neutral sites
+    pub home_advantage: f64,
+    pub spread_factor: f64,
+}
+
+impl Default for RatingsConfig {
+    fn default() -> Self {
+        Self {
+            home_advantage: DEFAULT_HOME_ADVANTAGE,
+            spread_factor: DEFAULT_SPREAD_FACTOR,
+        }
+    }
+}
+
+impl RatingsConfig {
+    /// Home-field advantage for a game, which is zero at a neutral site (bowls, conference
+    /// championships)
+    pub fn home_advantage_for(&self, neutral_site: bool) -> f64 {
+        if neutral_site {
+            0.0
+        } else {
+            self.home_advantage
+        }
+    }
+}
+
+/// A game to predict
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Matchup {
+    pub home_team: String,
+    pub away_team: String,
+    pub neutral_site: bool,
+}
+
+impl From<&GameResult> for Matchup {
+    fn from(result: &GameResult) -> Self {
+        Self {
+            home_team: result.home_team.clone(),
+            away_team: result.away_team.clone(),
+            neutral_site: result.neutral_site,
+        }
+    }
+}
+
+/// Matchups for the games being priced, with neutral sites taken from the CFBD schedule
+/// Games are matched to the schedule by school keys in either home/away order; games
+/// missing from it are assumed to be true home games.
+pub fn matchups_for_games(games: &[Game], schedule: &[GameResult]) -> Vec<Matchup> {
+    let neutral_sites: HashMap<(String, String), bool> = schedule
+        .iter()
+        .flat_map(|result| {
+            let home_key = extract_school_name(&result.home_team);
+            let away_key = extract_school_name(&result.away_team);
+            [
+                ((home_key.clone(), away_key.clone()), result.neutral_site),
+                ((away_key, home_key), result.neutral_site),
+            ]
+        })
+        .collect();
+
+    games
+        .iter()
+        .map(|game| {
+            let key = (
+                extract_school_name(&game.home_team),
+                extract_school_name(&game.away_team),
+            );
+            Matchup {
+                home_team: game.home_team.clone(),
+                away_team: game.away_team.clone(),
+                neutral_site: neutral_sites.get(&key).copied().unwrap_or(false),
+            }
+        })
+        .collect()
+}
+
+/// Win probability for a team that's `rating_diff` points better, home advantage included
+pub fn calculate_win_probability(rating_diff: f64, spread_factor: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf(-rating_diff / spread_factor))
+}
+
+/// Fit the spread factor to completed games by minimizing log-loss
+/// Each game's rating difference (with home advantage, none at neutral sites) is scored
+/// against who actually won; ties and games with a team missing from `ratings` are
+/// skipped. Returns `DEFAULT_SPREAD_FACTOR` if there's nothing to fit.
+pub fn calibrate_spread_factor(
+    ratings: &HashMap<String, f64>,
+    historical_results: &[GameResult],
+    home_advantage: f64,
+) -> f64 {
+    let ratings: HashMap<String, f64> = ratings
+        .iter()
+        .map(|(team, rating)| (extract_school_name(team), *rating))
+        .collect();
+
+    // (rating difference, home team won) for every decided game
+    let games: Vec<(f64, bool)> = historical_results
+        .iter()
+        .filter(|result| result.completed)
+        .filter_map(|result| {
+            let margin = result.home_points? - result.away_points?;
+            if margin == 0 {
+                return None;
+            }
+            let home_rating = ratings.get(&extract_school_name(&result.home_team))?;
+            let away_rating = ratings.get(&extract_school_name(&result.away_team))?;
+            let advantage = if result.neutral_site {
+                0.0
+            } else {
+                home_advantage
+            };
+            Some((home_rating - away_rating + advantage, margin > 0))
+        })
+        .collect();
+    if games.is_empty() {
+        return DEFAULT_SPREAD_FACTOR;
+    }
+
+    let log_loss = |spread_factor: f64| -> f64 {
+        games
+            .iter()
+            .map(|&(rating_diff, home_won)| {
+                let prob = calculate_win_probability(rating_diff, spread_factor)
+                    .clamp(LOG_LOSS_EPSILON, 1.0 - LOG_LOSS_EPSILON);
+                -if home_won { prob } else { 1.0 - prob }.ln()
+            })
+            .sum()
+    };
+
+    // Log-loss is convex in the inverse of the factor, so a golden-section search over it
+    // finds the minimum
+    let inv_phi = (5f64.sqrt() - 1.0) / 2.0;
+    let (mut low, mut high) = (1.0 / SPREAD_FACTOR_BOUNDS.1, 1.0 / SPREAD_FACTOR_BOUNDS.0);
+    for _ in 0..100 {
+        let a = high - inv_phi * (high - low);
+        let b = low + inv_phi * (high - low);
+        if log_loss(1.0 / a) < log_loss(1.0 / b) {
+            high = b;
+        } else {
+            low = a;
+        }
+    }
+    2.0 / (low + high)
+}
+
+/// Predict each matchup from a table of power ratings (in points)
+/// Ratings are looked up by school key, so any spelling `extract_school_name` understands
+/// works. Matchups with a team missing from the table are skipped.
+pub fn generate_predictions(
+    ratings: &HashMap<String, f64>,
+    matchups: &[Matchup],
+    config: &RatingsConfig,
+) -> Vec<GamePrediction> {
+    let ratings: HashMap<String, f64> = ratings
+        .iter()
+        .map(|(team, rating)| (extract_school_name(team), *rating))
+        .collect();
+
+    matchups
+        .iter()
+        .filter_map(|matchup| {
+            let home_rating = ratings.get(&extract_school_name(&matchup.home_team))?;
+            let away_rating = ratings.get(&extract_school_name(&matchup.away_team))?;
+
+            // Home perspective: positive means the home team is favored
+            let spread =
+                home_rating - away_rating + config.home_advantage_for(matchup.neutral_site);
+            let home_win_prob = calculate_win_probability(spread, config.spread_factor);
+
+            Some(GamePrediction {
+                home_team: matchup.home_team.clone(),
+                away_team: matchup.away_team.clone(),
+                spread,
+                opening_spread: None,
+                home_win_prob,
+                away_win_prob: 1.0 - home_win_prob,
+                _prediction_avg: spread,
+                spread_derived: false,
+                home_cover_prob: None,
+                source: String::new(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neutral_site_has_no_home_advantage() {
+        let ratings = HashMap::from([("Georgia".to_string(), 90.0), ("Alabama".to_string(), 87.0)]);
+        let matchups = [
+            Matchup {
+                home_team: "Alabama Crimson Tide".to_string(),
+                away_team: "Georgia Bulldogs".to_string(),
+                neutral_site: false,
+            },
+            Matchup {
+                home_team: "Alabama Crimson Tide".to_string(),
+                away_team: "Georgia Bulldogs".to_string(),
+                neutral_site: true,
+            },
+            Matchup {
+                home_team: "Alabama Crimson Tide".to_string(),
+                away_team: "Montana Grizzlies".to_string(),
+                neutral_site: false,
+            },
+        ];
+
+        let predictions = generate_predictions(&ratings, &matchups, &RatingsConfig::default());
+
+        assert_eq!(predictions.len(), 2);
+        // At home Alabama makes up the 3-point gap; in Atlanta Georgia is favored
+        assert_eq!(predictions[0].spread, 0.0);
+        assert_eq!(predictions[0].home_win_prob, 0.5);
+        assert_eq!(predictions[1].spread, -3.0);
+        assert!(predictions[1].home_win_prob < 0.5);
+        assert!((predictions[1].home_win_prob + predictions[1].away_win_prob - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_matchups_for_games_use_schedule_neutral_sites() {
+        let game = |home: &str, away: &str| Game {
+            id: format!("{}-{}", home, away),
+            home_team: home.to_string(),
+            away_team: away.to_string(),
+            commence_time: chrono::Utc::now(),
+            sport_title: "NCAAF".to_string(),
+            is_live: false,
+        };
+        let schedule: Vec<GameResult> = serde_json::from_value(serde_json::json!([{
+            "id": 1,
+            "season": 2025,
+            "week": 15,
+            "seasonType": "regular",
+            "startDate": "2025-12-06T21:00:00.000Z",
+            "startTimeTBD": false,
+            "completed": false,
+            "neutralSite": true,
+            "conferenceGame": true,
+            "venue": "Mercedes-Benz Stadium",
+            "homeId": 333,
+            "homeTeam": "Alabama",
+            "awayId": 61,
+            "awayTeam": "Georgia",
+        }]))
+        .unwrap();
+
+        // The odds feed lists the title game with the teams the other way around
+        let matchups = matchups_for_games(
+            &[
+                game("Georgia Bulldogs", "Alabama Crimson Tide"),
+                game("Iowa Hawkeyes", "Nebraska Cornhuskers"),
+            ],
+            &schedule,
+        );
+
+        assert!(matchups[0].neutral_site);
+        assert_eq!(matchups[0].home_team, "Georgia Bulldogs");
+        assert!(!matchups[1].neutral_site);
+    }
+
+    #[test]
+    fn test_calibrate_spread_factor() {
+        let result = |home: &str, away: &str, home_points: i32, away_points: i32| {
+            serde_json::json!({
+                "id": 1,
+                "season": 2024,
+                "week": 5,
+                "seasonType": "regular",
+                "startDate": "2024-09-28T19:30:00.000Z",
+                "startTimeTBD": false,
+                "completed": true,
+                "neutralSite": false,
+                "conferenceGame": true,
+                "homeId": 1,
+                "homeTeam": home,
+                "homePoints": home_points,
+                "awayId": 2,
+                "awayTeam": away,
+                "awayPoints": away_points,
+            })
+        };
+        let ratings = HashMap::from([("Georgia".to_string(), 10.0), ("Alabama".to_string(), 0.0)]);
+
+        // With no home advantage Georgia is 10 points better and wins 3 of the 4 decided
+        // games, so the best fit makes a 10-point edge worth 75%
+        let results: Vec<GameResult> = serde_json::from_value(serde_json::json!([
+            result("Georgia", "Alabama", 28, 14),
+            result("Georgia", "Alabama", 31, 17),
+            result("Georgia", "Alabama", 24, 21),
+            result("Georgia", "Alabama", 20, 27),
+            result("Georgia", "Alabama", 17, 17),
+            result("Georgia", "Montana", 56, 0),
+        ]))
+        .unwrap();
+
+        let factor = calibrate_spread_factor(&ratings, &results, 0.0);
+        assert!((calculate_win_probability(10.0, factor) - 0.75).abs() < 1e-6);
+        assert_eq!(
+            calibrate_spread_factor(&ratings, &[], 0.0),
+            DEFAULT_SPREAD_FACTOR
+        );
+    }
+
+    #[test]
+    fn test_calculate_win_probability() {
+        assert!((calculate_win_probability(25.0, 25.0) - 10.0 / 11.0).abs() < 1e-12);
+        assert!(
+            calculate_win_probability(7.0, 25.0) > calculate_win_probability(7.0, 35.0),
+            "a smaller spread factor makes the same edge more decisive"
+        );
+    }
+}
@@ -0,0 +1,224 @@
+use crate::api::game_results_api::GameResult;
+use crate::scrapers::prediction_tracker::GamePrediction;
+use crate::utils::ev_analysis::extract_school_name;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// How one prediction model did on completed games
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModelAccuracy {
+    pub model: String,
+    /// Completed games the model predicted
+    pub games: usize,
+    /// Games where the team the model gave a better than even chance won
+    pub winners_correct: usize,
+    /// Games where the model picked a winner (not a 50/50 call) and the game wasn't tied
+    pub winners_picked: usize,
+    pub ats_wins: usize,
+    pub ats_losses: usize,
+    pub ats_pushes: usize,
+}
+
+impl ModelAccuracy {
+    /// Share of picked winners that won
+    pub fn winner_accuracy(&self) -> Option<f64> {
+        (self.winners_picked > 0).then(|| self.winners_correct as f64 / self.winners_picked as f64)
+    }
+
+    /// Against-the-spread win rate, excluding pushes
+    pub fn ats_win_rate(&self) -> Option<f64> {
+        let decided = self.ats_wins + self.ats_losses;
+        (decided > 0).then(|| self.ats_wins as f64 / decided as f64)
+    }
+
+    /// Against-the-spread record as "W-L-P"
+    pub fn ats_record(&self) -> String {
+        format!("{}-{}-{}", self.ats_wins, self.ats_losses, self.ats_pushes)
+    }
+
+    /// Format the model's record as a readable string
+    pub fn format(&self) -> String {
+        let rate =
+            |rate: Option<f64>| rate.map_or("n/a".to_string(), |r| format!("{:.1}%", r * 100.0));
+        format!(
+            "{} | {} games | Winners: {}/{} ({}) | ATS: {} ({})",
+            self.model,
+            self.games,
+            self.winners_correct,
+            self.winners_picked,
+            rate(self.winner_accuracy()),
+            self.ats_record(),
+            rate(self.ats_win_rate())
+        )
+    }
+
+    fn grade(&mut self, prediction: &GamePrediction, home_margin: f64) {
+        self.games += 1;
+
+        let home_picked = prediction.home_win_prob > 0.5;
+        if prediction.home_win_prob != 0.5 && home_margin != 0.0 {
+            self.winners_picked += 1;
+            if home_picked == (home_margin > 0.0) {
+                self.winners_correct += 1;
+            }
+        }
+
+        // ATS is graded against the market line listed with the prediction: the model is
+        // on the home team if it projects the home team to beat the line
+        let Some(line) = prediction.opening_spread else {
+            return;
+        };
+        if prediction.spread == line {
+            return;
+        }
+        let home_ats = prediction.spread > line;
+        if home_margin == line {
+            self.ats_pushes += 1;
+        } else if home_ats == (home_margin > line) {
+            self.ats_wins += 1;
+        } else {
+            self.ats_losses += 1;
+        }
+    }
+}
+
+/// Grade every model's predictions against completed games, best ATS record first
+/// Predictions are grouped by `source`; games are matched on school keys in either
+/// home/away order. Spreads are from the home team's perspective (positive = home favored).
+pub fn model_accuracy(
+    predictions: &[GamePrediction],
+    results: &[GameResult],
+) -> Vec<ModelAccuracy> {
+    let mut final_margins: HashMap<(String, String), f64> = HashMap::new();
+    for result in results.iter().filter(|result| result.completed) {
+        let (Some(home_points), Some(away_points)) = (result.home_points, result.away_points)
+        else {
+            continue;
+        };
+        let home_key = extract_school_name(&result.home_team);
+        let away_key = extract_school_name(&result.away_team);
+        let margin = (home_points - away_points) as f64;
+        final_margins.insert((home_key.clone(), away_key.clone()), margin);
+        final_margins.insert((away_key, home_key), -margin);
+    }
+
+    let mut by_model: HashMap<&str, ModelAccuracy> = HashMap::new();
+    for prediction in predictions {
+        let key = (
+            extract_school_name(&prediction.home_team),
+            extract_school_name(&prediction.away_team),
+        );
+        let Some(&home_margin) = final_margins.get(&key) else {
+            continue;
+        };
+        by_model
+            .entry(prediction.source.as_str())
+            .or_insert_with(|| ModelAccuracy {
+                model: prediction.source.clone(),
+                ..ModelAccuracy::default()
+            })
+            .grade(prediction, home_margin);
+    }
+
+    let mut leaderboard: Vec<ModelAccuracy> = by_model.into_values().collect();
+    leaderboard.sort_by(|a, b| {
+        let score = |m: &ModelAccuracy| {
+            (
+                m.ats_win_rate().unwrap_or(0.0),
+                m.winner_accuracy().unwrap_or(0.0),
+            )
+        };
+        score(b)
+            .partial_cmp(&score(a))
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.model.cmp(&b.model))
+    });
+    leaderboard
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn create_result(home: &str, away: &str, home_points: i32, away_points: i32) -> GameResult {
+        serde_json::from_value(json!({
+            "id": 1,
+            "season": 2025,
+            "week": 10,
+            "seasonType": "regular",
+            "startDate": "2025-11-01T19:30:00.000Z",
+            "startTimeTBD": false,
+            "completed": true,
+            "neutralSite": false,
+            "conferenceGame": true,
+            "homeId": 1,
+            "homeTeam": home,
+            "homePoints": home_points,
+            "awayId": 2,
+            "awayTeam": away,
+            "awayPoints": away_points,
+        }))
+        .unwrap()
+    }
+
+    fn create_prediction(
+        source: &str,
+        home: &str,
+        away: &str,
+        spread: f64,
+        line: f64,
+        home_win_prob: f64,
+    ) -> GamePrediction {
+        GamePrediction {
+            home_team: home.to_string(),
+            away_team: away.to_string(),
+            spread,
+            opening_spread: Some(line),
+            home_win_prob,
+            away_win_prob: 1.0 - home_win_prob,
+            _prediction_avg: spread,
+            spread_derived: false,
+            home_cover_prob: None,
+            source: source.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_model_accuracy_by_source() {
+        // Iowa won by 10 as a 7-point favorite; Purdue lost to Indiana by 21 getting 14
+        let results = vec![
+            create_result("Iowa", "Nebraska", 24, 14),
+            create_result("Purdue", "Indiana", 10, 31),
+        ];
+        let predictions = vec![
+            create_prediction(
+                "sharp",
+                "Iowa Hawkeyes",
+                "Nebraska Cornhuskers",
+                9.0,
+                7.0,
+                0.7,
+            ),
+            create_prediction("sharp", "Purdue", "Indiana", -17.0, -14.0, 0.15),
+            create_prediction("square", "Iowa", "Nebraska", 3.0, 7.0, 0.6),
+            create_prediction("square", "Purdue", "Indiana", 2.0, -14.0, 0.55),
+            // Not played yet
+            create_prediction("square", "Ohio State", "Michigan", 10.0, 9.5, 0.8),
+        ];
+
+        let leaderboard = model_accuracy(&predictions, &results);
+
+        assert_eq!(leaderboard.len(), 2);
+        let sharp = &leaderboard[0];
+        assert_eq!(sharp.model, "sharp");
+        assert_eq!(sharp.games, 2);
+        assert_eq!(sharp.ats_record(), "2-0-0");
+        assert_eq!(sharp.winner_accuracy(), Some(1.0));
+
+        let square = &leaderboard[1];
+        assert_eq!(square.games, 2);
+        assert_eq!(square.ats_record(), "0-2-0");
+        assert_eq!(square.winner_accuracy(), Some(0.5));
+    }
+}
@@ -1,9 +1,178 @@
-use crate::models::{BettingOdds, Game};
-use crate::utils::ev_calculator::american_odds_to_probability;
-use anyhow::Result;
-use chrono::Utc;
+use crate::config::DEFAULT_MIN_BOOKS_IN_MARKET;
+use crate::error::Result;
+use crate::models::{best_moneyline, best_spread, BestOdds, BettingOdds, Bookmaker, Game};
+use crate::utils::ev_analysis::{extract_school_name, SPREAD_STD_DEV};
+use crate::utils::ev_calculator::{
+    american_odds_to_probability, middle_probability, profit_on_win, spread_outcome,
+};
+use crate::utils::team_names::GameMatcher;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+/// Minutes after which an arbitrage's odds are old enough that it has likely been bet or
+/// moved away
+pub const STALE_ARB_MINUTES: i64 = 5;
+
+/// Staleness limit for in-play arbitrage, where lines move every play
+pub const LIVE_STALE_ARB_MINUTES: i64 = 1;
+
+/// How far apart two spread lines can be and still count as the same number
+const LINE_TOLERANCE: f64 = 0.1;
+
+/// Checks a market must pass before its arbitrage is trusted
+/// An "arb" against one wildly off price is usually a limit-locked or mistaken line, so
+/// markets quoted by few books can be skipped and prices far from the other books ignored.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArbFilter {
+    /// Only pair legs at different books; a book's own two-sided arb is passed over for
+    /// the best pair across books
+    pub require_distinct_books: bool,
+    /// Books that must quote a market on a game before it's searched for arbitrage
+    pub min_books_in_market: usize,
+    /// Largest gap, in implied probability, between a price and the median of the books
+    /// quoting the same bet; prices further out are ignored
+    pub max_median_gap: Option<f64>,
+}
+
+impl Default for ArbFilter {
+    fn default() -> Self {
+        Self {
+            require_distinct_books: true,
+            min_books_in_market: DEFAULT_MIN_BOOKS_IN_MARKET,
+            max_median_gap: None,
+        }
+    }
+}
+
+impl ArbFilter {
+    /// Copy of the games with out-of-band prices removed and markets quoted by too few
+    /// books cleared
+    pub fn screen(
+        &self,
+        games_with_odds: &[(Game, Vec<BettingOdds>)],
+    ) -> Vec<(Game, Vec<BettingOdds>)> {
+        games_with_odds
+            .iter()
+            .map(|(game, odds_list)| {
+                let mut odds_list = odds_list.clone();
+                if let Some(max_gap) = self.max_median_gap {
+                    drop_outlier_prices(&mut odds_list, max_gap);
+                }
+
+                let moneyline_books = odds_list
+                    .iter()
+                    .filter(|odds| odds.has_complete_moneyline(game))
+                    .count();
+                let spread_books = odds_list
+                    .iter()
+                    .filter(|odds| !odds.spreads.is_empty())
+                    .count();
+                for odds in &mut odds_list {
+                    if moneyline_books < self.min_books_in_market {
+                        odds.moneyline.clear();
+                    }
+                    if spread_books < self.min_books_in_market {
+                        odds.spreads.clear();
+                    }
+                }
+
+                (game.clone(), odds_list)
+            })
+            .collect()
+    }
+
+    /// Whether an arbitrage with legs at these two books passes
+    pub fn allows_books(&self, bookmaker1: &str, bookmaker2: &str) -> bool {
+        !self.require_distinct_books
+            || Bookmaker::from_title(bookmaker1) != Bookmaker::from_title(bookmaker2)
+    }
+}
+
+/// Median of some implied probabilities
+fn median(mut probs: Vec<f64>) -> f64 {
+    probs.sort_by(|a, b| a.total_cmp(b));
+    let mid = probs.len() / 2;
+    if probs.len().is_multiple_of(2) {
+        (probs[mid - 1] + probs[mid]) / 2.0
+    } else {
+        probs[mid]
+    }
+}
+
+/// Remove prices more than `max_gap` in implied probability from the median of every
+/// book's price on the same team (and, for spreads, the same line)
+fn drop_outlier_prices(odds_list: &mut [BettingOdds], max_gap: f64) {
+    let moneyline_quotes: Vec<(String, f64)> = odds_list
+        .iter()
+        .flat_map(|odds| &odds.moneyline)
+        .map(|moneyline| {
+            (
+                moneyline.team.clone(),
+                american_odds_to_probability(moneyline.price),
+            )
+        })
+        .collect();
+    let spread_quotes: Vec<(String, f64, f64)> = odds_list
+        .iter()
+        .flat_map(|odds| &odds.spreads)
+        .map(|spread| {
+            (
+                spread.team.clone(),
+                spread.point,
+                american_odds_to_probability(spread.price),
+            )
+        })
+        .collect();
+
+    for odds in odds_list.iter_mut() {
+        odds.moneyline.retain(|moneyline| {
+            let median_prob = median(
+                moneyline_quotes
+                    .iter()
+                    .filter(|(team, _)| *team == moneyline.team)
+                    .map(|(_, prob)| *prob)
+                    .collect(),
+            );
+            (american_odds_to_probability(moneyline.price) - median_prob).abs() <= max_gap
+        });
+        odds.spreads.retain(|spread| {
+            let median_prob = median(
+                spread_quotes
+                    .iter()
+                    .filter(|(team, point, _)| {
+                        *team == spread.team && (point - spread.point).abs() < LINE_TOLERANCE
+                    })
+                    .map(|(_, _, prob)| *prob)
+                    .collect(),
+            );
+            (american_odds_to_probability(spread.price) - median_prob).abs() <= max_gap
+        });
+    }
+}
+
+/// Minutes after which an arbitrage is likely gone
+fn stale_after_minutes(is_live: bool) -> i64 {
+    if is_live {
+        LIVE_STALE_ARB_MINUTES
+    } else {
+        STALE_ARB_MINUTES
+    }
+}
+
+/// Whole minutes since `oldest_update`, never negative
+fn age_minutes(oldest_update: Option<DateTime<Utc>>, now: DateTime<Utc>) -> Option<i64> {
+    oldest_update.map(|updated| (now - updated).num_minutes().max(0))
+}
+
+/// " | Age: Nm", flagged when stale, or nothing if the age isn't known
+fn format_age(age_minutes: Option<i64>, likely_gone: bool) -> String {
+    match age_minutes {
+        Some(age) if likely_gone => format!(" | Age: {}m (likely gone)", age),
+        Some(age) => format!(" | Age: {}m", age),
+        None => String::new(),
+    }
+}
+
 /// Represents an arbitrage opportunity for a moneyline bet
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MoneylineArbitrage {
@@ -16,12 +185,47 @@ pub struct MoneylineArbitrage {
     pub profit_percentage: f64,
     pub home_stake_percentage: f64,
     pub away_stake_percentage: f64,
+    /// Kickoff time, when known
+    #[serde(default)]
+    pub commence_time: Option<DateTime<Utc>>,
+    /// Odds API id of the game, when known
+    #[serde(default)]
+    pub game_id: Option<String>,
+    /// When the staler of the two legs' odds were last updated, when known
+    #[serde(default)]
+    pub oldest_update: Option<DateTime<Utc>>,
+    /// Whether the game is in progress (live odds)
+    #[serde(default)]
+    pub is_live: bool,
 }
 
 impl MoneylineArbitrage {
+    /// Whether either leg of this arbitrage is placed on Kalshi
+    pub fn involves_kalshi(&self) -> bool {
+        self.uses_any_bookmaker(&[Bookmaker::Kalshi])
+    }
+
+    /// Whether either leg is placed at one of the given bookmakers
+    pub fn uses_any_bookmaker(&self, bookmakers: &[Bookmaker]) -> bool {
+        bookmakers
+            .iter()
+            .any(|book| book.matches(&self.home_bookmaker) || book.matches(&self.away_bookmaker))
+    }
+
+    /// Minutes since the older leg's odds were updated, when known
+    pub fn age_minutes(&self) -> Option<i64> {
+        age_minutes(self.oldest_update, Utc::now())
+    }
+
+    /// Whether the odds are old enough that the arbitrage has likely disappeared
+    pub fn is_likely_gone(&self) -> bool {
+        self.age_minutes()
+            .is_some_and(|age| age >= stale_after_minutes(self.is_live))
+    }
+
     pub fn format(&self) -> String {
         format!(
-            "{} @ {} | Home: {} ({:+}) on {} [{:.2}%] | Away: {} ({:+}) on {} [{:.2}%] | Profit: {:.2}%",
+            "{} @ {} | Home: {} ({:+}) on {} [{:.2}%] | Away: {} ({:+}) on {} [{:.2}%] | Profit: {:.2}%{}",
             self.away_team,
             self.home_team,
             self.home_team,
@@ -32,7 +236,8 @@ impl MoneylineArbitrage {
             self.away_odds,
             self.away_bookmaker,
             self.away_stake_percentage,
-            self.profit_percentage
+            self.profit_percentage,
+            format_age(self.age_minutes(), self.is_likely_gone())
         )
     }
 }
@@ -53,12 +258,50 @@ pub struct SpreadArbitrage {
     pub profit_percentage: f64,
     pub side1_stake_percentage: f64,
     pub side2_stake_percentage: f64,
+    /// Kickoff time, when known
+    #[serde(default)]
+    pub commence_time: Option<DateTime<Utc>>,
+    /// Odds API id of the game, when known
+    #[serde(default)]
+    pub game_id: Option<String>,
+    /// When the staler of the two legs' odds were last updated, when known
+    #[serde(default)]
+    pub oldest_update: Option<DateTime<Utc>>,
+    /// Whether the game is in progress (live odds)
+    #[serde(default)]
+    pub is_live: bool,
+    /// Chance both legs win, when the lines leave a gap between them (0 for equal lines)
+    #[serde(default)]
+    pub middle_prob: f64,
 }
 
 impl SpreadArbitrage {
+    /// Whether either leg is placed at one of the given bookmakers
+    pub fn uses_any_bookmaker(&self, bookmakers: &[Bookmaker]) -> bool {
+        bookmakers
+            .iter()
+            .any(|book| book.matches(&self.side1_bookmaker) || book.matches(&self.side2_bookmaker))
+    }
+
+    /// Minutes since the older leg's odds were updated, when known
+    pub fn age_minutes(&self) -> Option<i64> {
+        age_minutes(self.oldest_update, Utc::now())
+    }
+
+    /// Whether the odds are old enough that the arbitrage has likely disappeared
+    pub fn is_likely_gone(&self) -> bool {
+        self.age_minutes()
+            .is_some_and(|age| age >= stale_after_minutes(self.is_live))
+    }
+
     pub fn format(&self) -> String {
+        let middle = if self.middle_prob > 0.0 {
+            format!(" | Middle: {:.1}%", self.middle_prob * 100.0)
+        } else {
+            String::new()
+        };
         format!(
-            "{} @ {} | {} ({:+.1}) ({:+}) on {} [{:.2}%] | {} ({:+.1}) ({:+}) on {} [{:.2}%] | Profit: {:.2}%",
+            "{} @ {} | {} ({:+.1}) ({:+}) on {} [{:.2}%] | {} ({:+.1}) ({:+}) on {} [{:.2}%] | Profit: {:.2}%{}{}",
             self.away_team,
             self.home_team,
             self.side1_team,
@@ -71,45 +314,292 @@ impl SpreadArbitrage {
             self.side2_odds,
             self.side2_bookmaker,
             self.side2_stake_percentage,
-            self.profit_percentage
+            self.profit_percentage,
+            middle,
+            format_age(self.age_minutes(), self.is_likely_gone())
         )
     }
 }
 
+/// Opposing spread bets whose lines leave a margin where both win, though missing it loses
+/// a little
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpreadMiddle {
+    pub home_team: String,
+    pub away_team: String,
+    pub side1_team: String,
+    pub side1_spread: f64,
+    pub side1_odds: i32,
+    pub side1_bookmaker: String,
+    pub side2_team: String,
+    pub side2_spread: f64,
+    pub side2_odds: i32,
+    pub side2_bookmaker: String,
+    /// Stakes that return the same amount whichever leg wins
+    pub side1_stake_percentage: f64,
+    pub side2_stake_percentage: f64,
+    /// Chance the final margin lands between the lines
+    pub middle_prob: f64,
+    /// Profit when only one leg wins (negative)
+    pub miss_percentage: f64,
+    /// Profit when both legs win
+    pub hit_percentage: f64,
+    /// Average profit over all margins, counting pushes on whole-number lines
+    pub expected_profit_percentage: f64,
+    #[serde(default)]
+    pub commence_time: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub game_id: Option<String>,
+    #[serde(default)]
+    pub oldest_update: Option<DateTime<Utc>>,
+}
+
+impl SpreadMiddle {
+    /// Whether either leg is placed at one of the given bookmakers
+    pub fn uses_any_bookmaker(&self, bookmakers: &[Bookmaker]) -> bool {
+        bookmakers
+            .iter()
+            .any(|book| book.matches(&self.side1_bookmaker) || book.matches(&self.side2_bookmaker))
+    }
+
+    pub fn format(&self) -> String {
+        format!(
+            "{} @ {} | {} ({:+.1}) ({:+}) on {} [{:.2}%] | {} ({:+.1}) ({:+}) on {} [{:.2}%] | Middle: {:.1}% | Hit: {:+.2}% | Miss: {:+.2}% | EV: {:+.2}%",
+            self.away_team,
+            self.home_team,
+            self.side1_team,
+            self.side1_spread,
+            self.side1_odds,
+            self.side1_bookmaker,
+            self.side1_stake_percentage,
+            self.side2_team,
+            self.side2_spread,
+            self.side2_odds,
+            self.side2_bookmaker,
+            self.side2_stake_percentage,
+            self.middle_prob * 100.0,
+            self.hit_percentage,
+            self.miss_percentage,
+            self.expected_profit_percentage,
+        )
+    }
+}
+
+/// How two bets on opposite sides of a spread combine
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SpreadPairKind {
+    /// No margin loses both bets and the prices guarantee a profit
+    Arbitrage,
+    /// Some margins win both bets, but the prices lose a little everywhere else
+    Middle,
+    /// Some margins lose both bets, or the lines match and the prices don't pay
+    Neither,
+}
+
+/// Classify opposing spread bets by their lines (each from its own team's side) and prices
+/// The lines' sum is the gap between them: zero when they match (-7/+7), positive when
+/// they can't both lose (-7/+7.5) and negative when some margins lose both (-7/+6.5).
+/// Final margins are whole points, so a middle needs a whole number strictly between the
+/// lines: -6.5/+7.5 hits on 7, while -7/+7.5 only turns a push into a win.
+pub fn classify_spread_pair(line1: f64, odds1: i32, line2: f64, odds2: i32) -> SpreadPairKind {
+    let gap = line1 + line2;
+    let total_prob = american_odds_to_probability(odds1) + american_odds_to_probability(odds2);
+    // Smallest margin for side 1 that covers, and largest that side 2 still covers
+    let lowest_both_win = (-line1 + LINE_TOLERANCE).floor() + 1.0;
+    let highest_both_win = (line2 - LINE_TOLERANCE).ceil() - 1.0;
+    if gap < -LINE_TOLERANCE {
+        SpreadPairKind::Neither
+    } else if total_prob < 1.0 {
+        SpreadPairKind::Arbitrage
+    } else if lowest_both_win <= highest_both_win {
+        SpreadPairKind::Middle
+    } else {
+        SpreadPairKind::Neither
+    }
+}
+
+/// Chance both legs win, with the final margin projected at the midpoint of the lines
+/// (the market's own estimate)
+fn pair_middle_prob(line1: f64, line2: f64) -> f64 {
+    middle_probability((line2 - line1) / 2.0, line1, line2, SPREAD_STD_DEV)
+}
+
+/// Whole-increment stakes for the two legs of an arbitrage and what they actually return
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RoundedStakes {
+    /// Stake on the home team (moneyline) or side 1 (spread)
+    pub stake_1: f64,
+    /// Stake on the away team (moneyline) or side 2 (spread)
+    pub stake_2: f64,
+    pub total_staked: f64,
+    /// Profit if whichever leg pays less wins; negative means the rounded bet can lose
+    pub worst_case_profit: f64,
+}
+
+impl RoundedStakes {
+    /// Worst-case profit as a percentage of the amount staked
+    pub fn worst_case_profit_percentage(&self) -> f64 {
+        if self.total_staked > 0.0 {
+            self.worst_case_profit / self.total_staked * 100.0
+        } else {
+            0.0
+        }
+    }
+
+    /// Whether the rounded stakes still lock in a profit
+    pub fn is_profitable(&self) -> bool {
+        self.worst_case_profit > 0.0
+    }
+
+    /// Format the stakes as a readable string
+    pub fn format(&self) -> String {
+        format!(
+            "Stakes: ${:.2} / ${:.2} (total ${:.2}) | Worst case: ${:+.2} ({:+.2}%){}",
+            self.stake_1,
+            self.stake_2,
+            self.total_staked,
+            self.worst_case_profit,
+            self.worst_case_profit_percentage(),
+            if self.is_profitable() {
+                ""
+            } else {
+                " | NOT AN ARB after rounding"
+            }
+        )
+    }
+}
+
+/// Split roughly `total` between two legs in multiples of `increment`, keeping the bet
+/// as balanced as possible
+/// Each leg's ideal stake is rounded down and up; the combination with the best
+/// worst-case profit wins, with ties going to the smaller total stake.
+fn round_two_way_stakes(legs: [(i32, f64); 2], total: f64, increment: f64) -> RoundedStakes {
+    let candidates = |odds_and_pct: (i32, f64)| {
+        let ideal = total * odds_and_pct.1 / 100.0 / increment;
+        [ideal.floor() * increment, ideal.ceil() * increment]
+    };
+    let payout = |odds: i32, stake: f64| stake + profit_on_win(odds, stake);
+
+    let mut best: Option<RoundedStakes> = None;
+    for stake_1 in candidates(legs[0]) {
+        for stake_2 in candidates(legs[1]) {
+            let total_staked = stake_1 + stake_2;
+            let worst_case_profit =
+                payout(legs[0].0, stake_1).min(payout(legs[1].0, stake_2)) - total_staked;
+            let candidate = RoundedStakes {
+                stake_1,
+                stake_2,
+                total_staked,
+                worst_case_profit,
+            };
+
+            let is_better = best.is_none_or(|best| {
+                worst_case_profit > best.worst_case_profit + 1e-9
+                    || ((worst_case_profit - best.worst_case_profit).abs() <= 1e-9
+                        && total_staked < best.total_staked)
+            });
+            if is_better {
+                best = Some(candidate);
+            }
+        }
+    }
+
+    best.expect("at least one stake combination")
+}
+
+/// Round a moneyline arbitrage's stakes for a `total` bankroll to multiples of `increment`
+/// (e.g. 1.0 for whole dollars, 0.5 for half dollars), recomputing the guaranteed profit.
+/// Rounding can turn a thin arb into a loss, so check `worst_case_profit` before betting.
+pub fn round_to_increment(arb: &MoneylineArbitrage, total: f64, increment: f64) -> RoundedStakes {
+    round_two_way_stakes(
+        [
+            (arb.home_odds, arb.home_stake_percentage),
+            (arb.away_odds, arb.away_stake_percentage),
+        ],
+        total,
+        increment,
+    )
+}
+
+/// Round a spread arbitrage's stakes to multiples of `increment`; see [`round_to_increment`]
+pub fn round_spread_to_increment(
+    arb: &SpreadArbitrage,
+    total: f64,
+    increment: f64,
+) -> RoundedStakes {
+    round_two_way_stakes(
+        [
+            (arb.side1_odds, arb.side1_stake_percentage),
+            (arb.side2_odds, arb.side2_stake_percentage),
+        ],
+        total,
+        increment,
+    )
+}
+
+/// Best pair of legs the filter allows, given the best price on each side
+/// When both best prices are at one book and the filter wants distinct books, each is
+/// paired with the best price at another book on the other side, and the pair with the
+/// lower combined implied probability wins.
+fn best_leg_pair<'a>(
+    filter: &ArbFilter,
+    top1: BestOdds<'a>,
+    top2: BestOdds<'a>,
+    best1_elsewhere: impl Fn(&str) -> Option<BestOdds<'a>>,
+    best2_elsewhere: impl Fn(&str) -> Option<BestOdds<'a>>,
+) -> Option<(BestOdds<'a>, BestOdds<'a>)> {
+    if filter.allows_books(top1.bookmaker, top2.bookmaker) {
+        return Some((top1, top2));
+    }
+
+    let total_prob = |(leg1, leg2): &(BestOdds, BestOdds)| {
+        american_odds_to_probability(leg1.price) + american_odds_to_probability(leg2.price)
+    };
+    let with_top1 = best2_elsewhere(top1.bookmaker).map(|leg2| (top1, leg2));
+    let with_top2 = best1_elsewhere(top2.bookmaker).map(|leg1| (leg1, top2));
+    with_top1
+        .into_iter()
+        .chain(with_top2)
+        .min_by(|a, b| total_prob(a).total_cmp(&total_prob(b)))
+}
+
 /// Find arbitrage opportunities in moneyline bets
+/// Legs at the same book are only paired if `filter` allows it.
 pub fn find_moneyline_arbitrage(
     games_with_odds: &[(Game, Vec<BettingOdds>)],
+    filter: &ArbFilter,
 ) -> Result<Vec<MoneylineArbitrage>> {
     let mut arbitrage_opportunities = Vec::new();
 
-    let now = Utc::now();
-    let games_with_odds = games_with_odds.iter().filter(|g| g.0.commence_time >= now);
+    for (game, odds_list) in priceable_games(games_with_odds) {
+        // Find the best odds for each team across all bookmakers. Only books pricing both
+        // sides count; a one-sided market is usually suspended
+        let complete_books = || {
+            odds_list
+                .iter()
+                .filter(|odds| odds.has_complete_moneyline(game))
+        };
+        let best_elsewhere = |team: &str, bookmaker: &str| {
+            best_moneyline(
+                complete_books().filter(|odds| filter.allows_books(&odds.bookmaker, bookmaker)),
+                team,
+            )
+        };
+        let best_pair = best_moneyline(complete_books(), &game.home_team)
+            .zip(best_moneyline(complete_books(), &game.away_team))
+            .and_then(|(top_home, top_away)| {
+                best_leg_pair(
+                    filter,
+                    top_home,
+                    top_away,
+                    |bookmaker| best_elsewhere(&game.home_team, bookmaker),
+                    |bookmaker| best_elsewhere(&game.away_team, bookmaker),
+                )
+            });
+
+        if let Some((best_home, best_away)) = best_pair {
+            let (home_odds, away_odds) = (best_home.price, best_away.price);
 
-    for (game, odds_list) in games_with_odds {
-        // Find best odds for home team across all bookmakers
-        let mut best_home_odds: Option<(i32, String)> = None;
-        let mut best_away_odds: Option<(i32, String)> = None;
-
-        for bookmaker_odds in odds_list {
-            for moneyline in &bookmaker_odds.moneyline {
-                if moneyline.team == game.home_team {
-                    if best_home_odds.is_none()
-                        || moneyline.price > best_home_odds.as_ref().unwrap().0
-                    {
-                        best_home_odds = Some((moneyline.price, bookmaker_odds.bookmaker.clone()));
-                    }
-                } else if moneyline.team == game.away_team
-                    && (best_away_odds.is_none()
-                        || moneyline.price > best_away_odds.as_ref().unwrap().0)
-                {
-                    best_away_odds = Some((moneyline.price, bookmaker_odds.bookmaker.clone()));
-                }
-            }
-        }
-
-        if let (Some((home_odds, home_bookmaker)), Some((away_odds, away_bookmaker))) =
-            (best_home_odds, best_away_odds)
-        {
             // Calculate implied probabilities
             let home_prob = american_odds_to_probability(home_odds);
             let away_prob = american_odds_to_probability(away_odds);
@@ -128,13 +618,17 @@ pub fn find_moneyline_arbitrage(
                 arbitrage_opportunities.push(MoneylineArbitrage {
                     home_team: game.home_team.clone(),
                     away_team: game.away_team.clone(),
-                    home_bookmaker,
-                    away_bookmaker,
+                    home_bookmaker: best_home.bookmaker.to_string(),
+                    away_bookmaker: best_away.bookmaker.to_string(),
                     home_odds,
                     away_odds,
                     profit_percentage,
                     home_stake_percentage,
                     away_stake_percentage,
+                    commence_time: Some(game.commence_time),
+                    game_id: Some(game.id.clone()),
+                    oldest_update: Some(best_home.last_update.min(best_away.last_update)),
+                    is_live: game.is_live,
                 });
             }
         }
@@ -150,69 +644,269 @@ pub fn find_moneyline_arbitrage(
     Ok(arbitrage_opportunities)
 }
 
+/// Merge Kalshi odds into sportsbook games
+/// Games are matched like [`GameMatcher`] (school names in either home/away order, kickoffs
+/// within a day), and Kalshi's team names are rewritten to the sportsbook's so both sources
+/// price the same outcomes. Kalshi games with no sportsbook match are added as new entries.
+pub fn merge_kalshi_odds(
+    existing_games: &mut Vec<(Game, Vec<BettingOdds>)>,
+    kalshi_games: Vec<(Game, Vec<BettingOdds>)>,
+) {
+    let mut matcher = GameMatcher::new(existing_games.iter().map(|(game, _)| {
+        (
+            game.home_team.as_str(),
+            game.away_team.as_str(),
+            game.commence_time,
+        )
+    }));
+
+    for (kalshi_game, kalshi_odds_list) in kalshi_games {
+        match matcher.find(
+            &kalshi_game.home_team,
+            &kalshi_game.away_team,
+            kalshi_game.commence_time,
+        ) {
+            Some(game_match) => {
+                let (existing_game, existing_odds_list) = &mut existing_games[game_match.index];
+                for mut kalshi_odds in kalshi_odds_list {
+                    adopt_game_names(&mut kalshi_odds, existing_game);
+                    existing_odds_list.push(kalshi_odds);
+                }
+            }
+            None => {
+                // No matching game found, add as new entry
+                matcher.push(
+                    &kalshi_game.home_team,
+                    &kalshi_game.away_team,
+                    kalshi_game.commence_time,
+                );
+                existing_games.push((kalshi_game, kalshi_odds_list));
+            }
+        }
+    }
+}
+
+/// Point odds from another listing of `game` at its id and team names
+fn adopt_game_names(odds: &mut BettingOdds, game: &Game) {
+    odds.game_id = game.id.clone();
+    let home_key = extract_school_name(&game.home_team);
+    let away_key = extract_school_name(&game.away_team);
+    let team_names = odds
+        .moneyline
+        .iter_mut()
+        .map(|moneyline| &mut moneyline.team)
+        .chain(odds.spreads.iter_mut().map(|spread| &mut spread.team));
+    for team in team_names {
+        let team_key = extract_school_name(team);
+        if team_key == home_key {
+            *team = game.home_team.clone();
+        } else if team_key == away_key {
+            *team = game.away_team.clone();
+        }
+    }
+}
+
+/// Merge games listed more than once into a single entry
+/// Listings match like [`GameMatcher`] (school names in either order, kickoffs within a
+/// day). The first listing is kept, the others' odds are renamed to it, and each
+/// (bookmaker, market) pair keeps only its first copy, so one book can't be paired with
+/// itself as a false arbitrage.
+pub fn dedupe_games(
+    games_with_odds: Vec<(Game, Vec<BettingOdds>)>,
+) -> Vec<(Game, Vec<BettingOdds>)> {
+    let mut merged: Vec<(Game, Vec<BettingOdds>)> = Vec::with_capacity(games_with_odds.len());
+    let mut matcher = GameMatcher::default();
+
+    for (game, odds_list) in games_with_odds {
+        let index = match matcher.find(&game.home_team, &game.away_team, game.commence_time) {
+            Some(game_match) => game_match.index,
+            None => {
+                matcher.push(&game.home_team, &game.away_team, game.commence_time);
+                merged.push((game, Vec::new()));
+                merged.len() - 1
+            }
+        };
+
+        let (kept_game, kept_odds) = &mut merged[index];
+        for odds in odds_list {
+            merge_book_odds(kept_odds, kept_game, odds);
+        }
+    }
+
+    merged
+}
+
+/// Add one book's odds to a game, skipping any market the book already has there
+fn merge_book_odds(odds_list: &mut Vec<BettingOdds>, game: &Game, mut odds: BettingOdds) {
+    adopt_game_names(&mut odds, game);
+    match odds_list
+        .iter_mut()
+        .find(|existing| existing.bookmaker == odds.bookmaker)
+    {
+        Some(existing) => {
+            if existing.moneyline.is_empty() && !odds.moneyline.is_empty() {
+                existing.moneyline = odds.moneyline;
+                existing.last_update = existing.last_update.min(odds.last_update);
+            }
+            if existing.spreads.is_empty() && !odds.spreads.is_empty() {
+                existing.spreads = odds.spreads;
+                existing.last_update = existing.last_update.min(odds.last_update);
+            }
+        }
+        None => odds_list.push(odds),
+    }
+}
+
+/// Find moneyline arbitrage between Kalshi and the sportsbooks
+/// Merges both sources by matched game, runs the moneyline arbitrage search across the
+/// combined book list, and keeps only opportunities with a Kalshi leg.
+pub fn find_kalshi_moneyline_arbitrage(
+    sportsbook_games: &[(Game, Vec<BettingOdds>)],
+    kalshi_games: &[(Game, Vec<BettingOdds>)],
+    filter: &ArbFilter,
+) -> Result<Vec<MoneylineArbitrage>> {
+    let mut combined_games = sportsbook_games.to_vec();
+    merge_kalshi_odds(&mut combined_games, kalshi_games.to_vec());
+
+    let mut arbitrage_opportunities = find_moneyline_arbitrage(&combined_games, filter)?;
+    arbitrage_opportunities.retain(|arb| arb.involves_kalshi());
+
+    Ok(arbitrage_opportunities)
+}
+
+/// The best price on one team and spread line across all bookmakers
+struct BestSpread<'a> {
+    team: &'a str,
+    point: f64,
+    best: BestOdds<'a>,
+}
+
+/// Best price for every team and line offered on a game, in the order first seen
+/// Ties keep the first bookmaker listed.
+fn best_spreads(odds_list: &[BettingOdds]) -> Vec<BestSpread<'_>> {
+    let mut best: Vec<BestSpread> = Vec::new();
+
+    for spread in odds_list.iter().flat_map(|odds| &odds.spreads) {
+        let seen = best.iter().any(|current| {
+            current.team == spread.team && (current.point - spread.point).abs() < LINE_TOLERANCE
+        });
+        if seen {
+            continue;
+        }
+        if let Some(best_odds) = best_spread(odds_list, &spread.team, spread.point) {
+            best.push(BestSpread {
+                team: &spread.team,
+                point: spread.point,
+                best: best_odds,
+            });
+        }
+    }
+
+    best
+}
+
+/// Every pair of bets on opposite teams
+fn opposing_pairs<'s, 'a>(
+    spreads: &'s [BestSpread<'a>],
+) -> impl Iterator<Item = (&'s BestSpread<'a>, &'s BestSpread<'a>)> {
+    (0..spreads.len())
+        .flat_map(move |i| ((i + 1)..spreads.len()).map(move |j| (&spreads[i], &spreads[j])))
+        .filter(|(leg1, leg2)| leg1.team != leg2.team)
+}
+
+/// Best pair of books for two spread legs that `filter` allows
+fn best_spread_pair<'a>(
+    odds_list: &'a [BettingOdds],
+    filter: &ArbFilter,
+    leg1: &BestSpread<'a>,
+    leg2: &BestSpread<'a>,
+) -> Option<(BestOdds<'a>, BestOdds<'a>)> {
+    let best_elsewhere = |leg: &BestSpread, bookmaker: &str| {
+        best_spread(
+            odds_list
+                .iter()
+                .filter(|odds| filter.allows_books(&odds.bookmaker, bookmaker)),
+            leg.team,
+            leg.point,
+        )
+    };
+    best_leg_pair(
+        filter,
+        leg1.best,
+        leg2.best,
+        |bookmaker| best_elsewhere(leg1, bookmaker),
+        |bookmaker| best_elsewhere(leg2, bookmaker),
+    )
+}
+
+/// Games to price: upcoming ones, and started ones only from the live odds feed
+fn priceable_games(
+    games_with_odds: &[(Game, Vec<BettingOdds>)],
+) -> impl Iterator<Item = &(Game, Vec<BettingOdds>)> {
+    let now = Utc::now();
+    games_with_odds
+        .iter()
+        .filter(move |g| g.0.is_live || g.0.commence_time >= now)
+}
+
 /// Find arbitrage opportunities in spread bets
+/// Any opposing lines qualify as long as no margin loses both bets: equal lines, or lines
+/// with a gap that wins both (see [`classify_spread_pair`]). Each team and line is priced
+/// at its best book first, so every line pair yields at most one opportunity: the most
+/// profitable combination of books `filter` allows.
 pub fn find_spread_arbitrage(
     games_with_odds: &[(Game, Vec<BettingOdds>)],
+    filter: &ArbFilter,
 ) -> Result<Vec<SpreadArbitrage>> {
     let mut arbitrage_opportunities = Vec::new();
-    let now = Utc::now();
-    let games_with_odds = games_with_odds.iter().filter(|g| g.0.commence_time >= now);
 
-    for (game, odds_list) in games_with_odds {
-        // Collect all spread odds for this game
-        let mut all_spreads: Vec<(String, f64, i32, String)> = Vec::new();
-
-        for bookmaker_odds in odds_list {
-            for spread in &bookmaker_odds.spreads {
-                all_spreads.push((
-                    spread.team.clone(),
-                    spread.point,
-                    spread.price,
-                    bookmaker_odds.bookmaker.clone(),
-                ));
+    for (game, odds_list) in priceable_games(games_with_odds) {
+        // Pair only the best price on each team and line, so a better-priced leg is
+        // never passed over for a worse one
+        let all_spreads = best_spreads(odds_list);
+
+        for (leg1, leg2) in opposing_pairs(&all_spreads) {
+            let Some((best1, best2)) = best_spread_pair(odds_list, filter, leg1, leg2) else {
+                continue;
+            };
+            let (odds1, odds2) = (best1.price, best2.price);
+            if classify_spread_pair(leg1.point, odds1, leg2.point, odds2)
+                != SpreadPairKind::Arbitrage
+            {
+                continue;
             }
-        }
 
-        // Look for arbitrage between opposing spreads
-        for i in 0..all_spreads.len() {
-            for j in (i + 1)..all_spreads.len() {
-                let (team1, spread1, odds1, book1) = &all_spreads[i];
-                let (team2, spread2, odds2, book2) = &all_spreads[j];
-
-                // Check if these are opposing bets (one on each team)
-                // and the spreads are equal and opposite (or close enough)
-                if team1 != team2 && (spread1 + spread2).abs() < 0.1 {
-                    let prob1 = american_odds_to_probability(*odds1);
-                    let prob2 = american_odds_to_probability(*odds2);
-
-                    let total_prob = prob1 + prob2;
-
-                    // If total probability < 1, we have an arbitrage opportunity
-                    if total_prob < 1.0 {
-                        let profit_percentage = (1.0 / total_prob - 1.0) * 100.0;
-
-                        // Calculate optimal stake percentages
-                        let stake1_percentage = (prob1 / total_prob) * 100.0;
-                        let stake2_percentage = (prob2 / total_prob) * 100.0;
-
-                        arbitrage_opportunities.push(SpreadArbitrage {
-                            home_team: game.home_team.clone(),
-                            away_team: game.away_team.clone(),
-                            side1_team: team1.clone(),
-                            side1_spread: *spread1,
-                            side1_odds: *odds1,
-                            side1_bookmaker: book1.clone(),
-                            side2_team: team2.clone(),
-                            side2_spread: *spread2,
-                            side2_odds: *odds2,
-                            side2_bookmaker: book2.clone(),
-                            profit_percentage,
-                            side1_stake_percentage: stake1_percentage,
-                            side2_stake_percentage: stake2_percentage,
-                        });
-                    }
-                }
-            }
+            let prob1 = american_odds_to_probability(odds1);
+            let prob2 = american_odds_to_probability(odds2);
+            let total_prob = prob1 + prob2;
+
+            // Guaranteed profit whichever leg wins (a middle pays both on top of it)
+            let profit_percentage = (1.0 / total_prob - 1.0) * 100.0;
+
+            // Calculate optimal stake percentages
+            let stake1_percentage = (prob1 / total_prob) * 100.0;
+            let stake2_percentage = (prob2 / total_prob) * 100.0;
+
+            arbitrage_opportunities.push(SpreadArbitrage {
+                home_team: game.home_team.clone(),
+                away_team: game.away_team.clone(),
+                side1_team: leg1.team.to_string(),
+                side1_spread: leg1.point,
+                side1_odds: odds1,
+                side1_bookmaker: best1.bookmaker.to_string(),
+                side2_team: leg2.team.to_string(),
+                side2_spread: leg2.point,
+                side2_odds: odds2,
+                side2_bookmaker: best2.bookmaker.to_string(),
+                profit_percentage,
+                side1_stake_percentage: stake1_percentage,
+                side2_stake_percentage: stake2_percentage,
+                commence_time: Some(game.commence_time),
+                game_id: Some(game.id.clone()),
+                oldest_update: Some(best1.last_update.min(best2.last_update)),
+                is_live: game.is_live,
+                middle_prob: pair_middle_prob(leg1.point, leg2.point),
+            });
         }
     }
 
@@ -223,26 +917,114 @@ pub fn find_spread_arbitrage(
             .unwrap_or(std::cmp::Ordering::Equal)
     });
 
-    // Remove duplicates (same arb from different perspectives)
-    let mut seen = std::collections::HashSet::new();
-    arbitrage_opportunities.retain(|arb| {
-        let key = format!(
-            "{}_{}_{}_{}_{}",
-            arb.home_team,
-            arb.away_team,
-            arb.side1_bookmaker,
-            arb.side2_bookmaker,
-            arb.profit_percentage
-        );
-        seen.insert(key)
-    });
+    dedupe_spread_arbitrage(&mut arbitrage_opportunities);
 
     Ok(arbitrage_opportunities)
 }
 
+/// Find spread middles worth playing: opposing lines with a gap that wins both bets, where
+/// the chance of landing in the gap outweighs the small loss everywhere else
+/// Best expected profit first. Pairs that are outright arbitrage are left to
+/// [`find_spread_arbitrage`], and legs pair across books like it.
+pub fn find_spread_middles(
+    games_with_odds: &[(Game, Vec<BettingOdds>)],
+    filter: &ArbFilter,
+) -> Result<Vec<SpreadMiddle>> {
+    let mut middles = Vec::new();
+
+    for (game, odds_list) in priceable_games(games_with_odds) {
+        let all_spreads = best_spreads(odds_list);
+
+        for (leg1, leg2) in opposing_pairs(&all_spreads) {
+            let Some((best1, best2)) = best_spread_pair(odds_list, filter, leg1, leg2) else {
+                continue;
+            };
+            let (odds1, odds2) = (best1.price, best2.price);
+            if classify_spread_pair(leg1.point, odds1, leg2.point, odds2) != SpreadPairKind::Middle
+            {
+                continue;
+            }
+
+            let prob1 = american_odds_to_probability(odds1);
+            let prob2 = american_odds_to_probability(odds2);
+            let total_prob = prob1 + prob2;
+            let (stake1, stake2) = (prob1 / total_prob, prob2 / total_prob);
+
+            // Each winning leg returns 1 / total_prob of the combined stake; a push returns
+            // its own stake
+            let projected_margin = (leg2.point - leg1.point) / 2.0;
+            let outcome1 = spread_outcome(projected_margin, leg1.point, SPREAD_STD_DEV);
+            let outcome2 = spread_outcome(-projected_margin, leg2.point, SPREAD_STD_DEV);
+            let expected_return = (outcome1.win + outcome2.win) / total_prob
+                + stake1 * outcome1.push
+                + stake2 * outcome2.push;
+            if expected_return <= 1.0 {
+                continue;
+            }
+
+            middles.push(SpreadMiddle {
+                home_team: game.home_team.clone(),
+                away_team: game.away_team.clone(),
+                side1_team: leg1.team.to_string(),
+                side1_spread: leg1.point,
+                side1_odds: odds1,
+                side1_bookmaker: best1.bookmaker.to_string(),
+                side2_team: leg2.team.to_string(),
+                side2_spread: leg2.point,
+                side2_odds: odds2,
+                side2_bookmaker: best2.bookmaker.to_string(),
+                side1_stake_percentage: stake1 * 100.0,
+                side2_stake_percentage: stake2 * 100.0,
+                middle_prob: pair_middle_prob(leg1.point, leg2.point),
+                miss_percentage: (1.0 / total_prob - 1.0) * 100.0,
+                hit_percentage: (2.0 / total_prob - 1.0) * 100.0,
+                expected_profit_percentage: (expected_return - 1.0) * 100.0,
+                commence_time: Some(game.commence_time),
+                game_id: Some(game.id.clone()),
+                oldest_update: Some(best1.last_update.min(best2.last_update)),
+            });
+        }
+    }
+
+    middles.sort_by(|a, b| {
+        b.expected_profit_percentage
+            .total_cmp(&a.expected_profit_percentage)
+    });
+    Ok(middles)
+}
+
+/// One leg of a spread arbitrage: team, line in tenths of a point, and bookmaker
+type SpreadLegKey = (String, i64, String);
+
+/// Identity of a spread arbitrage regardless of which leg is listed first
+/// Lines are compared in tenths of a point so float noise can't split a duplicate.
+fn spread_arbitrage_key(arb: &SpreadArbitrage) -> (String, String, [SpreadLegKey; 2]) {
+    let leg = |team: &str, spread: f64, bookmaker: &str| {
+        (
+            team.to_string(),
+            (spread * 10.0).round() as i64,
+            bookmaker.to_string(),
+        )
+    };
+    let mut legs = [
+        leg(&arb.side1_team, arb.side1_spread, &arb.side1_bookmaker),
+        leg(&arb.side2_team, arb.side2_spread, &arb.side2_bookmaker),
+    ];
+    legs.sort();
+    (arb.home_team.clone(), arb.away_team.clone(), legs)
+}
+
+/// Remove spread arbitrage that repeats an earlier one with its legs swapped, keeping
+/// the first
+fn dedupe_spread_arbitrage(arbs: &mut Vec<SpreadArbitrage>) {
+    let mut seen = std::collections::HashSet::new();
+    arbs.retain(|arb| seen.insert(spread_arbitrage_key(arb)));
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::kalshi_api::KALSHI_BOOKMAKER;
     use crate::models::{BettingOdds, Game, MoneylineOdds, SpreadOdds};
     use chrono::{Duration, Utc};
 
@@ -253,6 +1035,7 @@ mod tests {
             away_team: away.to_string(),
             commence_time: Utc::now() + Duration::milliseconds(1),
             sport_title: "Test Sport".to_string(),
+            is_live: false,
         }
     }
 
@@ -271,6 +1054,20 @@ mod tests {
         }
     }
 
+    /// Moneyline odds for "Home Team" and "Away Team"
+    fn create_moneyline_odds(home_price: i32, away_price: i32) -> Vec<MoneylineOdds> {
+        vec![
+            MoneylineOdds {
+                team: "Home Team".to_string(),
+                price: home_price,
+            },
+            MoneylineOdds {
+                team: "Away Team".to_string(),
+                price: away_price,
+            },
+        ]
+    }
+
     #[test]
     fn test_moneyline_arbitrage_found() {
         // Setup: Create a game with arbitrage opportunity
@@ -281,26 +1078,20 @@ mod tests {
         let book_a_odds = create_betting_odds(
             &game.id,
             "BookmakerA",
-            vec![MoneylineOdds {
-                team: "Home Team".to_string(),
-                price: 120,
-            }],
+            create_moneyline_odds(120, -150),
             vec![],
         );
 
         let book_b_odds = create_betting_odds(
             &game.id,
             "BookmakerB",
-            vec![MoneylineOdds {
-                team: "Away Team".to_string(),
-                price: 125,
-            }],
+            create_moneyline_odds(-155, 125),
             vec![],
         );
 
         let games_with_odds = vec![(game.clone(), vec![book_a_odds, book_b_odds])];
 
-        let result = find_moneyline_arbitrage(&games_with_odds).unwrap();
+        let result = find_moneyline_arbitrage(&games_with_odds, &ArbFilter::default()).unwrap();
 
         assert_eq!(result.len(), 1);
         let arb = &result[0];
@@ -313,6 +1104,67 @@ mod tests {
         assert!(arb.home_stake_percentage + arb.away_stake_percentage < 101.0);
     }
 
+    #[test]
+    fn test_started_games_need_live_odds() {
+        let mut game = create_test_game("Home Team", "Away Team");
+        game.commence_time = Utc::now() - Duration::minutes(30);
+        let odds = vec![
+            create_betting_odds(
+                &game.id,
+                "BookmakerA",
+                create_moneyline_odds(120, -150),
+                vec![],
+            ),
+            create_betting_odds(
+                &game.id,
+                "BookmakerB",
+                create_moneyline_odds(-155, 125),
+                vec![],
+            ),
+        ];
+
+        let pregame = vec![(game.clone(), odds.clone())];
+        assert!(find_moneyline_arbitrage(&pregame, &ArbFilter::default())
+            .unwrap()
+            .is_empty());
+
+        game.is_live = true;
+        let result = find_moneyline_arbitrage(&[(game, odds)], &ArbFilter::default()).unwrap();
+        assert_eq!(result.len(), 1);
+        assert!(result[0].is_live);
+    }
+
+    #[test]
+    fn test_moneyline_arbitrage_ignores_one_sided_books() {
+        // BookmakerB's away side is suspended; its +150 home price would make an arb with
+        // BookmakerA's away price, but it can't be trusted
+        let game = create_test_game("Home Team", "Away Team");
+
+        let book_a_odds = create_betting_odds(
+            &game.id,
+            "BookmakerA",
+            create_moneyline_odds(-130, 110),
+            vec![],
+        );
+        let book_b_odds = create_betting_odds(
+            &game.id,
+            "BookmakerB",
+            vec![MoneylineOdds {
+                team: "Home Team".to_string(),
+                price: 150,
+            }],
+            vec![],
+        );
+        assert!(book_a_odds.has_complete_moneyline(&game));
+        assert!(!book_b_odds.has_complete_moneyline(&game));
+
+        let games_with_odds = vec![(game, vec![book_a_odds, book_b_odds])];
+
+        let result = find_moneyline_arbitrage(&games_with_odds, &ArbFilter::default()).unwrap();
+
+        assert!(result.is_empty());
+    }
+
     #[test]
     fn test_moneyline_no_arbitrage() {
         // Setup: No arbitrage opportunity (normal vig)
@@ -338,7 +1190,7 @@ mod tests {
 
         let games_with_odds = vec![(game.clone(), vec![book_a_odds])];
 
-        let result = find_moneyline_arbitrage(&games_with_odds).unwrap();
+        let result = find_moneyline_arbitrage(&games_with_odds, &ArbFilter::default()).unwrap();
 
         assert_eq!(result.len(), 0);
     }
@@ -367,26 +1219,20 @@ mod tests {
         let book_b_odds = create_betting_odds(
             &game.id,
             "BookmakerB",
-            vec![MoneylineOdds {
-                team: "Home Team".to_string(),
-                price: 130, // Best home odds
-            }],
+            create_moneyline_odds(130, -160), // Best home odds
             vec![],
         );
 
         let book_c_odds = create_betting_odds(
             &game.id,
             "BookmakerC",
-            vec![MoneylineOdds {
-                team: "Away Team".to_string(),
-                price: 140, // Best away odds
-            }],
+            create_moneyline_odds(-170, 140), // Best away odds
             vec![],
         );
 
         let games_with_odds = vec![(game.clone(), vec![book_a_odds, book_b_odds, book_c_odds])];
 
-        let result = find_moneyline_arbitrage(&games_with_odds).unwrap();
+        let result = find_moneyline_arbitrage(&games_with_odds, &ArbFilter::default()).unwrap();
 
         assert_eq!(result.len(), 1);
         let arb = &result[0];
@@ -425,7 +1271,7 @@ mod tests {
 
         let games_with_odds = vec![(game.clone(), vec![book_a_odds, book_b_odds])];
 
-        let result = find_spread_arbitrage(&games_with_odds).unwrap();
+        let result = find_spread_arbitrage(&games_with_odds, &ArbFilter::default()).unwrap();
 
         assert_eq!(result.len(), 1);
         let arb = &result[0];
@@ -459,7 +1305,7 @@ mod tests {
 
         let games_with_odds = vec![(game.clone(), vec![book_a_odds])];
 
-        let result = find_spread_arbitrage(&games_with_odds).unwrap();
+        let result = find_spread_arbitrage(&games_with_odds, &ArbFilter::default()).unwrap();
 
         assert_eq!(result.len(), 0);
     }
@@ -493,22 +1339,634 @@ mod tests {
 
         let games_with_odds = vec![(game.clone(), vec![book_a_odds, book_b_odds])];
 
-        let result = find_spread_arbitrage(&games_with_odds).unwrap();
+        let result = find_spread_arbitrage(&games_with_odds, &ArbFilter::default()).unwrap();
 
         assert_eq!(result.len(), 0);
     }
 
+    #[test]
+    fn test_spread_arbitrage_pairs_alternate_lines() {
+        // Both books list a main line and an alternate; only the alternates form an arb
+        let game = create_test_game("Home Team", "Away Team");
+        let spread = |team: &str, point: f64, price: i32| SpreadOdds {
+            team: team.to_string(),
+            point,
+            price,
+        };
+
+        let book_a_odds = create_betting_odds(
+            &game.id,
+            "BookmakerA",
+            vec![],
+            vec![
+                spread("Home Team", -3.5, -110),
+                spread("Home Team", -6.5, 150),
+            ],
+        );
+        let book_b_odds = create_betting_odds(
+            &game.id,
+            "BookmakerB",
+            vec![],
+            vec![
+                spread("Away Team", 3.5, -110),
+                spread("Away Team", 6.5, -140),
+            ],
+        );
+
+        let games_with_odds = vec![(game.clone(), vec![book_a_odds, book_b_odds])];
+
+        let result = find_spread_arbitrage(&games_with_odds, &ArbFilter::default()).unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].side1_spread, -6.5);
+        assert_eq!(result[0].side2_spread, 6.5);
+    }
+
+    #[test]
+    fn test_spread_arbitrage_uses_best_price_per_line() {
+        // Two books on Home -7 at different prices against one book on Away +7
+        let game = create_test_game("Home Team", "Away Team");
+        let spread = |team: &str, point: f64, price: i32| SpreadOdds {
+            team: team.to_string(),
+            point,
+            price,
+        };
+
+        let games_with_odds = vec![(
+            game.clone(),
+            vec![
+                create_betting_odds(
+                    &game.id,
+                    "BookmakerA",
+                    vec![],
+                    vec![spread("Home Team", -7.0, 105)],
+                ),
+                create_betting_odds(
+                    &game.id,
+                    "BookmakerB",
+                    vec![],
+                    vec![spread("Home Team", -7.0, 120)],
+                ),
+                create_betting_odds(
+                    &game.id,
+                    "BookmakerC",
+                    vec![],
+                    vec![spread("Away Team", 7.0, -105)],
+                ),
+            ],
+        )];
+
+        let result = find_spread_arbitrage(&games_with_odds, &ArbFilter::default()).unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].side1_bookmaker, "BookmakerB");
+        assert_eq!(result[0].side1_odds, 120);
+        assert_eq!(result[0].side2_bookmaker, "BookmakerC");
+    }
+
+    #[test]
+    fn test_spread_arbitrage_dedupes_mirrored_entries() {
+        // -7/+7, -7.5/+7.5 and -7/+7.5 on the same game at the same prices: same profit,
+        // different arbs
+        let game = create_test_game("Home Team", "Away Team");
+        let spread = |team: &str, point: f64| SpreadOdds {
+            team: team.to_string(),
+            point,
+            price: 110,
+        };
+        let games_with_odds = vec![(
+            game.clone(),
+            vec![
+                create_betting_odds(
+                    &game.id,
+                    "BookmakerA",
+                    vec![],
+                    vec![spread("Home Team", -7.0), spread("Home Team", -7.5)],
+                ),
+                create_betting_odds(
+                    &game.id,
+                    "BookmakerB",
+                    vec![],
+                    vec![spread("Away Team", 7.0), spread("Away Team", 7.5)],
+                ),
+            ],
+        )];
+
+        let mut arbs = find_spread_arbitrage(&games_with_odds, &ArbFilter::default()).unwrap();
+        assert_eq!(arbs.len(), 3);
+        assert!(arbs
+            .iter()
+            .all(|arb| arb.profit_percentage == arbs[0].profit_percentage));
+
+        // The first arb seen from the other side, with a line that formats differently
+        let first = arbs[0].clone();
+        arbs.push(SpreadArbitrage {
+            side1_team: first.side2_team.clone(),
+            side1_spread: first.side2_spread + 1e-9,
+            side1_odds: first.side2_odds,
+            side1_bookmaker: first.side2_bookmaker.clone(),
+            side2_team: first.side1_team.clone(),
+            side2_spread: first.side1_spread,
+            side2_odds: first.side1_odds,
+            side2_bookmaker: first.side1_bookmaker.clone(),
+            side1_stake_percentage: first.side2_stake_percentage,
+            side2_stake_percentage: first.side1_stake_percentage,
+            ..first
+        });
+
+        dedupe_spread_arbitrage(&mut arbs);
+
+        assert_eq!(arbs.len(), 3);
+        let keys: std::collections::HashSet<_> = arbs.iter().map(spread_arbitrage_key).collect();
+        assert_eq!(keys.len(), 3);
+    }
+
+    #[test]
+    fn test_classify_spread_pair() {
+        // Equal lines: an arb only when the prices pay
+        assert_eq!(
+            classify_spread_pair(-6.5, 105, 6.5, 105),
+            SpreadPairKind::Arbitrage
+        );
+        assert_eq!(
+            classify_spread_pair(-6.5, -110, 6.5, -110),
+            SpreadPairKind::Neither
+        );
+        // A gap that wins both bets at normal juice is a middle
+        assert_eq!(
+            classify_spread_pair(-6.5, -110, 7.5, -110),
+            SpreadPairKind::Middle
+        );
+        assert_eq!(
+            classify_spread_pair(-3.0, -110, 4.0, -110),
+            SpreadPairKind::Neither
+        );
+        // A gap that loses both bets is never safe, however good the prices
+        assert_eq!(
+            classify_spread_pair(-7.0, -110, 6.5, 150),
+            SpreadPairKind::Neither
+        );
+    }
+
+    #[test]
+    fn test_spread_arbitrage_pairs_overlapping_lines() {
+        // Home -6.5 and Away +7.5 never both lose, so good prices make it an arb with a
+        // chance of winning both on a 7-point home win
+        let game = create_test_game("Home Team", "Away Team");
+        let spread = |team: &str, point: f64, price: i32| SpreadOdds {
+            team: team.to_string(),
+            point,
+            price,
+        };
+        let games_with_odds = vec![(
+            game.clone(),
+            vec![
+                create_betting_odds(
+                    &game.id,
+                    "BookmakerA",
+                    vec![],
+                    vec![spread("Home Team", -6.5, 105)],
+                ),
+                create_betting_odds(
+                    &game.id,
+                    "BookmakerB",
+                    vec![],
+                    vec![spread("Away Team", 7.5, 100)],
+                ),
+            ],
+        )];
+
+        let arbs = find_spread_arbitrage(&games_with_odds, &ArbFilter::default()).unwrap();
+        assert_eq!(arbs.len(), 1);
+        assert!(arbs[0].profit_percentage > 0.0);
+        assert!(arbs[0].middle_prob > 0.0);
+        assert!(find_spread_middles(&games_with_odds, &ArbFilter::default())
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_spread_middles_need_positive_expected_profit() {
+        let spread = |team: &str, point: f64| SpreadOdds {
+            team: team.to_string(),
+            point,
+            price: -110,
+        };
+        let middle_game = |home: &str, away: &str, home_line: f64, away_line: f64| {
+            let game = create_test_game(home, away);
+            let odds = vec![
+                create_betting_odds(
+                    &game.id,
+                    "BookmakerA",
+                    vec![],
+                    vec![spread(home, home_line)],
+                ),
+                create_betting_odds(
+                    &game.id,
+                    "BookmakerB",
+                    vec![],
+                    vec![spread(away, away_line)],
+                ),
+            ];
+            (game, odds)
+        };
+        // -2.5/+7.5 wins both on 3 through 7, covering both key numbers; -10.5/+11.5 only
+        // on 11, which doesn't pay for the juice
+        let games_with_odds = vec![
+            middle_game("Iowa", "Nebraska", -2.5, 7.5),
+            middle_game("Ohio State", "Michigan", -10.5, 11.5),
+        ];
+
+        let middles = find_spread_middles(&games_with_odds, &ArbFilter::default()).unwrap();
+
+        assert_eq!(middles.len(), 1);
+        let middle = &middles[0];
+        assert_eq!(middle.home_team, "Iowa");
+        assert!(middle.middle_prob > 0.15);
+        assert!(middle.miss_percentage < 0.0);
+        assert!(middle.hit_percentage > 90.0);
+        assert!(middle.expected_profit_percentage > 0.0);
+        assert!(
+            find_spread_arbitrage(&games_with_odds, &ArbFilter::default())
+                .unwrap()
+                .is_empty()
+        );
+    }
+
     #[test]
     fn test_empty_games_returns_empty() {
         let games_with_odds: Vec<(Game, Vec<BettingOdds>)> = vec![];
 
-        let moneyline_result = find_moneyline_arbitrage(&games_with_odds).unwrap();
-        let spread_result = find_spread_arbitrage(&games_with_odds).unwrap();
+        let moneyline_result =
+            find_moneyline_arbitrage(&games_with_odds, &ArbFilter::default()).unwrap();
+        let spread_result = find_spread_arbitrage(&games_with_odds, &ArbFilter::default()).unwrap();
 
         assert_eq!(moneyline_result.len(), 0);
         assert_eq!(spread_result.len(), 0);
     }
 
+    #[test]
+    fn test_kalshi_moneyline_arbitrage_matches_team_names() {
+        // Sportsbook uses full names, Kalshi uses school names with home/away swapped
+        let sportsbook_game = create_test_game("Ohio State Buckeyes", "Michigan Wolverines");
+        let sportsbook_odds = create_betting_odds(
+            &sportsbook_game.id,
+            "BookmakerA",
+            vec![
+                MoneylineOdds {
+                    team: "Ohio State Buckeyes".to_string(),
+                    price: 130,
+                },
+                MoneylineOdds {
+                    team: "Michigan Wolverines".to_string(),
+                    price: -160,
+                },
+            ],
+            vec![],
+        );
+
+        let mut kalshi_game = create_test_game("Michigan", "Ohio State");
+        kalshi_game.id = "KXNCAAFGAME-OSUMICH".to_string();
+        let kalshi_odds = create_betting_odds(
+            &kalshi_game.id,
+            KALSHI_BOOKMAKER,
+            vec![
+                MoneylineOdds {
+                    team: "Michigan".to_string(),
+                    price: 110,
+                },
+                MoneylineOdds {
+                    team: "Ohio State".to_string(),
+                    price: -120,
+                },
+            ],
+            vec![],
+        );
+
+        let sportsbook_games = vec![(sportsbook_game, vec![sportsbook_odds])];
+        let kalshi_games = vec![(kalshi_game, vec![kalshi_odds])];
+
+        let result = find_kalshi_moneyline_arbitrage(
+            &sportsbook_games,
+            &kalshi_games,
+            &ArbFilter::default(),
+        )
+        .unwrap();
+
+        assert_eq!(result.len(), 1);
+        let arb = &result[0];
+        assert!(arb.involves_kalshi());
+        assert_eq!(arb.home_team, "Ohio State Buckeyes");
+        assert_eq!(arb.home_bookmaker, "BookmakerA");
+        assert_eq!(arb.away_bookmaker, KALSHI_BOOKMAKER);
+        assert_eq!(arb.away_odds, 110);
+    }
+
+    #[test]
+    fn test_kalshi_moneyline_arbitrage_ignores_sportsbook_only_arbs() {
+        let game = create_test_game("Home Team", "Away Team");
+        let book_a_odds = create_betting_odds(
+            &game.id,
+            "BookmakerA",
+            create_moneyline_odds(120, -150),
+            vec![],
+        );
+        let book_b_odds = create_betting_odds(
+            &game.id,
+            "BookmakerB",
+            create_moneyline_odds(-155, 125),
+            vec![],
+        );
+
+        let sportsbook_games = vec![(game, vec![book_a_odds, book_b_odds])];
+
+        let result =
+            find_kalshi_moneyline_arbitrage(&sportsbook_games, &[], &ArbFilter::default()).unwrap();
+
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_arb_filter_screens_thin_markets_and_off_prices() {
+        let game = create_test_game("Home Team", "Away Team");
+        let odds_list = vec![
+            create_betting_odds(
+                &game.id,
+                "BookmakerA",
+                create_moneyline_odds(-150, 130),
+                vec![],
+            ),
+            create_betting_odds(
+                &game.id,
+                "BookmakerB",
+                create_moneyline_odds(-145, 125),
+                vec![],
+            ),
+            create_betting_odds(
+                &game.id,
+                "BookmakerC",
+                create_moneyline_odds(-155, 135),
+                vec![],
+            ),
+            // Off the market by almost 20 points on the home side
+            create_betting_odds(
+                &game.id,
+                "BookmakerD",
+                create_moneyline_odds(150, 130),
+                vec![],
+            ),
+        ];
+        let games = vec![(game, odds_list)];
+        assert_eq!(
+            find_moneyline_arbitrage(&games, &ArbFilter::default())
+                .unwrap()
+                .len(),
+            1
+        );
+
+        let band = ArbFilter {
+            max_median_gap: Some(0.05),
+            ..ArbFilter::default()
+        };
+        assert!(find_moneyline_arbitrage(&band.screen(&games), &band)
+            .unwrap()
+            .is_empty());
+
+        // Too few books quoting: the whole market is skipped
+        let thin = ArbFilter {
+            min_books_in_market: 5,
+            ..ArbFilter::default()
+        };
+        assert!(thin.screen(&games)[0]
+            .1
+            .iter()
+            .all(|odds| odds.moneyline.is_empty()));
+
+        let filter = ArbFilter::default();
+        assert!(!filter.allows_books("BetOnline.ag", "betonline"));
+        assert!(filter.allows_books("DraftKings", "FanDuel"));
+        let lenient = ArbFilter {
+            require_distinct_books: false,
+            ..ArbFilter::default()
+        };
+        assert!(lenient.allows_books("DraftKings", "DraftKings"));
+    }
+
+    #[test]
+    fn test_distinct_books_pair_around_a_book_best_on_both_sides() {
+        let game = create_test_game("Home Team", "Away Team");
+        let odds_list = vec![
+            // Best price on both teams, but an arbitrage only against itself
+            create_betting_odds(
+                &game.id,
+                "BookmakerA",
+                create_moneyline_odds(130, 130),
+                vec![],
+            ),
+            create_betting_odds(
+                &game.id,
+                "BookmakerB",
+                create_moneyline_odds(120, -150),
+                vec![],
+            ),
+            create_betting_odds(
+                &game.id,
+                "BookmakerC",
+                create_moneyline_odds(-150, 115),
+                vec![],
+            ),
+        ];
+        let games = vec![(game, odds_list)];
+
+        // BookmakerB's home +120 with BookmakerA's away +130 beats BookmakerA's home +130
+        // with BookmakerC's away +115
+        let result = find_moneyline_arbitrage(&games, &ArbFilter::default()).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].home_bookmaker, "BookmakerB");
+        assert_eq!(result[0].away_bookmaker, "BookmakerA");
+
+        let lenient = ArbFilter {
+            require_distinct_books: false,
+            ..ArbFilter::default()
+        };
+        let result = find_moneyline_arbitrage(&games, &lenient).unwrap();
+        assert_eq!(result[0].home_bookmaker, "BookmakerA");
+        assert_eq!(result[0].away_bookmaker, "BookmakerA");
+
+        // Spreads pair the same way
+        let game = create_test_game("Home Team", "Away Team");
+        let spread = |team: &str, point: f64, price: i32| SpreadOdds {
+            team: team.to_string(),
+            point,
+            price,
+        };
+        let odds_list = vec![
+            create_betting_odds(
+                &game.id,
+                "BookmakerA",
+                vec![],
+                vec![
+                    spread("Home Team", -3.5, 115),
+                    spread("Away Team", 3.5, 115),
+                ],
+            ),
+            create_betting_odds(
+                &game.id,
+                "BookmakerB",
+                vec![],
+                vec![
+                    spread("Home Team", -3.5, 105),
+                    spread("Away Team", 3.5, -130),
+                ],
+            ),
+        ];
+        let result = find_spread_arbitrage(&[(game, odds_list)], &ArbFilter::default()).unwrap();
+        assert_eq!(result.len(), 1);
+        let bookmakers = [&result[0].side1_bookmaker, &result[0].side2_bookmaker];
+        assert!(bookmakers.contains(&&"BookmakerA".to_string()));
+        assert!(bookmakers.contains(&&"BookmakerB".to_string()));
+    }
+
+    #[test]
+    fn test_merge_kalshi_odds_keeps_games_a_week_apart() {
+        let this_week = create_test_game("Ohio State Buckeyes", "Michigan Wolverines");
+        let mut existing = vec![(this_week.clone(), Vec::new())];
+
+        // Kalshi lists this week's game and next week's rematch
+        let kalshi_market = |game: &Game| {
+            create_betting_odds(
+                &game.id,
+                KALSHI_BOOKMAKER,
+                vec![
+                    MoneylineOdds {
+                        team: "Ohio State".to_string(),
+                        price: -140,
+                    },
+                    MoneylineOdds {
+                        team: "Michigan".to_string(),
+                        price: 120,
+                    },
+                ],
+                vec![],
+            )
+        };
+        let mut kalshi_this_week = create_test_game("Ohio State", "Michigan");
+        kalshi_this_week.commence_time = this_week.commence_time + Duration::hours(3);
+        let mut kalshi_rematch = create_test_game("Ohio State", "Michigan");
+        kalshi_rematch.commence_time = this_week.commence_time + Duration::days(7);
+        let kalshi_games = vec![
+            (
+                kalshi_this_week.clone(),
+                vec![kalshi_market(&kalshi_this_week)],
+            ),
+            (kalshi_rematch.clone(), vec![kalshi_market(&kalshi_rematch)]),
+        ];
+
+        merge_kalshi_odds(&mut existing, kalshi_games);
+
+        assert_eq!(existing.len(), 2);
+        assert_eq!(existing[0].1.len(), 1);
+        assert_eq!(existing[0].1[0].moneyline[0].team, "Ohio State Buckeyes");
+        assert_eq!(existing[1].0.commence_time, kalshi_rematch.commence_time);
+        assert_eq!(existing[1].1[0].moneyline[0].team, "Ohio State");
+    }
+
+    #[test]
+    fn test_dedupe_games_merges_kalshi_and_odds_api_listings() {
+        let odds_api_game = create_test_game("Ohio State Buckeyes", "Michigan Wolverines");
+        let draftkings_moneyline = create_betting_odds(
+            &odds_api_game.id,
+            "DraftKings",
+            vec![
+                MoneylineOdds {
+                    team: "Ohio State Buckeyes".to_string(),
+                    price: 130,
+                },
+                MoneylineOdds {
+                    team: "Michigan Wolverines".to_string(),
+                    price: -160,
+                },
+            ],
+            vec![],
+        );
+
+        // The same game again from Kalshi, with school names and home/away swapped
+        let mut kalshi_game = create_test_game("Michigan", "Ohio State");
+        kalshi_game.id = "KXNCAAFGAME-OSUMICH".to_string();
+        let kalshi_odds = create_betting_odds(
+            &kalshi_game.id,
+            KALSHI_BOOKMAKER,
+            vec![
+                MoneylineOdds {
+                    team: "Michigan".to_string(),
+                    price: -140,
+                },
+                MoneylineOdds {
+                    team: "Ohio State".to_string(),
+                    price: 105,
+                },
+            ],
+            vec![],
+        );
+
+        // A second Odds API listing: DraftKings' moneyline again at an older price, plus spreads
+        let mut duplicate_game = create_test_game("Ohio State Buckeyes", "Michigan Wolverines");
+        duplicate_game.id = "test_game_2".to_string();
+        let draftkings_duplicate = create_betting_odds(
+            &duplicate_game.id,
+            "DraftKings",
+            vec![
+                MoneylineOdds {
+                    team: "Ohio State Buckeyes".to_string(),
+                    price: -150,
+                },
+                MoneylineOdds {
+                    team: "Michigan Wolverines".to_string(),
+                    price: 140,
+                },
+            ],
+            vec![
+                SpreadOdds {
+                    team: "Ohio State Buckeyes".to_string(),
+                    point: -3.5,
+                    price: -110,
+                },
+                SpreadOdds {
+                    team: "Michigan Wolverines".to_string(),
+                    point: 3.5,
+                    price: -110,
+                },
+            ],
+        );
+
+        let games = dedupe_games(vec![
+            (odds_api_game, vec![draftkings_moneyline]),
+            (kalshi_game, vec![kalshi_odds]),
+            (duplicate_game, vec![draftkings_duplicate]),
+        ]);
+
+        assert_eq!(games.len(), 1);
+        let (game, odds_list) = &games[0];
+        assert_eq!(game.id, "test_game_1");
+        let books: Vec<&str> = odds_list
+            .iter()
+            .map(|odds| odds.bookmaker.as_str())
+            .collect();
+        assert_eq!(books, vec!["DraftKings", KALSHI_BOOKMAKER]);
+        assert!(odds_list.iter().all(|odds| odds.game_id == "test_game_1"));
+
+        // DraftKings keeps its first moneyline and picks up the spreads it was missing
+        let draftkings = &odds_list[0];
+        assert_eq!(draftkings.moneyline[0].price, 130);
+        assert_eq!(draftkings.spreads.len(), 2);
+        assert_eq!(odds_list[1].moneyline[0].team, "Michigan Wolverines");
+
+        // Both DraftKings prices together would look like an arb; deduped there is none
+        assert!(find_moneyline_arbitrage(&games, &ArbFilter::default())
+            .unwrap()
+            .is_empty());
+    }
+
     #[test]
     fn test_arbitrage_profit_calculation() {
         // Test specific profit percentage calculation
@@ -520,26 +1978,20 @@ mod tests {
         let book_a_odds = create_betting_odds(
             &game.id,
             "BookmakerA",
-            vec![MoneylineOdds {
-                team: "Home Team".to_string(),
-                price: 100,
-            }],
+            create_moneyline_odds(100, -130),
             vec![],
         );
 
         let book_b_odds = create_betting_odds(
             &game.id,
             "BookmakerB",
-            vec![MoneylineOdds {
-                team: "Away Team".to_string(),
-                price: 110,
-            }],
+            create_moneyline_odds(-140, 110),
             vec![],
         );
 
         let games_with_odds = vec![(game.clone(), vec![book_a_odds, book_b_odds])];
 
-        let result = find_moneyline_arbitrage(&games_with_odds).unwrap();
+        let result = find_moneyline_arbitrage(&games_with_odds, &ArbFilter::default()).unwrap();
 
         assert_eq!(result.len(), 1);
         let arb = &result[0];
@@ -548,4 +2000,98 @@ mod tests {
         assert!(arb.profit_percentage > 2.0);
         assert!(arb.profit_percentage < 3.0);
     }
+
+    fn create_moneyline_arb(home_odds: i32, away_odds: i32) -> MoneylineArbitrage {
+        let home_prob = american_odds_to_probability(home_odds);
+        let away_prob = american_odds_to_probability(away_odds);
+        let total_prob = home_prob + away_prob;
+        MoneylineArbitrage {
+            home_team: "Home Team".to_string(),
+            away_team: "Away Team".to_string(),
+            home_bookmaker: "BookmakerA".to_string(),
+            away_bookmaker: "BookmakerB".to_string(),
+            home_odds,
+            away_odds,
+            profit_percentage: (1.0 / total_prob - 1.0) * 100.0,
+            home_stake_percentage: home_prob / total_prob * 100.0,
+            away_stake_percentage: away_prob / total_prob * 100.0,
+            commence_time: None,
+            game_id: None,
+            oldest_update: None,
+            is_live: false,
+        }
+    }
+
+    #[test]
+    fn test_arbitrage_age_uses_oldest_leg() {
+        let game = create_test_game("Home Team", "Away Team");
+        let mut book_a_odds = create_betting_odds(
+            &game.id,
+            "BookmakerA",
+            create_moneyline_odds(120, -150),
+            vec![],
+        );
+        book_a_odds.last_update = Utc::now() - Duration::minutes(8);
+        let book_b_odds = create_betting_odds(
+            &game.id,
+            "BookmakerB",
+            create_moneyline_odds(-155, 125),
+            vec![],
+        );
+
+        let result = find_moneyline_arbitrage(
+            &[(game, vec![book_a_odds, book_b_odds])],
+            &ArbFilter::default(),
+        )
+        .unwrap();
+
+        let arb = &result[0];
+        assert_eq!(arb.age_minutes(), Some(8));
+        assert!(arb.is_likely_gone());
+        assert!(arb.format().ends_with("| Age: 8m (likely gone)"));
+
+        let fresh = MoneylineArbitrage {
+            oldest_update: Some(Utc::now()),
+            ..arb.clone()
+        };
+        assert!(!fresh.is_likely_gone());
+        assert!(fresh.format().ends_with("| Age: 0m"));
+        assert_eq!(format_age(None, false), "");
+
+        // In-play lines go stale much sooner
+        let live = MoneylineArbitrage {
+            oldest_update: Some(Utc::now() - Duration::minutes(2)),
+            is_live: true,
+            ..arb.clone()
+        };
+        assert!(live.is_likely_gone());
+    }
+
+    #[test]
+    fn test_round_to_increment_keeps_stakes_balanced() {
+        // +100 / +110: ideal stakes on $100 are $51.22 / $48.78
+        let arb = create_moneyline_arb(100, 110);
+        let rounded = round_to_increment(&arb, 100.0, 1.0);
+
+        assert_eq!(rounded.stake_1.fract(), 0.0);
+        assert_eq!(rounded.stake_2.fract(), 0.0);
+        assert!((rounded.total_staked - 100.0).abs() <= 1.0);
+        assert!(rounded.is_profitable());
+        assert!(rounded.worst_case_profit_percentage() <= arb.profit_percentage);
+
+        let half_dollars = round_to_increment(&arb, 100.0, 0.5);
+        assert_eq!((half_dollars.stake_1 * 2.0).fract(), 0.0);
+        assert!(half_dollars.worst_case_profit >= rounded.worst_case_profit - 0.5);
+    }
+
+    #[test]
+    fn test_round_to_increment_reports_losing_thin_arb() {
+        // A ~0.2% arb on a $10 bankroll can't survive whole-dollar stakes
+        let arb = create_moneyline_arb(-110, 111);
+        assert!(arb.profit_percentage > 0.0 && arb.profit_percentage < 0.5);
+
+        let rounded = round_to_increment(&arb, 10.0, 1.0);
+        assert!(!rounded.is_profitable());
+        assert!(rounded.format().contains("NOT AN ARB"));
+    }
 }
@@ -0,0 +1,444 @@
+use crate::api::game_results_api::GameResult;
+use crate::models::{BettingOdds, Game};
+use crate::scrapers::prediction_tracker::GamePrediction;
+use crate::utils::ev_analysis::{
+    calibration_report, compare_ev_bets_to_results, moneyline_bets_vs_predictions, BetResult,
+    BoostConfig, CalibrationBucket, EvBetRecommendation, DEFAULT_UNIT_SIZE,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Edge bucket boundaries used in the backtest report
+const EDGE_BUCKETS: &[(f64, f64)] = &[(0.0, 0.02), (0.02, 0.05), (0.05, 0.10), (0.10, 1.0)];
+
+/// A game with every bookmaker's odds on it
+type GameOdds = (Game, Vec<BettingOdds>);
+
+/// Odds for a set of games as they stood at one point in time
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OddsSnapshot {
+    pub taken_at: DateTime<Utc>,
+    pub games: Vec<GameOdds>,
+}
+
+impl OddsSnapshot {
+    /// Build a snapshot from cached odds, timestamped by the most recent bookmaker update
+    pub fn from_games(games: Vec<GameOdds>) -> Self {
+        let taken_at = games
+            .iter()
+            .flat_map(|(_, odds_list)| odds_list.iter().map(|odds| odds.last_update))
+            .max()
+            .unwrap_or(DateTime::UNIX_EPOCH);
+
+        Self { taken_at, games }
+    }
+}
+
+/// How the backtest picks and sizes bets
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct StakingConfig {
+    /// Flat stake placed on every bet
+    pub unit_size: f64,
+    /// Minimum expected value (per $1 staked) for a bet to be placed
+    pub min_ev: f64,
+}
+
+impl Default for StakingConfig {
+    fn default() -> Self {
+        Self {
+            unit_size: DEFAULT_UNIT_SIZE,
+            min_ev: 0.0,
+        }
+    }
+}
+
+/// Graded bets whose edge fell in `[min_edge, max_edge)`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EdgeBucket {
+    pub min_edge: f64,
+    pub max_edge: f64,
+    pub bets: usize,
+    pub wins: usize,
+    pub profit: f64,
+}
+
+impl EdgeBucket {
+    pub fn win_rate(&self) -> Option<f64> {
+        (self.bets > 0).then(|| self.wins as f64 / self.bets as f64)
+    }
+}
+
+/// Summary of a backtest run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BacktestReport {
+    /// Every bet placed, graded in kickoff order
+    pub results: Vec<BetResult>,
+    pub bets_placed: usize,
+    /// Bets with a final score
+    pub bets_graded: usize,
+    pub wins: usize,
+    pub total_staked: f64,
+    pub net_profit: f64,
+    /// Net profit per dollar staked on graded bets
+    pub roi: f64,
+    /// Largest peak-to-trough drop in cumulative profit, in dollars
+    pub max_drawdown: f64,
+    pub edge_buckets: Vec<EdgeBucket>,
+    pub calibration: Vec<CalibrationBucket>,
+}
+
+impl BacktestReport {
+    pub fn win_rate(&self) -> Option<f64> {
+        (self.bets_graded > 0).then(|| self.wins as f64 / self.bets_graded as f64)
+    }
+
+    /// Format the report as a readable multi-line summary
+    pub fn format(&self) -> String {
+        let mut lines = vec![
+            format!(
+                "Bets: {} placed, {} graded, {} won ({})",
+                self.bets_placed,
+                self.bets_graded,
+                self.wins,
+                format_rate(self.win_rate())
+            ),
+            format!(
+                "Staked: ${:.2} | Profit: ${:+.2} | ROI: {:+.2}% | Max drawdown: ${:.2}",
+                self.total_staked,
+                self.net_profit,
+                self.roi * 100.0,
+                self.max_drawdown
+            ),
+            "Win rate by edge:".to_string(),
+        ];
+
+        for bucket in &self.edge_buckets {
+            lines.push(format!(
+                "  {:>4.1}%-{:<5} {:>4} bets | win rate {} | profit ${:+.2}",
+                bucket.min_edge * 100.0,
+                if bucket.max_edge >= 1.0 {
+                    "+".to_string()
+                } else {
+                    format!("{:.1}%", bucket.max_edge * 100.0)
+                },
+                bucket.bets,
+                format_rate(bucket.win_rate()),
+                bucket.profit
+            ));
+        }
+
+        lines.push("Calibration (model prob vs actual):".to_string());
+        for bucket in &self.calibration {
+            lines.push(format!(
+                "  {:>3.0}-{:<3.0}% {:>4} bets | model {:.1}% | actual {:.1}%",
+                bucket.min_prob * 100.0,
+                bucket.max_prob * 100.0,
+                bucket.bets,
+                bucket.avg_model_prob * 100.0,
+                bucket.actual_win_rate * 100.0
+            ));
+        }
+
+        lines.join("\n")
+    }
+}
+
+fn format_rate(rate: Option<f64>) -> String {
+    rate.map_or("n/a".to_string(), |rate| format!("{:.1}%", rate * 100.0))
+}
+
+/// Closing line for each game: its odds in the latest snapshot taken strictly before kickoff
+/// Games that only appear in snapshots taken after kickoff are left out.
+pub fn closing_lines(historical_odds: &[OddsSnapshot]) -> Vec<GameOdds> {
+    let mut latest: HashMap<&str, (DateTime<Utc>, &GameOdds)> = HashMap::new();
+
+    for snapshot in historical_odds {
+        for entry in &snapshot.games {
+            if snapshot.taken_at >= entry.0.commence_time {
+                continue;
+            }
+            let current = latest
+                .entry(entry.0.id.as_str())
+                .or_insert((snapshot.taken_at, entry));
+            if snapshot.taken_at > current.0 {
+                *current = (snapshot.taken_at, entry);
+            }
+        }
+    }
+
+    let mut games: Vec<_> = latest
+        .into_values()
+        .map(|(_, entry)| entry.clone())
+        .collect();
+    games.sort_by_key(|(game, _)| game.commence_time);
+    games
+}
+
+/// Replay the EV model over historical odds and grade the bets it would have made
+/// Each game is priced from the last snapshot taken before kickoff. One moneyline bet is
+/// placed per team at the best available price when its EV meets `staking.min_ev`, and
+/// bets are graded against `results` in kickoff order.
+pub fn backtest(
+    historical_odds: &[OddsSnapshot],
+    predictions: &[GamePrediction],
+    results: &[GameResult],
+    staking: StakingConfig,
+) -> BacktestReport {
+    let games = closing_lines(historical_odds);
+
+    let mut bets = Vec::new();
+    for entry in &games {
+        let mut game_bets: Vec<_> = moneyline_bets_vs_predictions(
+            std::iter::once(entry),
+            predictions,
+            &BoostConfig::default(),
+        )
+        .into_iter()
+        .filter(|bet| {
+            bet.is_best_price && bet.expected_value > 0.0 && bet.expected_value >= staking.min_ev
+        })
+        .collect();
+        // Several books can share the best price; bet each team once
+        game_bets.sort_by(|a, b| a.team.cmp(&b.team));
+        game_bets.dedup_by(|a, b| a.team == b.team);
+        bets.extend(game_bets.into_iter().map(|bet| EvBetRecommendation {
+            stake: Some(staking.unit_size),
+            ..bet
+        }));
+    }
+
+    let graded = compare_ev_bets_to_results(&bets, results);
+    summarize(graded)
+}
+
+fn summarize(results: Vec<BetResult>) -> BacktestReport {
+    let mut edge_buckets: Vec<EdgeBucket> = EDGE_BUCKETS
+        .iter()
+        .map(|&(min_edge, max_edge)| EdgeBucket {
+            min_edge,
+            max_edge,
+            bets: 0,
+            wins: 0,
+            profit: 0.0,
+        })
+        .collect();
+
+    let mut bets_graded = 0;
+    let mut wins = 0;
+    let mut net_profit: f64 = 0.0;
+    let mut peak: f64 = 0.0;
+    let mut max_drawdown: f64 = 0.0;
+    let mut total_staked = 0.0;
+
+    for result in &results {
+        let (Some(won), Some(profit)) = (result.bet_won, result.profit()) else {
+            continue;
+        };
+
+        bets_graded += 1;
+        total_staked += result.stake;
+        wins += usize::from(won);
+        net_profit += profit;
+        peak = peak.max(net_profit);
+        max_drawdown = max_drawdown.max(peak - net_profit);
+
+        if let Some(bucket) = edge_buckets
+            .iter_mut()
+            .find(|b| result.bet.edge >= b.min_edge && result.bet.edge < b.max_edge)
+        {
+            bucket.bets += 1;
+            bucket.wins += usize::from(won);
+            bucket.profit += profit;
+        }
+    }
+
+    BacktestReport {
+        bets_placed: results.len(),
+        bets_graded,
+        wins,
+        total_staked,
+        net_profit,
+        roi: if total_staked > 0.0 {
+            net_profit / total_staked
+        } else {
+            0.0
+        },
+        max_drawdown,
+        edge_buckets,
+        calibration: calibration_report(&results),
+        results,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::MoneylineOdds;
+    use chrono::Duration;
+
+    fn create_game(id: &str, home: &str, away: &str, kickoff: DateTime<Utc>) -> Game {
+        Game {
+            id: id.to_string(),
+            home_team: home.to_string(),
+            away_team: away.to_string(),
+            commence_time: kickoff,
+            sport_title: "NCAAF".to_string(),
+            is_live: false,
+        }
+    }
+
+    fn create_odds(game: &Game, bookmaker: &str, home_price: i32, away_price: i32) -> BettingOdds {
+        BettingOdds {
+            game_id: game.id.clone(),
+            bookmaker: bookmaker.to_string(),
+            last_update: game.commence_time,
+            moneyline: vec![
+                MoneylineOdds {
+                    team: game.home_team.clone(),
+                    price: home_price,
+                },
+                MoneylineOdds {
+                    team: game.away_team.clone(),
+                    price: away_price,
+                },
+            ],
+            spreads: vec![],
+        }
+    }
+
+    fn create_prediction(home: &str, away: &str, home_win_prob: f64) -> GamePrediction {
+        GamePrediction {
+            home_team: home.to_string(),
+            away_team: away.to_string(),
+            spread: 0.0,
+            opening_spread: None,
+            home_win_prob,
+            away_win_prob: 1.0 - home_win_prob,
+            _prediction_avg: 0.0,
+            spread_derived: false,
+            home_cover_prob: None,
+            source: String::new(),
+        }
+    }
+
+    fn create_result(
+        id: i32,
+        home: &str,
+        away: &str,
+        kickoff: DateTime<Utc>,
+        home_points: i32,
+        away_points: i32,
+    ) -> GameResult {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "season": 2025,
+            "week": 1,
+            "seasonType": "regular",
+            "startDate": kickoff.to_rfc3339(),
+            "startTimeTBD": false,
+            "completed": true,
+            "neutralSite": false,
+            "conferenceGame": false,
+            "homeId": id * 2,
+            "homeTeam": home,
+            "homePoints": home_points,
+            "awayId": id * 2 + 1,
+            "awayTeam": away,
+            "awayPoints": away_points,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_backtest_uses_last_pre_kickoff_snapshot() {
+        let kickoff = Utc::now() - Duration::days(7);
+        let game = create_game("g1", "Iowa Hawkeyes", "Nebraska Cornhuskers", kickoff);
+
+        // Early line has value on Iowa, the post-kickoff line must be ignored
+        let snapshots = vec![
+            OddsSnapshot {
+                taken_at: kickoff - Duration::days(2),
+                games: vec![(game.clone(), vec![create_odds(&game, "FanDuel", 150, -170)])],
+            },
+            OddsSnapshot {
+                taken_at: kickoff - Duration::hours(1),
+                games: vec![(game.clone(), vec![create_odds(&game, "FanDuel", 120, -140)])],
+            },
+            OddsSnapshot {
+                taken_at: kickoff + Duration::hours(1),
+                games: vec![(game.clone(), vec![create_odds(&game, "FanDuel", 400, -500)])],
+            },
+        ];
+        let predictions = vec![create_prediction("Iowa", "Nebraska", 0.5)];
+        let results = vec![create_result(1, "Iowa", "Nebraska", kickoff, 24, 17)];
+
+        let report = backtest(&snapshots, &predictions, &results, StakingConfig::default());
+
+        assert_eq!(report.bets_placed, 1);
+        assert_eq!(report.results[0].bet.odds, 120);
+        assert_eq!(report.wins, 1);
+        assert!((report.net_profit - 120.0).abs() < 1e-9);
+        assert!((report.roi - 1.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_backtest_report_drawdown_and_buckets() {
+        let start = Utc::now() - Duration::days(30);
+        let matchups = [
+            ("g1", "Iowa Hawkeyes", "Nebraska Cornhuskers", 10, 20),
+            ("g2", "Purdue Boilermakers", "Michigan Wolverines", 14, 21),
+            ("g3", "Texas Longhorns", "Oklahoma Sooners", 35, 3),
+        ];
+
+        let mut games = Vec::new();
+        let mut predictions = Vec::new();
+        let mut results = Vec::new();
+        for (i, (id, home, away, home_points, away_points)) in matchups.iter().enumerate() {
+            let kickoff = start + Duration::days(i as i64);
+            let game = create_game(id, home, away, kickoff);
+            let odds = create_odds(&game, "FanDuel", 100, -120);
+            games.push((game, vec![odds]));
+
+            let school = |name: &str| name.split(' ').next().unwrap().to_string();
+            predictions.push(create_prediction(&school(home), &school(away), 0.53));
+            results.push(create_result(
+                i as i32,
+                &school(home),
+                &school(away),
+                kickoff,
+                *home_points,
+                *away_points,
+            ));
+        }
+        let snapshots = vec![OddsSnapshot {
+            taken_at: start - Duration::days(1),
+            games,
+        }];
+
+        let report = backtest(
+            &snapshots,
+            &predictions,
+            &results,
+            StakingConfig {
+                unit_size: 10.0,
+                min_ev: 0.0,
+            },
+        );
+
+        // Two losses then a win at even money
+        assert_eq!(report.bets_graded, 3);
+        assert_eq!(report.wins, 1);
+        assert!((report.net_profit - -10.0).abs() < 1e-9);
+        assert!((report.max_drawdown - 20.0).abs() < 1e-9);
+
+        // 3% edge on every bet
+        let bucket = &report.edge_buckets[1];
+        assert_eq!(bucket.bets, 3);
+        assert!((bucket.win_rate().unwrap() - 1.0 / 3.0).abs() < 1e-9);
+
+        assert_eq!(report.calibration.len(), 1);
+        assert_eq!(report.calibration[0].bets, 3);
+        assert!((report.calibration[0].avg_model_prob - 0.53).abs() < 1e-9);
+    }
+}
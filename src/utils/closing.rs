@@ -0,0 +1,155 @@
+use crate::api::odds_api::{OddsApiClient, GAME_MARKETS};
+use crate::error::Result;
+use crate::models::{Game, Sport};
+use crate::utils::backtest::OddsSnapshot;
+use crate::utils::data::append_snapshot;
+use chrono::{DateTime, Duration, Utc};
+use tracing::{info, warn};
+
+/// Minutes before kickoff closing odds are captured when `--lead-minutes` isn't given
+pub const DEFAULT_CLOSING_LEAD_MINUTES: i64 = 5;
+
+/// Games whose closing odds are captured together, at `capture_at`
+#[derive(Debug, Clone, PartialEq)]
+pub struct CaptureBatch {
+    pub capture_at: DateTime<Utc>,
+    pub event_ids: Vec<String>,
+}
+
+impl CaptureBatch {
+    /// Odds API credits this batch costs: a lone game is fetched by its event id, several
+    /// sharing a kickoff with one call to the odds endpoint, which bills the same
+    pub fn estimated_cost(&self) -> u32 {
+        OddsApiClient::estimate_configured_cost(GAME_MARKETS)
+    }
+}
+
+/// When to capture each game's closing odds: `lead` before kickoff, grouped by kickoff
+/// Games that have started are skipped, and games already inside the lead window are
+/// captured right away. Batches come back in capture order.
+pub fn capture_batches(games: &[Game], lead: Duration, now: DateTime<Utc>) -> Vec<CaptureBatch> {
+    let mut upcoming: Vec<&Game> = games
+        .iter()
+        .filter(|game| game.commence_time > now)
+        .collect();
+    upcoming.sort_by_key(|game| game.commence_time);
+
+    let mut batches: Vec<CaptureBatch> = Vec::new();
+    for game in upcoming {
+        let capture_at = (game.commence_time - lead).max(now);
+        match batches.last_mut() {
+            Some(batch) if batch.capture_at == capture_at => batch.event_ids.push(game.id.clone()),
+            _ => batches.push(CaptureBatch {
+                capture_at,
+                event_ids: vec![game.id.clone()],
+            }),
+        }
+    }
+    batches
+}
+
+/// Capture each game's odds `lead` before kickoff and append them to `history_file` as
+/// snapshots, waiting between batches until the last game has been captured
+/// A failed capture is logged and the rest still run. Returns the number of games
+/// captured.
+pub async fn capture_closing_lines(
+    client: &OddsApiClient,
+    sport: Sport,
+    games: &[Game],
+    lead: Duration,
+    history_file: &str,
+) -> Result<usize> {
+    let mut captured = 0;
+
+    for batch in capture_batches(games, lead, Utc::now()) {
+        let wait = (batch.capture_at - Utc::now()).to_std().unwrap_or_default();
+        info!(
+            games = batch.event_ids.len(),
+            capture_at = %batch.capture_at,
+            "Waiting to capture closing odds"
+        );
+        tokio::time::sleep(wait).await;
+
+        let fetched = if let [event_id] = batch.event_ids.as_slice() {
+            client
+                .fetch_game_odds(sport.clone(), event_id, GAME_MARKETS)
+                .await
+                .map(|game| vec![game])
+        } else {
+            client.fetch_games(sport.clone()).await.map(|mut games| {
+                games.retain(|(game, _)| batch.event_ids.contains(&game.id));
+                games
+            })
+        };
+        let games = match fetched {
+            Ok(games) => games,
+            Err(e) => {
+                warn!(error = %e, games = batch.event_ids.len(), "Failed to capture closing odds");
+                continue;
+            }
+        };
+
+        captured += games.len();
+        let snapshot = OddsSnapshot {
+            taken_at: Utc::now(),
+            games,
+        };
+        append_snapshot(&snapshot, history_file)?;
+        info!(
+            games = snapshot.games.len(),
+            file = history_file,
+            "Captured closing odds"
+        );
+    }
+
+    Ok(captured)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_game(id: &str, commence_time: DateTime<Utc>) -> Game {
+        Game {
+            id: id.to_string(),
+            home_team: "Home Team".to_string(),
+            away_team: "Away Team".to_string(),
+            commence_time,
+            sport_title: "NCAAF".to_string(),
+            is_live: false,
+        }
+    }
+
+    #[test]
+    fn test_capture_batches_group_by_kickoff() {
+        let now = "2025-11-01T15:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let games = [
+            create_game("late", now + Duration::hours(4)),
+            create_game("noon_a", now + Duration::hours(1)),
+            create_game("started", now - Duration::minutes(10)),
+            create_game("noon_b", now + Duration::hours(1)),
+            // Kicks off in 3 minutes, inside the 5-minute lead
+            create_game("soon", now + Duration::minutes(3)),
+        ];
+
+        let batches = capture_batches(&games, Duration::minutes(5), now);
+
+        assert_eq!(
+            batches,
+            vec![
+                CaptureBatch {
+                    capture_at: now,
+                    event_ids: vec!["soon".to_string()],
+                },
+                CaptureBatch {
+                    capture_at: now + Duration::minutes(55),
+                    event_ids: vec!["noon_a".to_string(), "noon_b".to_string()],
+                },
+                CaptureBatch {
+                    capture_at: now + Duration::minutes(235),
+                    event_ids: vec!["late".to_string()],
+                },
+            ]
+        );
+    }
+}
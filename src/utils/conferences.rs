@@ -0,0 +1,303 @@
+/// Conference name used for teams that aren't in the conference table
+pub const OTHER_CONFERENCE: &str = "Other";
+
+/// Conference name for lower-division teams listed in the table only so that their names
+/// don't prefix-match an FBS school (e.g. "Florida A&M" vs "Florida")
+pub const FCS_CONFERENCE: &str = "FCS";
+
+/// FBS conference membership for the 2025 season, keyed by school name
+/// Alternate spellings used by the Odds API and prediction sites are listed as extra entries.
+const TEAM_CONFERENCES: &[(&str, &str)] = &[
+    // SEC
+    ("Alabama", "SEC"),
+    ("Arkansas", "SEC"),
+    ("Auburn", "SEC"),
+    ("Florida", "SEC"),
+    ("Georgia", "SEC"),
+    ("Kentucky", "SEC"),
+    ("LSU", "SEC"),
+    ("Louisiana State", "SEC"),
+    ("Mississippi State", "SEC"),
+    ("Missouri", "SEC"),
+    ("Oklahoma", "SEC"),
+    ("Ole Miss", "SEC"),
+    ("Mississippi", "SEC"),
+    ("South Carolina", "SEC"),
+    ("Tennessee", "SEC"),
+    ("Texas", "SEC"),
+    ("Texas A&M", "SEC"),
+    ("Vanderbilt", "SEC"),
+    // Big Ten
+    ("Illinois", "Big Ten"),
+    ("Indiana", "Big Ten"),
+    ("Iowa", "Big Ten"),
+    ("Maryland", "Big Ten"),
+    ("Michigan", "Big Ten"),
+    ("Michigan State", "Big Ten"),
+    ("Minnesota", "Big Ten"),
+    ("Nebraska", "Big Ten"),
+    ("Northwestern", "Big Ten"),
+    ("Ohio State", "Big Ten"),
+    ("Oregon", "Big Ten"),
+    ("Penn State", "Big Ten"),
+    ("Purdue", "Big Ten"),
+    ("Rutgers", "Big Ten"),
+    ("UCLA", "Big Ten"),
+    ("USC", "Big Ten"),
+    ("Southern California", "Big Ten"),
+    ("Washington", "Big Ten"),
+    ("Wisconsin", "Big Ten"),
+    // Big 12
+    ("Arizona", "Big 12"),
+    ("Arizona State", "Big 12"),
+    ("Baylor", "Big 12"),
+    ("BYU", "Big 12"),
+    ("Brigham Young", "Big 12"),
+    ("Cincinnati", "Big 12"),
+    ("Colorado", "Big 12"),
+    ("Houston", "Big 12"),
+    ("Iowa State", "Big 12"),
+    ("Kansas", "Big 12"),
+    ("Kansas State", "Big 12"),
+    ("Oklahoma State", "Big 12"),
+    ("TCU", "Big 12"),
+    ("Texas Christian", "Big 12"),
+    ("Texas Tech", "Big 12"),
+    ("UCF", "Big 12"),
+    ("Central Florida", "Big 12"),
+    ("Utah", "Big 12"),
+    ("West Virginia", "Big 12"),
+    // ACC
+    ("Boston College", "ACC"),
+    ("California", "ACC"),
+    ("Clemson", "ACC"),
+    ("Duke", "ACC"),
+    ("Florida State", "ACC"),
+    ("Georgia Tech", "ACC"),
+    ("Louisville", "ACC"),
+    ("Miami", "ACC"),
+    ("Miami (FL)", "ACC"),
+    ("NC State", "ACC"),
+    ("North Carolina State", "ACC"),
+    ("North Carolina", "ACC"),
+    ("Pittsburgh", "ACC"),
+    ("Pitt", "ACC"),
+    ("SMU", "ACC"),
+    ("Southern Methodist", "ACC"),
+    ("Stanford", "ACC"),
+    ("Syracuse", "ACC"),
+    ("Virginia", "ACC"),
+    ("Virginia Tech", "ACC"),
+    ("Wake Forest", "ACC"),
+    // Pac-12
+    ("Oregon State", "Pac-12"),
+    ("Washington State", "Pac-12"),
+    // American
+    ("Army", "American"),
+    ("Charlotte", "American"),
+    ("East Carolina", "American"),
+    ("Florida Atlantic", "American"),
+    ("Memphis", "American"),
+    ("Navy", "American"),
+    ("North Texas", "American"),
+    ("Rice", "American"),
+    ("South Florida", "American"),
+    ("Temple", "American"),
+    ("Tulane", "American"),
+    ("Tulsa", "American"),
+    ("UAB", "American"),
+    ("UTSA", "American"),
+    ("Texas-San Antonio", "American"),
+    // Mountain West
+    ("Air Force", "Mountain West"),
+    ("Boise State", "Mountain West"),
+    ("Colorado State", "Mountain West"),
+    ("Fresno State", "Mountain West"),
+    ("Hawaii", "Mountain West"),
+    ("Hawai'i", "Mountain West"),
+    ("Nevada", "Mountain West"),
+    ("New Mexico", "Mountain West"),
+    ("San Diego State", "Mountain West"),
+    ("San Jose State", "Mountain West"),
+    ("San José State", "Mountain West"),
+    ("UNLV", "Mountain West"),
+    ("Utah State", "Mountain West"),
+    ("Wyoming", "Mountain West"),
+    // Sun Belt
+    ("Appalachian State", "Sun Belt"),
+    ("App State", "Sun Belt"),
+    ("Arkansas State", "Sun Belt"),
+    ("Coastal Carolina", "Sun Belt"),
+    ("Georgia Southern", "Sun Belt"),
+    ("Georgia State", "Sun Belt"),
+    ("James Madison", "Sun Belt"),
+    ("Louisiana", "Sun Belt"),
+    ("UL Lafayette", "Sun Belt"),
+    ("UL Monroe", "Sun Belt"),
+    ("Louisiana-Monroe", "Sun Belt"),
+    ("Louisiana Monroe", "Sun Belt"),
+    ("Marshall", "Sun Belt"),
+    ("Old Dominion", "Sun Belt"),
+    ("South Alabama", "Sun Belt"),
+    ("Southern Miss", "Sun Belt"),
+    ("Southern Mississippi", "Sun Belt"),
+    ("Texas State", "Sun Belt"),
+    ("Troy", "Sun Belt"),
+    // MAC
+    ("Akron", "MAC"),
+    ("Ball State", "MAC"),
+    ("Bowling Green", "MAC"),
+    ("Buffalo", "MAC"),
+    ("Central Michigan", "MAC"),
+    ("Eastern Michigan", "MAC"),
+    ("Kent State", "MAC"),
+    ("Kent", "MAC"),
+    ("Massachusetts", "MAC"),
+    ("UMass", "MAC"),
+    ("Miami (OH)", "MAC"),
+    ("Miami OH", "MAC"),
+    ("Miami Ohio", "MAC"),
+    ("Miami-Ohio", "MAC"),
+    ("Northern Illinois", "MAC"),
+    ("Ohio", "MAC"),
+    ("Toledo", "MAC"),
+    ("Western Michigan", "MAC"),
+    // Conference USA
+    ("Delaware", "Conference USA"),
+    ("FIU", "Conference USA"),
+    ("Florida International", "Conference USA"),
+    ("Florida Intl", "Conference USA"),
+    ("Jacksonville State", "Conference USA"),
+    ("Kennesaw State", "Conference USA"),
+    ("Liberty", "Conference USA"),
+    ("Louisiana Tech", "Conference USA"),
+    ("Middle Tennessee", "Conference USA"),
+    ("Missouri State", "Conference USA"),
+    ("New Mexico State", "Conference USA"),
+    ("Sam Houston", "Conference USA"),
+    ("UTEP", "Conference USA"),
+    ("Western Kentucky", "Conference USA"),
+    // FBS Independents
+    ("Notre Dame", "FBS Independents"),
+    ("UConn", "FBS Independents"),
+    ("Connecticut", "FBS Independents"),
+    // Lower-division teams whose names start with an FBS school name
+    ("Alabama A&M", FCS_CONFERENCE),
+    ("Alabama State", FCS_CONFERENCE),
+    ("Delaware State", FCS_CONFERENCE),
+    ("Florida A&M", FCS_CONFERENCE),
+    ("Houston Christian", FCS_CONFERENCE),
+    ("Houston Baptist", FCS_CONFERENCE),
+    ("Illinois State", FCS_CONFERENCE),
+    ("Indiana State", FCS_CONFERENCE),
+    ("Mississippi Valley State", FCS_CONFERENCE),
+    ("North Carolina A&T", FCS_CONFERENCE),
+    ("North Carolina Central", FCS_CONFERENCE),
+    ("South Carolina State", FCS_CONFERENCE),
+    ("Tennessee State", FCS_CONFERENCE),
+    ("Tennessee Tech", FCS_CONFERENCE),
+    ("Texas A&M-Commerce", FCS_CONFERENCE),
+    ("Texas Southern", FCS_CONFERENCE),
+    ("Utah Tech", FCS_CONFERENCE),
+];
+
+/// Look up a team's conference from its school or full name
+/// Full names like "Georgia Southern Eagles" match the longest school name they start
+/// with, so "Georgia Southern" wins over "Georgia".
+pub fn conference_for_team(team: &str) -> Option<&'static str> {
+    let team = team.trim().to_lowercase();
+
+    TEAM_CONFERENCES
+        .iter()
+        .filter(|(school, _)| {
+            let school = school.to_lowercase();
+            team == school || team.starts_with(&format!("{} ", school))
+        })
+        .max_by_key(|(school, _)| school.len())
+        .map(|(_, conference)| *conference)
+}
+
+/// A team's conference, or "Other" when it isn't known
+pub fn conference_or_other(team: &str) -> String {
+    conference_for_team(team)
+        .unwrap_or(OTHER_CONFERENCE)
+        .to_string()
+}
+
+/// Whether a team plays in the FBS
+/// Teams missing from the table are assumed to be lower-division.
+pub fn is_fbs_team(team: &str) -> bool {
+    conference_for_team(team).is_some_and(|conference| conference != FCS_CONFERENCE)
+}
+
+/// Whether both teams in a game play in the FBS
+pub fn is_fbs_game(home_team: &str, away_team: &str) -> bool {
+    is_fbs_team(home_team) && is_fbs_team(away_team)
+}
+
+/// Whether either team in a game plays in one of the given conferences (case-insensitive)
+/// Teams with an unknown conference count as "Other".
+pub fn game_in_conferences(home_team: &str, away_team: &str, conferences: &[String]) -> bool {
+    [home_team, away_team].iter().any(|team| {
+        let conference = conference_or_other(team);
+        conferences
+            .iter()
+            .any(|wanted| wanted.eq_ignore_ascii_case(&conference))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conference_for_team() {
+        assert_eq!(conference_for_team("Ohio State Buckeyes"), Some("Big Ten"));
+        assert_eq!(conference_for_team("Ohio Bobcats"), Some("MAC"));
+        assert_eq!(conference_for_team("Georgia Bulldogs"), Some("SEC"));
+        assert_eq!(
+            conference_for_team("Georgia Southern Eagles"),
+            Some("Sun Belt")
+        );
+        assert_eq!(conference_for_team("Miami Hurricanes"), Some("ACC"));
+        assert_eq!(conference_for_team("Miami (OH) RedHawks"), Some("MAC"));
+        assert_eq!(conference_for_team("Miami Ohio"), Some("MAC"));
+        assert_eq!(conference_for_team("Miami Florida"), Some("ACC"));
+        assert_eq!(conference_for_team("Texas A&M Aggies"), Some("SEC"));
+        assert_eq!(conference_for_team("Texas State Bobcats"), Some("Sun Belt"));
+        assert_eq!(conference_for_team("Florida Intl"), Some("Conference USA"));
+        assert_eq!(conference_for_team("Montana Grizzlies"), None);
+    }
+
+    #[test]
+    fn test_is_fbs_game() {
+        assert!(is_fbs_game("Iowa Hawkeyes", "Nebraska Cornhuskers"));
+        assert!(!is_fbs_game("Florida Gators", "Florida A&M Rattlers"));
+        assert!(!is_fbs_game("Montana Grizzlies", "Oregon Ducks"));
+        assert!(is_fbs_team("Texas State Bobcats"));
+        assert!(!is_fbs_team("Texas Southern Tigers"));
+    }
+
+    #[test]
+    fn test_game_in_conferences() {
+        let wanted = vec!["sec".to_string(), "Big Ten".to_string()];
+        assert!(game_in_conferences(
+            "Clemson Tigers",
+            "South Carolina Gamecocks",
+            &wanted
+        ));
+        assert!(!game_in_conferences(
+            "Clemson Tigers",
+            "Duke Blue Devils",
+            &wanted
+        ));
+
+        // Unknown teams are grouped under "Other"
+        let other = vec![OTHER_CONFERENCE.to_string()];
+        assert!(game_in_conferences(
+            "Montana Grizzlies",
+            "Iowa Hawkeyes",
+            &other
+        ));
+    }
+}
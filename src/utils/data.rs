@@ -1,41 +1,200 @@
+use crate::error::{CfbBettingError, Result};
+use crate::models::Game;
 use crate::utils::arbitrage::{MoneylineArbitrage, SpreadArbitrage};
+use crate::utils::backtest::OddsSnapshot;
+use crate::utils::conferences::conference_or_other;
+use crate::utils::ev_analysis::{
+    plausibility_score, AppliedBoost, BoostConfig, OddsBoost, PredictionAdjustment,
+};
 use crate::{EvBetRecommendation, SpreadEvBetRecommendation};
-use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
-use std::fs::File;
-use std::io::Write;
+use serde_json::Value;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
 use std::path::Path;
+use tracing::warn;
+
+/// Current cache file format version
+/// Bump this when a cached type changes shape, and add a step to `migrate_payload` if old
+/// files can't be read through serde defaults alone.
+pub const CACHE_VERSION: u32 = 1;
+
+/// Version assigned to cache files written before the envelope existed
+const LEGACY_CACHE_VERSION: u32 = 0;
+
+/// Versioned wrapper written around every cache file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheEnvelope<T> {
+    pub version: u32,
+    pub cached_at: DateTime<Utc>,
+    pub payload: T,
+}
 
 /// Save any serializable data to a JSON cache file.
 pub fn save_to_cache<T: Serialize>(data: &T, cache_file: &str) -> Result<()> {
-    let json = serde_json::to_string_pretty(data).context("Failed to serialize data")?;
-    std::fs::create_dir_all(Path::new(cache_file).parent().unwrap())?;
-    std::fs::write(cache_file, json).context("Failed to write cache file")?;
+    let envelope = CacheEnvelope {
+        version: CACHE_VERSION,
+        cached_at: Utc::now(),
+        payload: data,
+    };
+    let json = serde_json::to_string_pretty(&envelope)
+        .map_err(|e| CfbBettingError::cache(cache_file, e))?;
+    if let Some(dir) = Path::new(cache_file).parent() {
+        std::fs::create_dir_all(dir).map_err(|e| CfbBettingError::cache(cache_file, e))?;
+    }
+    std::fs::write(cache_file, json).map_err(|e| CfbBettingError::cache(cache_file, e))?;
     Ok(())
 }
 
 /// Load any deserializable data from a JSON cache file.
+/// Files from older versions, including unversioned raw dumps, are migrated on the way in.
 pub fn load_from_cache<T: DeserializeOwned>(cache_file: &str) -> Result<T> {
-    let json = std::fs::read_to_string(cache_file).context("Failed to read cache file")?;
-    let data: T = serde_json::from_str(&json).context("Failed to deserialize data")?;
+    let json =
+        std::fs::read_to_string(cache_file).map_err(|e| CfbBettingError::cache(cache_file, e))?;
+    let value: Value =
+        serde_json::from_str(&json).map_err(|e| CfbBettingError::cache(cache_file, e))?;
+
+    let (version, payload) = match value {
+        Value::Object(mut fields)
+            if fields.contains_key("version") && fields.contains_key("payload") =>
+        {
+            let version = fields
+                .get("version")
+                .and_then(Value::as_u64)
+                .ok_or_else(|| CfbBettingError::cache(cache_file, "invalid version"))?
+                as u32;
+            (version, fields.remove("payload").unwrap_or(Value::Null))
+        }
+        legacy => (LEGACY_CACHE_VERSION, legacy),
+    };
+
+    if version > CACHE_VERSION {
+        return Err(CfbBettingError::cache(
+            cache_file,
+            format!(
+                "written by a newer version (cache v{}, this build reads up to v{})",
+                version, CACHE_VERSION
+            ),
+        ));
+    }
+
+    let payload = migrate_payload(payload, version);
+    let data: T = serde_json::from_value(payload).map_err(|e| {
+        CfbBettingError::cache(
+            cache_file,
+            format!("failed to deserialize (cache v{}): {}", version, e),
+        )
+    })?;
     Ok(data)
 }
 
+/// Upgrade a cache payload written at `version` to the current layout
+fn migrate_payload(payload: Value, version: u32) -> Value {
+    match version {
+        // Unversioned files are the bare payload; every field added since then has a serde
+        // default, so it deserializes unchanged
+        LEGACY_CACHE_VERSION | CACHE_VERSION => payload,
+        unknown => {
+            warn!(
+                version = unknown,
+                "No migration for cache version, reading it as the current layout"
+            );
+            payload
+        }
+    }
+}
+
+/// Append an odds snapshot to a JSON Lines history file, one snapshot per line
+/// Unlike the odds cache, earlier snapshots are never overwritten, so opening lines stay
+/// available for CLV and line movement.
+pub fn append_snapshot(snapshot: &OddsSnapshot, history_file: &str) -> Result<()> {
+    let mut line =
+        serde_json::to_string(snapshot).map_err(|e| CfbBettingError::cache(history_file, e))?;
+    line.push('\n');
+    if let Some(dir) = Path::new(history_file).parent() {
+        std::fs::create_dir_all(dir).map_err(|e| CfbBettingError::cache(history_file, e))?;
+    }
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(history_file)
+        .and_then(|mut file| file.write_all(line.as_bytes()))
+        .map_err(|e| CfbBettingError::cache(history_file, e))
+}
+
+/// Every snapshot of one game in a history file, oldest first
+/// Each returned snapshot holds just that game. Lines that can't be read (e.g. cut off
+/// by a crash mid-write) are skipped with a warning.
+pub fn load_snapshots(game_id: &str, history_file: &str) -> Result<Vec<OddsSnapshot>> {
+    read_snapshots(history_file, |game| game.id == game_id)
+}
+
+/// Every snapshot in a history file, oldest first
+/// Unreadable lines are skipped with a warning, like [`load_snapshots`].
+pub fn load_all_snapshots(history_file: &str) -> Result<Vec<OddsSnapshot>> {
+    read_snapshots(history_file, |_| true)
+}
+
+/// Snapshots in a history file cut down to the games `keep` accepts, oldest first, leaving
+/// out any with none left
+fn read_snapshots(history_file: &str, keep: impl Fn(&Game) -> bool) -> Result<Vec<OddsSnapshot>> {
+    let file = File::open(history_file).map_err(|e| CfbBettingError::cache(history_file, e))?;
+
+    let mut snapshots = Vec::new();
+    for (index, line) in BufReader::new(file).lines().enumerate() {
+        let line = line.map_err(|e| CfbBettingError::cache(history_file, e))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let snapshot: OddsSnapshot = match serde_json::from_str(&line) {
+            Ok(snapshot) => snapshot,
+            Err(e) => {
+                warn!(file = history_file, line = index + 1, error = %e, "Skipping unreadable snapshot");
+                continue;
+            }
+        };
+        let games: Vec<_> = snapshot
+            .games
+            .into_iter()
+            .filter(|(game, _)| keep(game))
+            .collect();
+        if !games.is_empty() {
+            snapshots.push(OddsSnapshot {
+                taken_at: snapshot.taken_at,
+                games,
+            });
+        }
+    }
+
+    snapshots.sort_by_key(|snapshot| snapshot.taken_at);
+    Ok(snapshots)
+}
+
+/// Kickoff time as an RFC 3339 CSV field, empty when unknown
+fn format_commence_time(commence_time: Option<DateTime<Utc>>) -> String {
+    commence_time
+        .map(|time| time.to_rfc3339())
+        .unwrap_or_default()
+}
+
 /// Save moneyline arbitrage opportunities to CSV
 pub fn save_moneyline_arbitrage_to_csv(arbs: &[MoneylineArbitrage], filename: &str) -> Result<()> {
-    let mut file = File::create(filename).context("Failed to create CSV file")?;
+    let mut file = File::create(filename)?;
 
     // Write CSV header
     writeln!(
         file,
-        "Home Team,Away Team,Home Bookmaker,Home Odds,Home Stake %,Away Bookmaker,Away Odds,Away Stake %,Profit %"
+        "Game ID,Commence Time,Home Team,Away Team,Home Bookmaker,Home Odds,Home Stake %,Away Bookmaker,Away Odds,Away Stake %,Profit %"
     )?;
 
     // Write each arbitrage opportunity
     for arb in arbs {
         writeln!(
             file,
-            "{},{},{},{},{:.2},{},{},{:.2},{:.2}",
+            "{},{},{},{},{},{},{:.2},{},{},{:.2},{:.2}",
+            arb.game_id.as_deref().unwrap_or_default(),
+            format_commence_time(arb.commence_time),
             arb.home_team,
             arb.away_team,
             arb.home_bookmaker,
@@ -53,19 +212,21 @@ pub fn save_moneyline_arbitrage_to_csv(arbs: &[MoneylineArbitrage], filename: &s
 
 /// Save spread arbitrage opportunities to CSV
 pub fn save_spread_arbitrage_to_csv(arbs: &[SpreadArbitrage], filename: &str) -> Result<()> {
-    let mut file = File::create(filename).context("Failed to create CSV file")?;
+    let mut file = File::create(filename)?;
 
     // Write CSV header
     writeln!(
         file,
-        "Home Team,Away Team,Side 1 Team,Side 1 Spread,Side 1 Odds,Side 1 Bookmaker,Side 1 Stake %,Side 2 Team,Side 2 Spread,Side 2 Odds,Side 2 Bookmaker,Side 2 Stake %,Profit %"
+        "Game ID,Commence Time,Home Team,Away Team,Side 1 Team,Side 1 Spread,Side 1 Odds,Side 1 Bookmaker,Side 1 Stake %,Side 2 Team,Side 2 Spread,Side 2 Odds,Side 2 Bookmaker,Side 2 Stake %,Profit %"
     )?;
 
     // Write each arbitrage opportunity
     for arb in arbs {
         writeln!(
             file,
-            "{},{},{},{:.1},{},{},{:.2},{},{:.1},{},{},{:.2},{:.2}",
+            "{},{},{},{},{},{:.1},{},{},{:.2},{},{:.1},{},{},{:.2},{:.2}",
+            arb.game_id.as_deref().unwrap_or_default(),
+            format_commence_time(arb.commence_time),
             arb.home_team,
             arb.away_team,
             arb.side1_team,
@@ -86,20 +247,28 @@ pub fn save_spread_arbitrage_to_csv(arbs: &[SpreadArbitrage], filename: &str) ->
 }
 
 /// Save moneyline bets to CSV
-pub fn save_moneyline_bets_to_csv(bets: &[EvBetRecommendation], filename: &str) -> Result<()> {
-    let mut file = File::create(filename).context("Failed to create CSV file")?;
+/// Dollar columns are computed for each bet's stake, or a `unit_size` stake if it has none
+pub fn save_moneyline_bets_to_csv(
+    bets: &[EvBetRecommendation],
+    filename: &str,
+    unit_size: f64,
+) -> Result<()> {
+    let mut file = File::create(filename)?;
 
     // Write CSV header
     writeln!(
         file,
-        "Home Team,Away Team,Bet Team,Odds,Bookmaker,Expected Value (%),Edge (%),Model Probability (%),Implied Probability (%)"
+        "Game ID,Commence Time,Home Team,Away Team,Bet Team,Odds,Bookmaker,Expected Value (%),Edge (%),Model Probability (%),Implied Probability (%),Unit Stake ($),Profit on Win ($),Expected Profit ($),Profit Boost (%)"
     )?;
 
     // Write each bet
     for bet in bets {
+        let stake = bet.stake.unwrap_or(unit_size);
         writeln!(
             file,
-            "{},{},{},{},{},{:.2},{:.2},{:.1},{:.1}",
+            "{},{},{},{},{},{},{},{:.2},{:.2},{:.1},{:.1},{:.2},{:.2},{:.2},{}",
+            bet.game_id.as_deref().unwrap_or_default(),
+            format_commence_time(bet.commence_time),
             bet.home_team,
             bet.away_team,
             bet.team,
@@ -108,7 +277,13 @@ pub fn save_moneyline_bets_to_csv(bets: &[EvBetRecommendation], filename: &str)
             bet.expected_value * 100.0,
             bet.edge * 100.0,
             bet.model_prob * 100.0,
-            bet.implied_prob * 100.0
+            bet.implied_prob * 100.0,
+            stake,
+            bet.profit_on_win(stake),
+            bet.expected_profit(stake),
+            bet.boost
+                .map(|boost| format!("{:.1}", boost.profit_boost * 100.0))
+                .unwrap_or_default()
         )?;
     }
 
@@ -116,20 +291,28 @@ pub fn save_moneyline_bets_to_csv(bets: &[EvBetRecommendation], filename: &str)
 }
 
 /// Save spread bets to CSV
-pub fn save_spread_bets_to_csv(bets: &[SpreadEvBetRecommendation], filename: &str) -> Result<()> {
-    let mut file = File::create(filename).context("Failed to create CSV file")?;
+/// Dollar columns are computed for each bet's stake, or a `unit_size` stake if it has none
+pub fn save_spread_bets_to_csv(
+    bets: &[SpreadEvBetRecommendation],
+    filename: &str,
+    unit_size: f64,
+) -> Result<()> {
+    let mut file = File::create(filename)?;
 
     // Write CSV header
     writeln!(
         file,
-        "Home Team,Away Team,Bet Team,Spread,Odds,Bookmaker,Expected Value (%),Edge (%),Model Spread,Model Probability (%),Implied Probability (%)"
+        "Game ID,Commence Time,Home Team,Away Team,Bet Team,Spread,Odds,Bookmaker,Expected Value (%),Edge (%),Model Spread,Model Probability (%),Implied Probability (%),Unit Stake ($),Profit on Win ($),Expected Profit ($)"
     )?;
 
     // Write each bet
     for bet in bets {
+        let stake = bet.stake.unwrap_or(unit_size);
         writeln!(
             file,
-            "{},{},{},{:.1},{},{},{:.2},{:.2},{:.1},{:.1},{:.1}",
+            "{},{},{},{},{},{:.1},{},{},{:.2},{:.2},{:.1},{:.1},{:.1},{:.2},{:.2},{:.2}",
+            bet.game_id.as_deref().unwrap_or_default(),
+            format_commence_time(bet.commence_time),
             bet.home_team,
             bet.away_team,
             bet.team,
@@ -140,7 +323,10 @@ pub fn save_spread_bets_to_csv(bets: &[SpreadEvBetRecommendation], filename: &st
             bet.edge * 100.0,
             bet.model_spread,
             bet.model_prob * 100.0,
-            bet.implied_prob * 100.0
+            bet.implied_prob * 100.0,
+            stake,
+            bet.profit_on_win(stake),
+            bet.expected_profit(stake)
         )?;
     }
 
@@ -150,6 +336,11 @@ pub fn save_spread_bets_to_csv(bets: &[SpreadEvBetRecommendation], filename: &st
 /// CSV record for reading moneyline bets
 #[derive(Debug, Deserialize)]
 struct MoneylineBetCsvRecord {
+    /// Missing from CSVs written before these columns existed
+    #[serde(rename = "Game ID", default)]
+    game_id: Option<String>,
+    #[serde(rename = "Commence Time", default)]
+    commence_time: Option<DateTime<Utc>>,
     #[serde(rename = "Home Team")]
     home_team: String,
     #[serde(rename = "Away Team")]
@@ -168,11 +359,20 @@ struct MoneylineBetCsvRecord {
     model_prob_pct: f64,
     #[serde(rename = "Implied Probability (%)")]
     implied_prob_pct: f64,
+    #[serde(rename = "Unit Stake ($)", default)]
+    stake: Option<f64>,
+    #[serde(rename = "Profit Boost (%)", default)]
+    profit_boost_pct: Option<f64>,
 }
 
 /// CSV record for reading spread bets
 #[derive(Debug, Deserialize)]
 struct SpreadBetCsvRecord {
+    /// Missing from CSVs written before these columns existed
+    #[serde(rename = "Game ID", default)]
+    game_id: Option<String>,
+    #[serde(rename = "Commence Time", default)]
+    commence_time: Option<DateTime<Utc>>,
     #[serde(rename = "Home Team")]
     home_team: String,
     #[serde(rename = "Away Team")]
@@ -195,27 +395,45 @@ struct SpreadBetCsvRecord {
     model_prob_pct: f64,
     #[serde(rename = "Implied Probability (%)")]
     implied_prob_pct: f64,
+    #[serde(rename = "Unit Stake ($)", default)]
+    stake: Option<f64>,
 }
 
 /// Load moneyline bets from CSV
 pub fn load_moneyline_bets_from_csv(filename: &str) -> Result<Vec<EvBetRecommendation>> {
     let mut reader = csv::Reader::from_path(filename)
-        .context(format!("Failed to open CSV file: {}", filename))?;
+        .map_err(|e| CfbBettingError::parse(format!("CSV file {}", filename), e))?;
 
     let mut bets = Vec::new();
     for result in reader.deserialize() {
-        let record: MoneylineBetCsvRecord = result.context("Failed to parse CSV record")?;
+        let record: MoneylineBetCsvRecord =
+            result.map_err(|e| CfbBettingError::parse(format!("CSV record in {}", filename), e))?;
+        let conference = conference_or_other(&record.team);
 
         bets.push(EvBetRecommendation {
             home_team: record.home_team,
             away_team: record.away_team,
             team: record.team,
-            bookmaker: record.bookmaker,
+            bookmaker: record.bookmaker.clone(),
             odds: record.odds,
             model_prob: record.model_prob_pct / 100.0,
             implied_prob: record.implied_prob_pct / 100.0,
+            fair_prob: None,
             expected_value: record.expected_value_pct / 100.0,
             edge: record.edge_pct / 100.0,
+            // The CSV only records the chosen book, so treat it as the best price
+            best_odds: record.odds,
+            best_bookmaker: record.bookmaker.clone(),
+            is_best_price: true,
+            plausibility_score: plausibility_score(record.edge_pct / 100.0),
+            conference,
+            commence_time: record.commence_time,
+            game_id: record.game_id,
+            stake: record.stake,
+            adjustments: Vec::new(),
+            boost: record.profit_boost_pct.map(|pct| {
+                AppliedBoost::new(record.odds, record.model_prob_pct / 100.0, pct / 100.0)
+            }),
         });
     }
 
@@ -225,26 +443,196 @@ pub fn load_moneyline_bets_from_csv(filename: &str) -> Result<Vec<EvBetRecommend
 /// Load spread bets from CSV
 pub fn load_spread_bets_from_csv(filename: &str) -> Result<Vec<SpreadEvBetRecommendation>> {
     let mut reader = csv::Reader::from_path(filename)
-        .context(format!("Failed to open CSV file: {}", filename))?;
+        .map_err(|e| CfbBettingError::parse(format!("CSV file {}", filename), e))?;
 
     let mut bets = Vec::new();
     for result in reader.deserialize() {
-        let record: SpreadBetCsvRecord = result.context("Failed to parse CSV record")?;
+        let record: SpreadBetCsvRecord =
+            result.map_err(|e| CfbBettingError::parse(format!("CSV record in {}", filename), e))?;
+        let conference = conference_or_other(&record.team);
 
         bets.push(SpreadEvBetRecommendation {
             home_team: record.home_team,
             away_team: record.away_team,
             team: record.team,
             spread_line: record.spread_line,
-            bookmaker: record.bookmaker,
+            bookmaker: record.bookmaker.clone(),
             odds: record.odds,
             model_spread: record.model_spread,
             model_prob: record.model_prob_pct / 100.0,
             implied_prob: record.implied_prob_pct / 100.0,
             expected_value: record.expected_value_pct / 100.0,
             edge: record.edge_pct / 100.0,
+            // The CSV only records the chosen book, so treat it as the best price
+            best_odds: record.odds,
+            best_bookmaker: record.bookmaker,
+            is_best_price: true,
+            // Alternate lines aren't recorded in the CSV
+            is_main_line: true,
+            scraped_cover_prob: None,
+            conference,
+            commence_time: record.commence_time,
+            game_id: record.game_id,
+            stake: record.stake,
+            adjustments: Vec::new(),
         });
     }
 
     Ok(bets)
 }
+
+/// Load manual prediction adjustments from a CSV with `team`, `spread_delta`,
+/// `prob_delta` and `note` columns (`prob_delta` as a fraction, e.g. -0.05)
+pub fn load_adjustments_from_csv(filename: &str) -> Result<Vec<PredictionAdjustment>> {
+    let mut reader = csv::Reader::from_path(filename)
+        .map_err(|e| CfbBettingError::parse(format!("CSV file {}", filename), e))?;
+
+    reader
+        .deserialize()
+        .map(|result| {
+            result.map_err(|e| CfbBettingError::parse(format!("CSV record in {}", filename), e))
+        })
+        .collect()
+}
+
+/// Load profit boosts from a CSV with `team`, `bookmaker`, `opponent` and `profit_boost`
+/// columns (`opponent` may be left empty; `profit_boost` as a fraction, e.g. 0.5)
+pub fn load_boosts_from_csv(filename: &str) -> Result<BoostConfig> {
+    let mut reader = csv::Reader::from_path(filename)
+        .map_err(|e| CfbBettingError::parse(format!("CSV file {}", filename), e))?;
+
+    reader
+        .deserialize::<OddsBoost>()
+        .map(|result| {
+            result.map_err(|e| CfbBettingError::parse(format!("CSV record in {}", filename), e))
+        })
+        .collect::<Result<Vec<_>>>()
+        .map(BoostConfig::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Timelike};
+
+    fn temp_cache_file(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!("cfb_betting_{}_{}.json", name, std::process::id()))
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[test]
+    fn test_cache_round_trip_writes_envelope() {
+        let file = temp_cache_file("round_trip");
+        save_to_cache(&vec![1, 2, 3], &file).unwrap();
+
+        let envelope: CacheEnvelope<Vec<i32>> =
+            serde_json::from_str(&std::fs::read_to_string(&file).unwrap()).unwrap();
+        assert_eq!(envelope.version, CACHE_VERSION);
+        assert_eq!(envelope.payload, vec![1, 2, 3]);
+
+        let loaded: Vec<i32> = load_from_cache(&file).unwrap();
+        assert_eq!(loaded, vec![1, 2, 3]);
+        std::fs::remove_file(&file).unwrap();
+    }
+
+    #[test]
+    fn test_snapshot_history_keeps_every_snapshot() {
+        let file = temp_cache_file("history");
+        let _ = std::fs::remove_file(&file);
+        let game = |id: &str| -> (crate::Game, Vec<crate::BettingOdds>) {
+            serde_json::from_value(serde_json::json!([{
+                "id": id,
+                "home_team": "Iowa Hawkeyes",
+                "away_team": "Nebraska Cornhuskers",
+                "commence_time": "2025-11-28T20:00:00Z",
+                "sport_title": "NCAAF",
+            }, []]))
+            .unwrap()
+        };
+        let snapshot = |hour: u32, ids: &[&str]| OddsSnapshot {
+            taken_at: Utc.with_ymd_and_hms(2025, 11, 24, hour, 0, 0).unwrap(),
+            games: ids.iter().map(|id| game(id)).collect(),
+        };
+
+        append_snapshot(&snapshot(9, &["a", "b"]), &file).unwrap();
+        append_snapshot(&snapshot(12, &["b"]), &file).unwrap();
+        // A line cut off mid-write doesn't lose the rest of the history
+        OpenOptions::new()
+            .append(true)
+            .open(&file)
+            .unwrap()
+            .write_all(b"{\"taken_at\":\n")
+            .unwrap();
+        append_snapshot(&snapshot(15, &["a"]), &file).unwrap();
+
+        let history = load_snapshots("a", &file).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].taken_at.hour(), 9);
+        assert_eq!(history[1].taken_at.hour(), 15);
+        assert!(history
+            .iter()
+            .all(|snapshot| snapshot.games.len() == 1 && snapshot.games[0].0.id == "a"));
+        assert_eq!(load_snapshots("b", &file).unwrap().len(), 2);
+        assert!(load_snapshots("c", &file).unwrap().is_empty());
+        std::fs::remove_file(&file).unwrap();
+    }
+
+    #[test]
+    fn test_load_from_cache_migrates_legacy_and_rejects_newer() {
+        let file = temp_cache_file("legacy");
+        std::fs::write(&file, "[4, 5]").unwrap();
+        let loaded: Vec<i32> = load_from_cache(&file).unwrap();
+        assert_eq!(loaded, vec![4, 5]);
+
+        let newer = serde_json::json!({
+            "version": CACHE_VERSION + 1,
+            "cached_at": Utc::now(),
+            "payload": [4, 5],
+        });
+        std::fs::write(&file, newer.to_string()).unwrap();
+        let err = load_from_cache::<Vec<i32>>(&file).unwrap_err();
+        assert!(err.to_string().contains("newer version"));
+        std::fs::remove_file(&file).unwrap();
+    }
+
+    #[test]
+    fn test_spread_bet_csv_round_trips_game_id_and_time() {
+        let kickoff = "2025-09-13T19:30:00Z".parse::<DateTime<Utc>>().unwrap();
+        let bet: SpreadEvBetRecommendation = serde_json::from_value(serde_json::json!({
+            "home_team": "Iowa Hawkeyes",
+            "away_team": "Iowa State Cyclones",
+            "team": "Iowa Hawkeyes",
+            "spread_line": -3.5,
+            "bookmaker": "FanDuel",
+            "odds": -110,
+            "model_spread": 7.0,
+            "model_prob": 0.6,
+            "implied_prob": 0.524,
+            "expected_value": 0.15,
+            "edge": 0.076,
+            "commence_time": kickoff,
+            "game_id": "abc123",
+        }))
+        .unwrap();
+
+        let file = temp_cache_file("spread_csv").replace(".json", ".csv");
+        save_spread_bets_to_csv(&[bet], &file, 100.0).unwrap();
+        let loaded = load_spread_bets_from_csv(&file).unwrap();
+        assert_eq!(loaded[0].game_id.as_deref(), Some("abc123"));
+        assert_eq!(loaded[0].commence_time, Some(kickoff));
+
+        // CSVs from before the columns existed still load
+        std::fs::write(
+            &file,
+            "Home Team,Away Team,Bet Team,Spread,Odds,Bookmaker,Expected Value (%),Edge (%),Model Spread,Model Probability (%),Implied Probability (%)\n\
+             Iowa Hawkeyes,Iowa State Cyclones,Iowa Hawkeyes,-3.5,-110,FanDuel,15.0,7.6,7.0,60.0,52.4\n",
+        )
+        .unwrap();
+        let loaded = load_spread_bets_from_csv(&file).unwrap();
+        assert_eq!(loaded[0].game_id, None);
+        assert_eq!(loaded[0].commence_time, None);
+        std::fs::remove_file(&file).unwrap();
+    }
+}
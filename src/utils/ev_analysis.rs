@@ -1,19 +1,64 @@
 use crate::api::game_results_api::GameResult;
-use crate::models::{BettingOdds, Game};
+use crate::error::Result;
+use crate::models::{BestOdds, BettingOdds, Bookmaker, Game};
 use crate::scrapers::prediction_tracker::{normalize_team_name, GamePrediction};
+use crate::utils::conferences::{
+    conference_or_other, game_in_conferences, is_fbs_game, OTHER_CONFERENCE,
+};
 use crate::utils::ev_calculator::{
-    american_odds_to_probability, calculate_expected_value, calculate_spread_cover_probability,
+    american_odds_to_probability, boost_odds, calculate_expected_value,
+    calculate_spread_cover_probability, devig_two_way, profit_on_win,
 };
-use anyhow::Result;
-use chrono::Utc;
+use crate::utils::team_names::GameMatcher;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use tracing::debug;
+
+/// First words of directional school names ("South Florida", "Western Michigan"), which
+/// need the second word to tell them apart
+const DIRECTIONAL_PREFIXES: &[&str] = &[
+    "north", "south", "east", "west", "northern", "southern", "eastern", "western", "central",
+    "middle", "coastal",
+];
+
+/// School keys that the odds and prediction sources spell differently, mapped to one key
+const SCHOOL_KEY_ALIASES: &[(&str, &str)] = &[
+    ("mississippi", "ole_miss"),
+    // A bare "Miami" is the Hurricanes; the RedHawks are always qualified
+    ("miami", "miami_fl"),
+    ("sjsu", "san_jose_st"),
+    ("sdsu", "san_diego_st"),
+    ("southern_miss", "southern_mississippi"),
+    ("nc_st", "north_carolina_st"),
+    ("app_st", "appalachian_st"),
+    ("fiu", "florida_international"),
+    ("florida_intl", "florida_international"),
+    ("louisiana_monroe", "ul_monroe"),
+    ("sam_houston_st", "sam_houston"),
+    ("middle_tennessee_st", "middle_tennessee"),
+    ("ul_lafayette", "louisiana"),
+    ("louisiana_lafayette", "louisiana"),
+];
 
 /// Extract the primary school name from a full team name
 /// "Iowa Hawkeyes" -> "iowa"
 /// "Ohio State Buckeyes" -> "ohio_st"
 /// "San Diego State Aztecs" -> "san_diego_st"
-fn extract_school_name(team_name: &str) -> String {
+/// "Miami (OH) RedHawks" -> "miami_oh", "Miami Hurricanes" -> "miami_fl"
+pub fn extract_school_name(team_name: &str) -> String {
+    let key = school_key(team_name);
+
+    SCHOOL_KEY_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == key)
+        .map_or(key, |(_, canonical)| canonical.to_string())
+}
+
+/// School key before aliases are applied
+fn school_key(team_name: &str) -> String {
     // Apply special mappings first (matching what the scraper does)
     if team_name.contains("Central Florida") || team_name.contains("UCF") {
         return "ucf".to_string();
@@ -30,11 +75,13 @@ fn extract_school_name(team_name: &str) -> String {
     if team_name == "Kent" {
         return "kent_st".to_string();
     }
-    if team_name == "Southern Miss" {
-        return "southern_mississippi".to_string();
-    }
 
-    let normalized = normalize_team_name(team_name);
+    // Drop punctuation so "Miami (OH)", "Hawai'i" and "San José State" line up with the
+    // plain spellings
+    let normalized = normalize_team_name(team_name)
+        .replace('é', "e")
+        .replace(['(', ')', '\'', '.'], "")
+        .replace('-', "_");
 
     // Split by underscore
     let parts: Vec<&str> = normalized.split('_').collect();
@@ -48,62 +95,398 @@ fn extract_school_name(team_name: &str) -> String {
             // Include everything up to and including "st"
             // e.g., "san_diego_st" for San Diego State
             parts[..=idx].join("_")
-        } else if parts.len() >= 2 && parts[1] == "dame" {
+        } else if parts[0] == "miami" {
+            // Miami (OH) and Miami (FL) are different schools; tell them apart by the state
+            // or the nickname
+            if matches!(parts[1], "oh" | "ohio" | "redhawks") {
+                "miami_oh".to_string()
+            } else {
+                "miami_fl".to_string()
+            }
+        } else if parts.len() >= 3
+            && parts[0] == "north"
+            && parts[1] == "carolina"
+            && (parts[2] == "aandt" || parts[2] == "central")
+        {
+            // North Carolina A&T and North Carolina Central aren't UNC
+            parts[..3].join("_")
+        } else if DIRECTIONAL_PREFIXES.contains(&parts[0]) {
+            format!("{}_{}", parts[0], parts[1])
+        } else if parts[1] == "dame" {
             // Handle "Notre Dame"
             format!("{}_{}", parts[0], parts[1])
-        } else if parts.len() >= 2 && parts[1] == "ill" {
+        } else if parts[1] == "ill" {
             let parts_1 = "illinois";
             format!("{}_{}", parts[0], parts_1)
-        } else if parts.len() >= 2 && parts[1] == "mich" {
+        } else if parts[1] == "mich" {
             let parts_1 = "michigan";
             format!("{}_{}", parts[0], parts_1)
-        } else if parts.len() >= 2 && parts[1] == "va" {
+        } else if parts[1] == "va" {
             let parts_1 = "virginia";
             format!("{}_{}", parts[0], parts_1)
-        } else if parts.len() >= 2 && parts[1] == "aandm" {
+        } else if parts[1] == "aandm" {
             // Handle "Texas A&M" -> "texas_aandm"
             format!("{}_{}", parts[0], parts[1])
-        } else if parts.len() >= 2
-            && (parts[1] == "forest"
-                || parts[1] == "texas"
-                || parts[1] == "force"
-                || parts[1] == "mexico"
-                || parts[1] == "kentucky"
-                || parts[1] == "virginia"
-                || parts[1] == "michigan"
-                || parts[1] == "illinois"
-                || parts[1] == "tech"
-                || parts[1] == "carolina"
-                || parts[1] == "mississippi"
-                || parts[1] == "mich"
-                || parts[1] == "monroe"
-                || parts[1] == "miss")
+        } else if parts[1] == "forest"
+            || parts[1] == "texas"
+            || parts[1] == "force"
+            || parts[1] == "mexico"
+            || parts[1] == "kentucky"
+            || parts[1] == "virginia"
+            || parts[1] == "michigan"
+            || parts[1] == "illinois"
+            || parts[1] == "tech"
+            || parts[1] == "carolina"
+            || parts[1] == "mississippi"
+            || parts[1] == "monroe"
+            || parts[1] == "miss"
+            || parts[1] == "southern"
+            || parts[1] == "atlantic"
+            || parts[1] == "international"
+            || parts[1] == "intl"
+            || parts[1] == "houston"
+            || parts[1] == "lafayette"
         {
             // Handle two-word schools: Wake Forest, North Texas, Air Force, New Mexico,
-            // Western Kentucky, West Virginia, Western Michigan, etc.
+            // West Virginia, Ole Miss, Georgia Southern, Florida Atlantic, Sam Houston, etc.
             format!("{}_{}", parts[0], parts[1])
         } else {
             // Just use the first word (e.g., "iowa" from "iowa_hawkeyes")
             parts[0].to_string()
         }
     } else {
-        if normalized == "mississippi" {
-            return "ole_miss".to_string();
-        }
         normalized
     }
 }
 
+/// Standard deviation of the final margin around the model spread (typically 10-14 points
+/// for college football)
+pub const SPREAD_STD_DEV: f64 = 12.0;
+
+/// How the std dev of the final margin is chosen for each game when pricing spreads
+/// Lopsided games end in a wider range of margins than even ones, so the linear model lets
+/// the std dev grow with the size of the projected margin (and with the projected total,
+/// when one is known).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum MarginStdDev {
+    /// The same std dev for every game
+    Constant(f64),
+    /// `base + per_spread_point * |projected margin| + per_total_point * projected total`
+    Linear {
+        base: f64,
+        per_spread_point: f64,
+        /// Ignored when the game has no projected total
+        per_total_point: f64,
+    },
+}
+
+impl Default for MarginStdDev {
+    fn default() -> Self {
+        MarginStdDev::Constant(SPREAD_STD_DEV)
+    }
+}
+
+impl MarginStdDev {
+    /// Std dev of the final margin for a game projected to be decided by `projected_margin`
+    pub fn std_dev(&self, projected_margin: f64, projected_total: Option<f64>) -> f64 {
+        match *self {
+            MarginStdDev::Constant(std_dev) => std_dev,
+            MarginStdDev::Linear {
+                base,
+                per_spread_point,
+                per_total_point,
+            } => {
+                base + per_spread_point * projected_margin.abs()
+                    + per_total_point * projected_total.unwrap_or(0.0)
+            }
+        }
+    }
+}
+
+/// Default stake used when showing dollar amounts for a recommendation
+pub const DEFAULT_UNIT_SIZE: f64 = 100.0;
+
+/// Edges up to this size (model prob minus implied prob) are fully plausible
+const PLAUSIBLE_EDGE: f64 = 0.10;
+
+/// Edges at or above this size almost always mean stale odds or a mismatched prediction
+pub const DEFAULT_MAX_PLAUSIBLE_EDGE: f64 = 0.25;
+
+/// How believable an edge is, from 1.0 (moderate edge) down to 0.0 (edge at or above
+/// `DEFAULT_MAX_PLAUSIBLE_EDGE`), decaying linearly in between
+pub fn plausibility_score(edge: f64) -> f64 {
+    if edge <= PLAUSIBLE_EDGE {
+        1.0
+    } else {
+        (1.0 - (edge - PLAUSIBLE_EDGE) / (DEFAULT_MAX_PLAUSIBLE_EDGE - PLAUSIBLE_EDGE)).max(0.0)
+    }
+}
+
+fn default_plausibility_score() -> f64 {
+    1.0
+}
+
+fn default_conference() -> String {
+    OTHER_CONFERENCE.to_string()
+}
+
+fn default_main_line() -> bool {
+    true
+}
+
+/// Each team's main spread line at one bookmaker
+/// When a book also lists alternate lines, the main line is the one priced closest to even.
+pub fn main_spread_lines(odds: &BettingOdds) -> HashMap<&str, f64> {
+    let mut main: HashMap<&str, (f64, f64)> = HashMap::new();
+    for spread in &odds.spreads {
+        let distance = (american_odds_to_probability(spread.price) - 0.5).abs();
+        let entry = main
+            .entry(spread.team.as_str())
+            .or_insert((spread.point, distance));
+        if distance < entry.1 {
+            *entry = (spread.point, distance);
+        }
+    }
+
+    main.into_iter()
+        .map(|(team, (point, _))| (team, point))
+        .collect()
+}
+
+/// Which bets the EV finders keep; every check runs before the `top_n` cut
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EvFilter {
+    /// Skip games involving a non-FBS team
+    pub fbs_only: bool,
+    /// Drop bets below this EV per $1 staked (e.g. 0.03 for 3%); 0.0 keeps every positive
+    /// EV bet
+    pub min_ev: f64,
+    /// Drop bets whose edge is above this fraction, which usually means a stale line or a
+    /// data error
+    pub max_edge: Option<f64>,
+    /// Only consider games involving one of these conferences (empty for every game)
+    pub conferences: Vec<String>,
+}
+
+impl EvFilter {
+    /// Keep every positive EV bet of at least `min_ev`
+    pub fn new(min_ev: f64) -> Self {
+        Self {
+            min_ev,
+            ..Self::default()
+        }
+    }
+
+    /// Whether a game's bets are considered at all
+    pub fn includes_matchup(&self, home_team: &str, away_team: &str) -> bool {
+        self.includes_division(home_team, away_team)
+            && self.includes_conference(home_team, away_team)
+    }
+
+    fn includes_game(&self, game: &Game) -> bool {
+        self.includes_matchup(&game.home_team, &game.away_team)
+    }
+
+    /// Whether a game passes `fbs_only`
+    fn includes_division(&self, home_team: &str, away_team: &str) -> bool {
+        !self.fbs_only || is_fbs_game(home_team, away_team)
+    }
+
+    /// Whether a game passes the conference filter
+    fn includes_conference(&self, home_team: &str, away_team: &str) -> bool {
+        self.conferences.is_empty() || game_in_conferences(home_team, away_team, &self.conferences)
+    }
+
+    /// Whether an edge is small enough to trust
+    fn trusts_edge(&self, edge: f64) -> bool {
+        self.max_edge.is_none_or(|max_edge| edge <= max_edge)
+    }
+
+    /// Whether a bet with this EV and edge is kept
+    pub fn keeps(&self, expected_value: f64, edge: f64) -> bool {
+        expected_value > 0.0 && expected_value >= self.min_ev && self.trusts_edge(edge)
+    }
+}
+
 /// Analyze all available games and return all positive EV bets (or top N if specified)
+/// `max_books_per_bet` caps how many bookmakers are listed for the same bet (keeping the
+/// best-EV ones), so `top_n` counts distinct opportunities. `None` lists every book.
+/// Bets are screened by `filter` before the top N are taken. Prices covered by `boosts` are
+/// priced at their boosted odds too, and those bets are ranked and filtered by the
+/// boosted EV.
 pub async fn find_top_ev_bets(
     games_with_odds: &[(Game, Vec<BettingOdds>)],
     predictions: &[GamePrediction],
     top_n: Option<usize>,
+    max_books_per_bet: Option<usize>,
+    filter: &EvFilter,
+    boosts: &BoostConfig,
 ) -> Result<Vec<EvBetRecommendation>> {
+    let (bets, _) = find_top_ev_bets_with_diagnostics(
+        games_with_odds,
+        predictions,
+        top_n,
+        max_books_per_bet,
+        filter,
+        boosts,
+    )
+    .await?;
+    Ok(bets)
+}
+
+/// Why a game produced no moneyline recommendation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SkipReason {
+    /// Kicked off already; predictions aren't live
+    Started,
+    /// `fbs_only` is set and a team isn't FBS
+    NotFbs,
+    /// Neither team is in the requested conferences
+    OutsideConferences,
+    /// No prediction matched the game's teams
+    NoPrediction,
+    /// A prediction matched, but no book's moneyline names its teams the same way
+    NoPricedTeams,
+    /// Every price is at or below break-even
+    NoPositiveEv { best_ev: f64 },
+    /// The best bet is positive but under `min_ev`
+    BelowMinEv { best_ev: f64 },
+    /// Every positive EV bet has an edge above `max_edge`
+    EdgeTooLarge { best_ev: f64 },
+    /// Qualifying bets were crowded out by better ones under `top_n`
+    OutsideTopN { best_ev: f64 },
+}
+
+impl SkipReason {
+    /// Human-readable explanation for the debug page
+    pub fn describe(&self) -> String {
+        match self {
+            SkipReason::Started => "Already started".to_string(),
+            SkipReason::NotFbs => "Not an FBS matchup".to_string(),
+            SkipReason::OutsideConferences => "Outside the requested conferences".to_string(),
+            SkipReason::NoPrediction => "No prediction matched this game".to_string(),
+            SkipReason::NoPricedTeams => {
+                "Prediction found, but no moneyline matched its team names".to_string()
+            }
+            SkipReason::NoPositiveEv { best_ev } => {
+                format!("No positive EV (best {:+.1}%)", best_ev * 100.0)
+            }
+            SkipReason::BelowMinEv { best_ev } => {
+                format!("Best EV {:+.1}% is below the minimum", best_ev * 100.0)
+            }
+            SkipReason::EdgeTooLarge { best_ev } => format!(
+                "Best EV {:+.1}% comes from an edge too large to trust",
+                best_ev * 100.0
+            ),
+            SkipReason::OutsideTopN { best_ev } => {
+                format!("Best EV {:+.1}% didn't make the top N", best_ev * 100.0)
+            }
+        }
+    }
+}
+
+/// A game left out of the recommendations, and why
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Skipped {
+    pub game: Game,
+    pub reason: SkipReason,
+}
+
+/// `find_top_ev_bets`, plus the reason every other game produced no bet
+/// Handy for spotting team names that silently fail to match between the odds feed and
+/// the predictions.
+pub async fn find_top_ev_bets_with_diagnostics(
+    games_with_odds: &[(Game, Vec<BettingOdds>)],
+    predictions: &[GamePrediction],
+    top_n: Option<usize>,
+    max_books_per_bet: Option<usize>,
+    filter: &EvFilter,
+    boosts: &BoostConfig,
+) -> Result<(Vec<EvBetRecommendation>, Vec<Skipped>)> {
     // Prediction model data is not live yet, so only look at bets in the future
     let now = Utc::now();
-    let games_with_odds = games_with_odds.iter().filter(|g| g.0.commence_time > now);
+    let mut skipped = Vec::new();
+    let mut priced = Vec::new();
+    for pair in games_with_odds {
+        let game = &pair.0;
+        let reason = if game.commence_time <= now {
+            SkipReason::Started
+        } else if !filter.includes_division(&game.home_team, &game.away_team) {
+            SkipReason::NotFbs
+        } else if !filter.includes_conference(&game.home_team, &game.away_team) {
+            SkipReason::OutsideConferences
+        } else {
+            priced.push(pair);
+            continue;
+        };
+        skipped.push(Skipped {
+            game: game.clone(),
+            reason,
+        });
+    }
+
+    let all_bets = moneyline_bets_vs_predictions(priced.iter().copied(), predictions, boosts);
+
+    // Best EV per game, overall and among trusted edges, to explain games that end up
+    // without a bet
+    let mut best_ev: HashMap<(String, String), (f64, f64)> = HashMap::new();
+    for bet in &all_bets {
+        let (best, best_trusted) = best_ev
+            .entry((bet.home_team.clone(), bet.away_team.clone()))
+            .or_insert((f64::NEG_INFINITY, f64::NEG_INFINITY));
+        *best = best.max(bet.effective_ev());
+        if filter.trusts_edge(bet.edge) {
+            *best_trusted = best_trusted.max(bet.effective_ev());
+        }
+    }
+    let predicted: HashSet<(String, String)> = predictions
+        .iter()
+        .flat_map(|pred| {
+            let home_key = extract_school_name(&pred.home_team);
+            let away_key = extract_school_name(&pred.away_team);
+            [(home_key.clone(), away_key.clone()), (away_key, home_key)]
+        })
+        .collect();
+
+    let bets = top_positive_ev_bets(all_bets, top_n, max_books_per_bet, filter);
+
+    for (game, _) in priced {
+        if bets
+            .iter()
+            .any(|bet| bet.home_team == game.home_team && bet.away_team == game.away_team)
+        {
+            continue;
+        }
+        let key = (
+            extract_school_name(&game.home_team),
+            extract_school_name(&game.away_team),
+        );
+        let reason = match best_ev.get(&(game.home_team.clone(), game.away_team.clone())) {
+            None if !predicted.contains(&key) => SkipReason::NoPrediction,
+            None => SkipReason::NoPricedTeams,
+            Some(&(best_ev, _)) if best_ev <= 0.0 => SkipReason::NoPositiveEv { best_ev },
+            Some(&(best_ev, best_trusted)) if best_trusted <= 0.0 => {
+                SkipReason::EdgeTooLarge { best_ev }
+            }
+            Some(&(_, best_ev)) if best_ev < filter.min_ev => SkipReason::BelowMinEv { best_ev },
+            Some(&(_, best_ev)) => SkipReason::OutsideTopN { best_ev },
+        };
+        skipped.push(Skipped {
+            game: game.clone(),
+            reason,
+        });
+    }
 
+    Ok((bets, skipped))
+}
+
+/// Price every moneyline in the given games against the prediction model
+/// Returns all bets, positive EV or not; games without a prediction are skipped.
+pub(crate) fn moneyline_bets_vs_predictions<'a>(
+    games_with_odds: impl Iterator<Item = &'a (Game, Vec<BettingOdds>)>,
+    predictions: &[GamePrediction],
+    boosts: &BoostConfig,
+) -> Vec<EvBetRecommendation> {
     // Create a lookup map for predictions by team names
     // Use extract_school_name to match with Odds API which has full names
     let mut prediction_map: HashMap<String, HashMap<String, f64>> = HashMap::new();
@@ -132,55 +515,341 @@ pub async fn find_top_ev_bets(
         let game_predictions = match prediction_map.get(&game_key) {
             Some(preds) => preds,
             None => {
-                println!(
-                    "No prediction found for: {} vs {} (odds api key: {})",
-                    game.home_team, game.away_team, game_key
+                debug!(
+                    home = %game.home_team,
+                    away = %game.away_team,
+                    key = %game_key,
+                    "No prediction found"
                 );
                 continue; // Skip games without predictions
             }
         };
 
         // Analyze each bookmaker's odds
-        for bookmaker_odds in odds_list {
-            for moneyline in &bookmaker_odds.moneyline {
-                let team_key = extract_school_name(&moneyline.team);
-
-                if let Some(&model_prob) = game_predictions.get(&team_key) {
-                    let implied_prob = american_odds_to_probability(moneyline.price);
-                    let ev = calculate_expected_value(model_prob, moneyline.price);
-                    let edge = model_prob - implied_prob;
-
-                    all_bets.push(EvBetRecommendation {
-                        home_team: game.home_team.clone(),
-                        away_team: game.away_team.clone(),
-                        team: moneyline.team.clone(),
-                        bookmaker: bookmaker_odds.bookmaker.clone(),
-                        odds: moneyline.price,
-                        model_prob,
-                        implied_prob,
-                        expected_value: ev,
-                        edge,
+        let best_prices = best_price_by_book(odds_list);
+        all_bets.extend(moneyline_bets_for_game(
+            game,
+            odds_list.iter(),
+            game_predictions,
+            &best_prices,
+            boosts,
+        ));
+    }
+
+    all_bets
+}
+
+/// `sharp_book` value that prices bets against the whole market's consensus instead of one book
+pub const CONSENSUS_SHARP_BOOK: &str = "consensus";
+
+/// Market consensus fair win probabilities for a game, as `(home, away)`
+/// Every book pricing both teams' moneylines is de-vigged, and the results are averaged with
+/// sharper books weighted up (see `Bookmaker::market_weight`). `None` when no book prices
+/// both sides.
+pub fn fair_market_prob(odds_list: &[BettingOdds], game: &Game) -> Option<(f64, f64)> {
+    let mut weighted_home_prob = 0.0;
+    let mut total_weight = 0.0;
+
+    for odds in odds_list {
+        let price = |team: &str| {
+            odds.moneyline
+                .iter()
+                .find(|moneyline| moneyline.team == team)
+                .map(|moneyline| moneyline.price)
+        };
+        let (Some(home_price), Some(away_price)) = (price(&game.home_team), price(&game.away_team))
+        else {
+            continue;
+        };
+
+        let (home_prob, _) = devig_two_way(home_price, away_price);
+        let weight = odds.book().market_weight();
+        weighted_home_prob += home_prob * weight;
+        total_weight += weight;
+    }
+
+    (total_weight > 0.0).then(|| {
+        let home_prob = weighted_home_prob / total_weight;
+        (home_prob, 1.0 - home_prob)
+    })
+}
+
+/// Fewest books pricing both sides of a game for its consensus to count as a fair line
+pub const MIN_MARKET_BOOKS: usize = 3;
+
+/// Find moneyline value with no prediction model, e.g. for CBB
+/// Each book's prices are compared against the de-vigged consensus of every book on the
+/// game (`fair_market_prob`), so `model_prob` is the consensus fair probability. Games
+/// priced by fewer than `MIN_MARKET_BOOKS` books are skipped; their consensus is mostly
+/// the book being judged.
+pub async fn find_market_value_bets(
+    games_with_odds: &[(Game, Vec<BettingOdds>)],
+    top_n: Option<usize>,
+    max_books_per_bet: Option<usize>,
+    filter: &EvFilter,
+) -> Result<Vec<EvBetRecommendation>> {
+    let liquid_games: Vec<(Game, Vec<BettingOdds>)> = games_with_odds
+        .iter()
+        .filter(|(game, odds_list)| {
+            odds_list
+                .iter()
+                .filter(|odds| odds.has_complete_moneyline(game))
+                .count()
+                >= MIN_MARKET_BOOKS
+        })
+        .cloned()
+        .collect();
+
+    find_top_ev_bets_vs_sharp(
+        CONSENSUS_SHARP_BOOK,
+        &liquid_games,
+        top_n,
+        max_books_per_bet,
+        filter,
+    )
+    .await
+}
+
+/// Sum of the implied probabilities of a book's two moneyline prices on a game
+/// `None` when the book doesn't price both teams.
+fn moneyline_overround(odds: &BettingOdds, game: &Game) -> Option<f64> {
+    let price = |team: &str| {
+        odds.moneyline
+            .iter()
+            .find(|moneyline| moneyline.team == team)
+            .map(|moneyline| american_odds_to_probability(moneyline.price))
+    };
+    Some(price(&game.home_team)? + price(&game.away_team)?)
+}
+
+/// Each book's over-round on a game's moneyline, by bookmaker
+/// The over-round is the sum of both sides' implied probabilities; how far it's above 1.0
+/// is the book's margin (vig). Books not pricing both teams are left out.
+pub fn overround(odds_list: &[BettingOdds], game: &Game) -> HashMap<String, f64> {
+    odds_list
+        .iter()
+        .filter_map(|odds| Some((odds.bookmaker.clone(), moneyline_overround(odds, game)?)))
+        .collect()
+}
+
+/// The book charging the least vig on a game's moneyline, with its over-round
+/// Ties go to the book listed first.
+pub fn lowest_vig_book<'a>(odds_list: &'a [BettingOdds], game: &Game) -> Option<(&'a str, f64)> {
+    odds_list
+        .iter()
+        .filter_map(|odds| Some((odds.bookmaker.as_str(), moneyline_overround(odds, game)?)))
+        .fold(None, |lowest, (bookmaker, overround)| match lowest {
+            Some((_, lowest_overround)) if lowest_overround <= overround => lowest,
+            _ => Some((bookmaker, overround)),
+        })
+}
+
+/// Find positive EV moneyline bets using a sharp bookmaker's de-vigged line as the model
+/// For each game, the sharp book's two moneyline prices are de-vigged into fair win
+/// probabilities, which are then compared against every other bookmaker's prices.
+/// Games the sharp book hasn't priced are skipped. The sharp book must be present in
+/// `games_with_odds` (e.g. "Pinnacle", which the Odds API only returns for the "eu" region).
+/// With `CONSENSUS_SHARP_BOOK` every book is compared against `fair_market_prob` instead.
+pub async fn find_top_ev_bets_vs_sharp(
+    sharp_book: &str,
+    games_with_odds: &[(Game, Vec<BettingOdds>)],
+    top_n: Option<usize>,
+    max_books_per_bet: Option<usize>,
+    filter: &EvFilter,
+) -> Result<Vec<EvBetRecommendation>> {
+    let now = Utc::now();
+    let games_with_odds = games_with_odds
+        .iter()
+        .filter(|g| g.0.commence_time > now && filter.includes_game(&g.0));
+
+    let consensus = sharp_book.eq_ignore_ascii_case(CONSENSUS_SHARP_BOOK);
+    let sharp_book = Bookmaker::from_title(sharp_book);
+    let mut all_bets = Vec::new();
+    for (game, odds_list) in games_with_odds {
+        let market = if consensus {
+            odds_list.as_slice()
+        } else {
+            match odds_list
+                .iter()
+                .find(|odds| sharp_book.matches(&odds.bookmaker))
+            {
+                Some(sharp_odds) => std::slice::from_ref(sharp_odds),
+                None => continue,
+            }
+        };
+
+        // Need both sides of the moneyline to remove the vig
+        let Some((fair_home, fair_away)) = fair_market_prob(market, game) else {
+            continue;
+        };
+
+        let mut fair_probs = HashMap::new();
+        fair_probs.insert(extract_school_name(&game.home_team), fair_home);
+        fair_probs.insert(extract_school_name(&game.away_team), fair_away);
+
+        let soft_books = odds_list
+            .iter()
+            .filter(|odds| consensus || !sharp_book.matches(&odds.bookmaker));
+        let best_prices = best_price_by_book(odds_list);
+        all_bets.extend(moneyline_bets_for_game(
+            game,
+            soft_books,
+            &fair_probs,
+            &best_prices,
+            &BoostConfig::default(),
+        ));
+    }
+
+    Ok(top_positive_ev_bets(
+        all_bets,
+        top_n,
+        max_books_per_bet,
+        filter,
+    ))
+}
+
+/// Identifies the exact same bet across bookmakers: the team's school name and the
+/// spread line in half points (`None` for moneylines)
+type BetKey = (String, Option<i32>);
+
+fn moneyline_key(team: &str) -> BetKey {
+    (extract_school_name(team), None)
+}
+
+fn spread_key(team: &str, point: f64) -> BetKey {
+    (
+        extract_school_name(team),
+        Some((point * 2.0).round() as i32),
+    )
+}
+
+/// Find the highest-paying price for every moneyline and spread bet in a game
+/// across all bookmakers. Ties keep the first bookmaker listed.
+/// Bets are matched by school name, so books spelling a team differently (e.g. Kalshi's
+/// "Ohio State" and the Odds API's "Ohio State Buckeyes") still compete on price.
+pub fn best_price_by_book(odds_list: &[BettingOdds]) -> HashMap<BetKey, BestOdds<'_>> {
+    let mut best: HashMap<BetKey, Option<BestOdds>> = HashMap::new();
+
+    for bookmaker_odds in odds_list {
+        for moneyline in &bookmaker_odds.moneyline {
+            let entry = best.entry(moneyline_key(&moneyline.team)).or_default();
+            BestOdds::consider(entry, bookmaker_odds, moneyline.price);
+        }
+        for spread in &bookmaker_odds.spreads {
+            let entry = best
+                .entry(spread_key(&spread.team, spread.point))
+                .or_default();
+            BestOdds::consider(entry, bookmaker_odds, spread.price);
+        }
+    }
+
+    best.into_iter()
+        .filter_map(|(key, price)| Some((key, price?)))
+        .collect()
+}
+
+/// Price every bookmaker's moneyline for a game against model win probabilities,
+/// keyed by `extract_school_name`, also at the boosted odds where `boosts` has a boost
+fn moneyline_bets_for_game<'a>(
+    game: &Game,
+    odds_list: impl Iterator<Item = &'a BettingOdds>,
+    team_probs: &HashMap<String, f64>,
+    best_prices: &HashMap<BetKey, BestOdds>,
+    boosts: &BoostConfig,
+) -> Vec<EvBetRecommendation> {
+    let mut bets = Vec::new();
+
+    for bookmaker_odds in odds_list {
+        for moneyline in &bookmaker_odds.moneyline {
+            let team_key = extract_school_name(&moneyline.team);
+
+            if let Some(&model_prob) = team_probs.get(&team_key) {
+                let implied_prob = american_odds_to_probability(moneyline.price);
+                let fair_prob = bookmaker_odds
+                    .moneyline
+                    .iter()
+                    .find(|other| other.team != moneyline.team)
+                    .map(|other| devig_two_way(moneyline.price, other.price).0);
+                let ev = calculate_expected_value(model_prob, moneyline.price);
+                let edge = model_prob - implied_prob;
+                let best = best_prices
+                    .get(&moneyline_key(&moneyline.team))
+                    .expect("best prices cover every quoted moneyline");
+                let boost = boosts
+                    .boost_for(game, &moneyline.team, &bookmaker_odds.bookmaker)
+                    .map(|profit_boost| {
+                        AppliedBoost::new(moneyline.price, model_prob, profit_boost)
                     });
-                }
+
+                bets.push(EvBetRecommendation {
+                    home_team: game.home_team.clone(),
+                    away_team: game.away_team.clone(),
+                    team: moneyline.team.clone(),
+                    bookmaker: bookmaker_odds.bookmaker.clone(),
+                    odds: moneyline.price,
+                    model_prob,
+                    implied_prob,
+                    fair_prob,
+                    expected_value: ev,
+                    edge,
+                    best_odds: best.price,
+                    best_bookmaker: best.bookmaker.to_string(),
+                    is_best_price: moneyline.price >= best.price,
+                    plausibility_score: plausibility_score(edge),
+                    conference: conference_or_other(&moneyline.team),
+                    commence_time: Some(game.commence_time),
+                    game_id: Some(game.id.clone()),
+                    stake: None,
+                    adjustments: Vec::new(),
+                    boost,
+                });
             }
         }
     }
 
-    // Filter for positive EV only
-    all_bets.retain(|bet| bet.expected_value > 0.0);
+    bets
+}
 
-    // Sort by EV (descending)
-    all_bets.sort_by(|a, b| {
-        b.expected_value
-            .partial_cmp(&a.expected_value)
-            .unwrap_or(std::cmp::Ordering::Equal)
+/// Keep only the first `max_books` recommendations for each bet, as identified by `key`
+/// Bets must already be sorted best first; `Some(1)` leaves one book per bet.
+fn cap_books_per_bet<T, K: Hash + Eq>(bets: &mut Vec<T>, max_books: usize, key: impl Fn(&T) -> K) {
+    let mut counts: HashMap<K, usize> = HashMap::new();
+    bets.retain(|bet| {
+        let count = counts.entry(key(bet)).or_insert(0);
+        *count += 1;
+        *count <= max_books
     });
+}
+
+/// Keep the positive EV bets `filter` allows, sorted by EV (descending) and truncated to
+/// the top N if specified
+fn top_positive_ev_bets(
+    mut all_bets: Vec<EvBetRecommendation>,
+    top_n: Option<usize>,
+    max_books_per_bet: Option<usize>,
+    filter: &EvFilter,
+) -> Vec<EvBetRecommendation> {
+    // Filter for positive EV above the threshold
+    all_bets.retain(|bet| filter.keeps(bet.effective_ev(), bet.edge));
+
+    // Sort by plausibility-weighted EV (descending) so suspiciously large edges sink
+    all_bets.sort_by(EvBetRecommendation::ranking_cmp);
+
+    if let Some(max_books) = max_books_per_bet {
+        cap_books_per_bet(&mut all_bets, max_books, |bet| {
+            (
+                bet.home_team.clone(),
+                bet.away_team.clone(),
+                bet.team.clone(),
+            )
+        });
+    }
 
     // Take top N if specified, otherwise return all positive EV bets
-    Ok(match top_n {
+    match top_n {
         Some(n) => all_bets.into_iter().take(n).collect(),
         None => all_bets,
-    })
+    }
 }
 
 /// A bet recommendation with EV analysis
@@ -193,15 +862,107 @@ pub struct EvBetRecommendation {
     pub odds: i32,
     pub model_prob: f64,
     pub implied_prob: f64,
+    /// This side's de-vigged probability from the same book's two-way market, when the book
+    /// also prices the other side
+    #[serde(default)]
+    pub fair_prob: Option<f64>,
     pub expected_value: f64,
     pub edge: f64,
+    /// Best price available for this exact bet across all bookmakers
+    #[serde(default)]
+    pub best_odds: i32,
+    #[serde(default)]
+    pub best_bookmaker: String,
+    /// Whether `bookmaker` is offering the best available price
+    #[serde(default)]
+    pub is_best_price: bool,
+    /// 1.0 for moderate edges, falling to 0.0 for edges too large to be believable
+    #[serde(default = "default_plausibility_score")]
+    pub plausibility_score: f64,
+    /// Conference of the team being bet on ("Other" when unknown)
+    #[serde(default = "default_conference")]
+    pub conference: String,
+    /// Kickoff time, when known
+    #[serde(default)]
+    pub commence_time: Option<DateTime<Utc>>,
+    /// Odds API id of the game, when known
+    #[serde(default)]
+    pub game_id: Option<String>,
+    /// Amount actually wagered, when known (e.g. read back from a saved bets CSV)
+    #[serde(default)]
+    pub stake: Option<f64>,
+    /// Manual prediction adjustments applied to this game (see [`apply_adjustments`])
+    #[serde(default)]
+    pub adjustments: Vec<String>,
+    /// The book's profit boost on this bet, when there is one (see [`BoostConfig`])
+    #[serde(default)]
+    pub boost: Option<AppliedBoost>,
 }
 
 impl EvBetRecommendation {
-    /// Format the bet recommendation as a readable string
+    /// Whether the edge is small enough to trust (not likely a stale line or data error)
+    pub fn is_plausible(&self) -> bool {
+        self.plausibility_score > 0.0
+    }
+
+    /// Odds the bet actually pays: the boosted odds when boosted
+    pub fn effective_odds(&self) -> i32 {
+        self.boost.as_ref().map_or(self.odds, |boost| boost.odds)
+    }
+
+    /// EV at the odds the bet actually pays
+    pub fn effective_ev(&self) -> f64 {
+        self.boost
+            .as_ref()
+            .map_or(self.expected_value, |boost| boost.expected_value)
+    }
+
+    /// EV weighted by plausibility, used to rank recommendations
+    fn ranking_value(&self) -> f64 {
+        self.effective_ev() * self.plausibility_score
+    }
+
+    /// Best first: ranking value, then edge (both descending), then bookmaker and team so
+    /// ties come out in the same order every run
+    fn ranking_cmp(&self, other: &Self) -> Ordering {
+        other
+            .ranking_value()
+            .total_cmp(&self.ranking_value())
+            .then_with(|| other.edge.total_cmp(&self.edge))
+            .then_with(|| self.bookmaker.cmp(&other.bookmaker))
+            .then_with(|| self.team.cmp(&other.team))
+    }
+
+    /// Profit from a winning bet of `stake`, boost included
+    pub fn profit_on_win(&self, stake: f64) -> f64 {
+        profit_on_win(self.effective_odds(), stake)
+    }
+
+    /// Win probability needed for this bet to break even (the implied probability)
+    pub fn breakeven_prob(&self) -> f64 {
+        self.implied_prob
+    }
+
+    /// Expected dollar profit from a bet of `stake`, boost included
+    pub fn expected_profit(&self, stake: f64) -> f64 {
+        self.effective_ev() * stake
+    }
+
+    /// Model probability minus this book's de-vigged probability, which compares books
+    /// without each one's vig skewing the edge
+    pub fn fair_edge(&self) -> Option<f64> {
+        self.fair_prob.map(|fair_prob| self.model_prob - fair_prob)
+    }
+
+    /// Format the bet recommendation as a readable string, with dollar amounts at the default unit
     pub fn format(&self) -> String {
-        format!(
-            "{} @ {} | Bet: {} ({:+}) on {} | EV: {:+.2}% | Edge: {:+.2}% | Model: {:.1}% | Implied: {:.1}%",
+        self.format_with_unit(DEFAULT_UNIT_SIZE)
+    }
+
+    /// Format the bet recommendation with dollar amounts for a `unit` stake
+    pub fn format_with_unit(&self, unit: f64) -> String {
+        let formatted = format!(
+            "{} @ {} | Bet: {} ({:+}) on {} | EV: {:+.2}% | Edge: {:+.2}% | Model: {:.1}% | Break-even: {:.1}% | Win: ${:.2} on ${:.2} | Exp. profit: ${:.2}",
             self.away_team,
             self.home_team,
             self.team,
@@ -210,7 +971,36 @@ impl EvBetRecommendation {
             self.expected_value * 100.0,
             self.edge * 100.0,
             self.model_prob * 100.0,
-            self.implied_prob * 100.0
+            self.breakeven_prob() * 100.0,
+            self.profit_on_win(unit),
+            unit,
+            self.expected_profit(unit)
+        );
+        let formatted = match self.fair_edge() {
+            Some(fair_edge) => format!("{} | Fair edge: {:+.2}%", formatted, fair_edge * 100.0),
+            None => formatted,
+        };
+        let formatted = match &self.boost {
+            Some(boost) => format!(
+                "{} | Boosted {:+.0}%: {:+}, EV {:+.2}%",
+                formatted,
+                boost.profit_boost * 100.0,
+                boost.odds,
+                boost.expected_value * 100.0
+            ),
+            None => formatted,
+        };
+        let formatted = if self.is_plausible() {
+            formatted
+        } else {
+            format!("{} | Check: edge too large to trust", formatted)
+        };
+        let formatted = with_adjustments(formatted, &self.adjustments);
+        with_best_price(
+            formatted,
+            self.is_best_price,
+            self.best_odds,
+            &self.best_bookmaker,
         )
     }
 }
@@ -229,96 +1019,397 @@ pub struct SpreadEvBetRecommendation {
     pub implied_prob: f64,
     pub expected_value: f64,
     pub edge: f64,
+    /// Best price available for this exact team and line across all bookmakers
+    #[serde(default)]
+    pub best_odds: i32,
+    #[serde(default)]
+    pub best_bookmaker: String,
+    /// Whether `bookmaker` is offering the best available price
+    #[serde(default)]
+    pub is_best_price: bool,
+    /// Whether this is the book's main line for the team rather than an alternate line
+    #[serde(default = "default_main_line")]
+    pub is_main_line: bool,
+    /// The prediction source's own cover probability, when it lists one for this line,
+    /// to compare against the modeled `model_prob`
+    #[serde(default)]
+    pub scraped_cover_prob: Option<f64>,
+    /// Conference of the team being bet on ("Other" when unknown)
+    #[serde(default = "default_conference")]
+    pub conference: String,
+    /// Kickoff time, when known
+    #[serde(default)]
+    pub commence_time: Option<DateTime<Utc>>,
+    /// Odds API id of the game, when known
+    #[serde(default)]
+    pub game_id: Option<String>,
+    /// Amount actually wagered, when known (e.g. read back from a saved bets CSV)
+    #[serde(default)]
+    pub stake: Option<f64>,
+    /// Manual prediction adjustments applied to this game (see [`apply_adjustments`])
+    #[serde(default)]
+    pub adjustments: Vec<String>,
 }
 
 impl SpreadEvBetRecommendation {
-    /// Format the spread bet recommendation as a readable string
+    /// Best first: EV, then edge (both descending), then bookmaker, team and line so ties
+    /// come out in the same order every run
+    fn ranking_cmp(&self, other: &Self) -> Ordering {
+        other
+            .expected_value
+            .total_cmp(&self.expected_value)
+            .then_with(|| other.edge.total_cmp(&self.edge))
+            .then_with(|| self.bookmaker.cmp(&other.bookmaker))
+            .then_with(|| self.team.cmp(&other.team))
+            .then_with(|| self.spread_line.total_cmp(&other.spread_line))
+    }
+
+    /// Profit from a winning bet of `stake`
+    pub fn profit_on_win(&self, stake: f64) -> f64 {
+        profit_on_win(self.odds, stake)
+    }
+
+    /// Cover probability needed for this bet to break even (the implied probability)
+    pub fn breakeven_prob(&self) -> f64 {
+        self.implied_prob
+    }
+
+    /// Expected dollar profit from a bet of `stake`
+    pub fn expected_profit(&self, stake: f64) -> f64 {
+        self.expected_value * stake
+    }
+
+    /// Format the spread bet recommendation as a readable string, with dollar amounts at the default unit
     pub fn format(&self) -> String {
-        format!(
-            "{} @ {} | Bet: {} ({:+.1}) ({:+}) on {} | EV: {:+.2}% | Edge: {:+.2}% | Model Spread: {:+.1} | Model: {:.1}% | Implied: {:.1}%",
+        self.format_with_unit(DEFAULT_UNIT_SIZE)
+    }
+
+    /// Format the spread bet recommendation with dollar amounts for a `unit` stake
+    pub fn format_with_unit(&self, unit: f64) -> String {
+        let formatted = format!(
+            "{} @ {} | Bet: {} ({:+.1}{}) ({:+}) on {} | EV: {:+.2}% | Edge: {:+.2}% | Model Spread: {:+.1} | Model: {:.1}% | Break-even: {:.1}% | Win: ${:.2} on ${:.2} | Exp. profit: ${:.2}",
             self.away_team,
             self.home_team,
             self.team,
             self.spread_line,
+            if self.is_main_line { "" } else { " alt" },
             self.odds,
             self.bookmaker,
             self.expected_value * 100.0,
             self.edge * 100.0,
             self.model_spread,
             self.model_prob * 100.0,
-            self.implied_prob * 100.0
+            self.breakeven_prob() * 100.0,
+            self.profit_on_win(unit),
+            unit,
+            self.expected_profit(unit)
+        );
+        let formatted = match self.scraped_cover_prob {
+            Some(prob) => format!("{} | Scraped cover: {:.1}%", formatted, prob * 100.0),
+            None => formatted,
+        };
+        let formatted = with_adjustments(formatted, &self.adjustments);
+        with_best_price(
+            formatted,
+            self.is_best_price,
+            self.best_odds,
+            &self.best_bookmaker,
         )
     }
 }
 
-/// Analyze all available games and return all positive spread EV bets (or top N if specified)
-pub async fn find_top_spread_ev_bets(
-    games_with_odds: &[(Game, Vec<BettingOdds>)],
-    game_predictions: &[GamePrediction],
-    top_n: Option<usize>,
-) -> Result<Vec<SpreadEvBetRecommendation>> {
-    // Prediction model data is not live yet, so only look at bets in the future
-    let now = Utc::now();
-    let games_with_odds = games_with_odds.iter().filter(|g| g.0.commence_time > now);
-
-    // Standard deviation for college football score predictions (typically 10-14 points)
-    const STD_DEV: f64 = 12.0;
-
-    // Create a lookup map for game predictions
-    let mut prediction_map: HashMap<String, &GamePrediction> = HashMap::new();
-    for pred in game_predictions {
-        let home_key = extract_school_name(&pred.home_team);
-        let away_key = extract_school_name(&pred.away_team);
-
-        let game_key = format!("{}_{}", home_key, away_key);
-        prediction_map.insert(game_key.clone(), pred);
+/// Point out a better price at another book when the recommended book isn't the best
+fn with_best_price(
+    formatted: String,
+    is_best_price: bool,
+    best_odds: i32,
+    best_book: &str,
+) -> String {
+    if is_best_price || best_book.is_empty() {
+        formatted
+    } else {
+        format!("{} | Best: {:+} on {}", formatted, best_odds, best_book)
+    }
+}
 
-        // Also store reverse key
-        let reverse_key = format!("{}_{}", away_key, home_key);
-        prediction_map.insert(reverse_key, pred);
+/// List the manual adjustments behind a recommendation, if any
+fn with_adjustments(formatted: String, adjustments: &[String]) -> String {
+    if adjustments.is_empty() {
+        formatted
+    } else {
+        format!("{} | Adjusted: {}", formatted, adjustments.join("; "))
     }
+}
 
-    // Calculate EV for all spread bets
-    let mut all_bets = Vec::new();
+/// A manual correction to one team's predictions, e.g. for an injury or the weather
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PredictionAdjustment {
+    pub team: String,
+    /// Points added to the team's projected margin (negative when a starter is out)
+    #[serde(default)]
+    pub spread_delta: f64,
+    /// Added to the team's win probability, as a fraction
+    #[serde(default)]
+    pub prob_delta: f64,
+    /// Why the adjustment was made, e.g. "QB out"
+    #[serde(default)]
+    pub note: String,
+}
 
-    for (game, odds_list) in games_with_odds {
-        // Extract school names from full team names
-        let home_key = extract_school_name(&game.home_team);
-        let away_key = extract_school_name(&game.away_team);
+impl PredictionAdjustment {
+    /// Short description recorded on recommendations, e.g. "Iowa: QB out (-3.0 pts, -5.0%)"
+    pub fn describe(&self) -> String {
+        format!(
+            "{}: {} ({:+.1} pts, {:+.1}%)",
+            self.team,
+            self.note,
+            self.spread_delta,
+            self.prob_delta * 100.0
+        )
+    }
+}
 
-        // Try to find matching prediction
-        let game_key = format!("{}_{}", home_key, away_key);
-        let game_pred = match prediction_map.get(&game_key) {
-            Some(pred) => pred,
-            None => {
+/// Descriptions of the adjustments applied to each game, keyed by school keys in both
+/// home/away orders
+pub type AdjustmentNotes = HashMap<(String, String), Vec<String>>;
+
+/// Apply manual adjustments to the matching predictions before EV is computed
+/// Teams are matched by school key. Deltas are from the adjusted team's perspective, so
+/// the home-perspective spread moves the opposite way when it's the away team; win
+/// probabilities are clamped to [0, 1] and the opponent gets the complement.
+pub fn apply_adjustments(
+    predictions: &mut [GamePrediction],
+    adjustments: &[PredictionAdjustment],
+) -> AdjustmentNotes {
+    let mut notes = AdjustmentNotes::new();
+
+    for prediction in predictions.iter_mut() {
+        let home_key = extract_school_name(&prediction.home_team);
+        let away_key = extract_school_name(&prediction.away_team);
+
+        for adjustment in adjustments {
+            let team_key = extract_school_name(&adjustment.team);
+            if team_key == home_key {
+                prediction.spread += adjustment.spread_delta;
+                prediction.home_win_prob =
+                    (prediction.home_win_prob + adjustment.prob_delta).clamp(0.0, 1.0);
+                prediction.away_win_prob = 1.0 - prediction.home_win_prob;
+            } else if team_key == away_key {
+                prediction.spread -= adjustment.spread_delta;
+                prediction.away_win_prob =
+                    (prediction.away_win_prob + adjustment.prob_delta).clamp(0.0, 1.0);
+                prediction.home_win_prob = 1.0 - prediction.away_win_prob;
+            } else {
                 continue;
             }
-        };
 
-        // The prediction tracker spread is positive if the home team is predicted to win
-        let model_spread = game_pred.spread;
+            let description = adjustment.describe();
+            notes
+                .entry((home_key.clone(), away_key.clone()))
+                .or_default()
+                .push(description.clone());
+            notes
+                .entry((away_key.clone(), home_key.clone()))
+                .or_default()
+                .push(description);
+        }
+    }
 
-        // Analyze each bookmaker's spread odds
-        for bookmaker_odds in odds_list {
-            for spread_odds in &bookmaker_odds.spreads {
+    notes
+}
+
+/// Adjustment descriptions for a game, in either home/away order
+pub fn adjustment_notes_for(
+    notes: &AdjustmentNotes,
+    home_team: &str,
+    away_team: &str,
+) -> Vec<String> {
+    notes
+        .get(&(
+            extract_school_name(home_team),
+            extract_school_name(away_team),
+        ))
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// A book's profit boost on one team's moneyline, e.g. 50% more winnings on Iowa at FanDuel
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OddsBoost {
+    pub team: String,
+    pub bookmaker: String,
+    /// The other team, to limit the boost to one game; any of `team`'s games when empty
+    #[serde(default)]
+    pub opponent: Option<String>,
+    /// Extra share of the winnings, as a fraction (0.5 for a 50% boost)
+    pub profit_boost: f64,
+}
+
+impl OddsBoost {
+    /// Whether this boost covers `team`'s moneyline at `bookmaker` in `game`
+    fn applies_to(&self, game: &Game, team: &str, bookmaker: &str) -> bool {
+        let team_key = extract_school_name(team);
+        let opponent_key = if team_key == extract_school_name(&game.home_team) {
+            extract_school_name(&game.away_team)
+        } else {
+            extract_school_name(&game.home_team)
+        };
+
+        extract_school_name(&self.team) == team_key
+            && Bookmaker::from_title(&self.bookmaker).matches(bookmaker)
+            && self
+                .opponent
+                .as_deref()
+                .filter(|opponent| !opponent.is_empty())
+                .is_none_or(|opponent| extract_school_name(opponent) == opponent_key)
+    }
+}
+
+/// Profit boosts and promos to price moneylines with, on top of the posted odds
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BoostConfig {
+    pub boosts: Vec<OddsBoost>,
+}
+
+impl BoostConfig {
+    pub fn new(boosts: Vec<OddsBoost>) -> Self {
+        Self { boosts }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.boosts.is_empty()
+    }
+
+    /// The boost on `team`'s moneyline at `bookmaker` in `game`, if any
+    /// When several match, the biggest one is used.
+    pub fn boost_for(&self, game: &Game, team: &str, bookmaker: &str) -> Option<f64> {
+        self.boosts
+            .iter()
+            .filter(|boost| boost.applies_to(game, team, bookmaker))
+            .map(|boost| boost.profit_boost)
+            .max_by(f64::total_cmp)
+    }
+}
+
+/// A profit boost as priced into a recommendation
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AppliedBoost {
+    /// Extra share of the winnings, as a fraction
+    pub profit_boost: f64,
+    /// The boosted American odds
+    pub odds: i32,
+    /// EV at the boosted odds; the bet's `expected_value` is at the posted odds
+    pub expected_value: f64,
+}
+
+impl AppliedBoost {
+    /// Boost posted `odds` by `profit_boost` and price them at `model_prob`
+    pub fn new(odds: i32, model_prob: f64, profit_boost: f64) -> Self {
+        let odds = boost_odds(odds, profit_boost);
+        Self {
+            profit_boost,
+            odds,
+            expected_value: calculate_expected_value(model_prob, odds),
+        }
+    }
+}
+
+/// How `find_top_spread_ev_bets` prices the chance a spread bet covers
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SpreadModel {
+    /// Std dev of each game's final margin for the normal model
+    pub margin_std_dev: MarginStdDev,
+    /// Use the prediction source's own cover probability on the line it lists one for,
+    /// falling back to the normal model on every other line
+    pub scraped_cover: bool,
+}
+
+/// Analyze all available games and return all positive spread EV bets (or top N if specified)
+/// `max_books_per_bet` caps how many bookmakers are listed for the same team and line.
+/// Bets are screened by `filter` before the top N are taken. Cover probabilities come from
+/// `model` (see [`SpreadModel`]).
+pub async fn find_top_spread_ev_bets(
+    games_with_odds: &[(Game, Vec<BettingOdds>)],
+    game_predictions: &[GamePrediction],
+    top_n: Option<usize>,
+    max_books_per_bet: Option<usize>,
+    filter: &EvFilter,
+    model: SpreadModel,
+) -> Result<Vec<SpreadEvBetRecommendation>> {
+    // Prediction model data is not live yet, so only look at bets in the future
+    let now = Utc::now();
+    let games_with_odds = games_with_odds
+        .iter()
+        .filter(|g| g.0.commence_time > now && filter.includes_game(&g.0));
+
+    // Create a lookup map for game predictions
+    let mut prediction_map: HashMap<String, &GamePrediction> = HashMap::new();
+    for pred in game_predictions {
+        let home_key = extract_school_name(&pred.home_team);
+        let away_key = extract_school_name(&pred.away_team);
+
+        let game_key = format!("{}_{}", home_key, away_key);
+        prediction_map.insert(game_key.clone(), pred);
+
+        // Also store reverse key
+        let reverse_key = format!("{}_{}", away_key, home_key);
+        prediction_map.insert(reverse_key, pred);
+    }
+
+    // Calculate EV for all spread bets
+    let mut all_bets = Vec::new();
+
+    for (game, odds_list) in games_with_odds {
+        // Extract school names from full team names
+        let home_key = extract_school_name(&game.home_team);
+        let away_key = extract_school_name(&game.away_team);
+
+        // Try to find matching prediction
+        let game_key = format!("{}_{}", home_key, away_key);
+        let game_pred = match prediction_map.get(&game_key) {
+            Some(pred) => pred,
+            None => {
+                continue;
+            }
+        };
+
+        // The prediction tracker spread is positive if the home team is predicted to win
+        let model_spread = game_pred.spread;
+        // Predictions don't include a projected total
+        let std_dev = model.margin_std_dev.std_dev(model_spread, None);
+
+        // Analyze each bookmaker's spread odds
+        let best_prices = best_price_by_book(odds_list);
+        for bookmaker_odds in odds_list {
+            let main_lines = main_spread_lines(bookmaker_odds);
+            for spread_odds in &bookmaker_odds.spreads {
                 let team_key = extract_school_name(&spread_odds.team);
                 let is_home_team = team_key == home_key;
 
                 // The model_spread is from the home team's perspective (positive = home wins by that much)
                 // The spread_odds.point is from the team's perspective in the bet and using normal betting lines
                 // such as negative = spread_odds.team wins
-                let cover_prob = if is_home_team {
+                let modeled_prob = if is_home_team {
                     // Betting on home team: use spread as-is
-                    calculate_spread_cover_probability(model_spread, spread_odds.point, STD_DEV)
+                    calculate_spread_cover_probability(model_spread, spread_odds.point, std_dev)
                 } else {
                     // Betting on away team: we need the OPPOSITE condition
                     // If away has +12.5, they cover when home_margin < 12.5
-                    calculate_spread_cover_probability(-model_spread, spread_odds.point, STD_DEV)
+                    calculate_spread_cover_probability(-model_spread, spread_odds.point, std_dev)
+                };
+                let scraped_cover_prob =
+                    game_pred.scraped_cover_prob(is_home_team, spread_odds.point);
+                let cover_prob = match scraped_cover_prob {
+                    Some(scraped) if model.scraped_cover => scraped,
+                    _ => modeled_prob,
                 };
 
                 let implied_prob = american_odds_to_probability(spread_odds.price);
                 let ev = calculate_expected_value(cover_prob, spread_odds.price);
                 let edge = cover_prob - implied_prob;
+                let best = best_prices
+                    .get(&spread_key(&spread_odds.team, spread_odds.point))
+                    .expect("best prices cover every quoted spread");
 
                 all_bets.push(SpreadEvBetRecommendation {
                     home_team: game.home_team.clone(),
@@ -332,20 +1423,38 @@ pub async fn find_top_spread_ev_bets(
                     implied_prob,
                     expected_value: ev,
                     edge,
+                    best_odds: best.price,
+                    best_bookmaker: best.bookmaker.to_string(),
+                    is_best_price: spread_odds.price >= best.price,
+                    is_main_line: main_lines.get(spread_odds.team.as_str())
+                        == Some(&spread_odds.point),
+                    scraped_cover_prob,
+                    conference: conference_or_other(&spread_odds.team),
+                    commence_time: Some(game.commence_time),
+                    game_id: Some(game.id.clone()),
+                    stake: None,
+                    adjustments: Vec::new(),
                 });
             }
         }
     }
 
-    // Filter for positive EV only
-    all_bets.retain(|bet| bet.expected_value > 0.0);
+    // Filter for positive EV above the threshold
+    all_bets.retain(|bet| filter.keeps(bet.expected_value, bet.edge));
 
     // Sort by EV (descending)
-    all_bets.sort_by(|a, b| {
-        b.expected_value
-            .partial_cmp(&a.expected_value)
-            .unwrap_or(std::cmp::Ordering::Equal)
-    });
+    all_bets.sort_by(SpreadEvBetRecommendation::ranking_cmp);
+
+    if let Some(max_books) = max_books_per_bet {
+        cap_books_per_bet(&mut all_bets, max_books, |bet| {
+            (
+                bet.home_team.clone(),
+                bet.away_team.clone(),
+                bet.team.clone(),
+                (bet.spread_line * 2.0).round() as i32,
+            )
+        });
+    }
 
     // Take top N if specified, otherwise return all positive EV bets
     Ok(match top_n {
@@ -354,13 +1463,49 @@ pub async fn find_top_spread_ev_bets(
     })
 }
 
+/// Keep only the best-EV spread line for each team in each game
+/// Books that offer alternate lines produce one bet per line; this collapses them to the
+/// single line worth betting. Bets must already be sorted best first.
+pub fn best_line_per_team(
+    mut bets: Vec<SpreadEvBetRecommendation>,
+) -> Vec<SpreadEvBetRecommendation> {
+    cap_books_per_bet(&mut bets, 1, |bet| {
+        (
+            bet.home_team.clone(),
+            bet.away_team.clone(),
+            bet.team.clone(),
+        )
+    });
+    bets
+}
+
+/// Stake assumed for a graded bet whose recommendation has no recorded stake
+pub const FLAT_STAKE: f64 = 1.0;
+
+fn default_stake() -> f64 {
+    FLAT_STAKE
+}
+
 /// Result of comparing a moneyline bet against actual game outcome
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BetResult {
     pub bet: EvBetRecommendation,
     pub game_result: Option<GameResult>,
     pub bet_won: Option<bool>,
+    /// Profit on `stake` if the bet won, 0 if it lost
     pub actual_payout: Option<f64>,
+    /// Amount wagered: the bet's recorded stake, or `FLAT_STAKE`
+    #[serde(default = "default_stake")]
+    pub stake: f64,
+    /// The bookmaker's last price on the team before kickoff, if a snapshot had it
+    #[serde(default)]
+    pub closing_odds: Option<i32>,
+    /// Whether the bet got a better price than the close (`None` without closing odds)
+    #[serde(default)]
+    pub beat_close: Option<bool>,
+    /// Market consensus fair win probability for the team at the close
+    #[serde(default)]
+    pub closing_fair_prob: Option<f64>,
 }
 
 /// Result of comparing a spread bet against actual game outcome
@@ -369,10 +1514,35 @@ pub struct SpreadBetResult {
     pub bet: SpreadEvBetRecommendation,
     pub game_result: Option<GameResult>,
     pub bet_won: Option<bool>,
+    /// Profit on `stake` if the bet won, 0 if it lost
     pub actual_payout: Option<f64>,
+    /// Amount wagered: the bet's recorded stake, or `FLAT_STAKE`
+    #[serde(default = "default_stake")]
+    pub stake: f64,
+    /// The bookmaker's last spread on the team before kickoff, if a snapshot had it
+    #[serde(default)]
+    pub closing_spread: Option<f64>,
+    #[serde(default)]
+    pub closing_odds: Option<i32>,
+    /// Whether the bet got more points, or the same points at a better price, than the
+    /// close (`None` without closing odds)
+    #[serde(default)]
+    pub beat_close: Option<bool>,
 }
 
 impl BetResult {
+    /// Closing line value: how much likelier the team was to win at the close, by the market's
+    /// fair probability, than the bet's price implied
+    pub fn clv(&self) -> Option<f64> {
+        self.closing_fair_prob
+            .map(|fair_prob| fair_prob - self.bet.implied_prob)
+    }
+
+    /// Net profit on the bet (negative for a loss), or `None` if it isn't graded
+    pub fn profit(&self) -> Option<f64> {
+        graded_profit(self.bet_won, self.actual_payout, self.stake)
+    }
+
     pub fn format(&self) -> String {
         match (&self.game_result, &self.bet_won, &self.actual_payout) {
             (Some(game), Some(won), Some(payout)) => {
@@ -382,24 +1552,39 @@ impl BetResult {
                 let payout_str = if *won {
                     format!("+${:.2}", payout)
                 } else {
-                    "-$1.00".to_string()
+                    format!("-${:.2}", self.stake)
                 };
 
                 format!(
-                    "{} | {} {} | Score: {}-{}",
-                    self.bet.format(),
+                    "{} | {} {} | Score: {}-{}{}{}",
+                    self.bet.format_with_unit(self.stake),
                     result_str,
                     payout_str,
                     away_score,
-                    home_score
+                    home_score,
+                    format_closing(
+                        self.closing_odds.map(|odds| format!("{:+}", odds)),
+                        self.beat_close
+                    ),
+                    self.clv()
+                        .map(|clv| format!(" | CLV: {:+.1}%", clv * 100.0))
+                        .unwrap_or_default()
                 )
             }
-            _ => format!("{} | Game not found or incomplete", self.bet.format()),
+            _ => format!(
+                "{} | Game not found or incomplete",
+                self.bet.format_with_unit(self.stake)
+            ),
         }
     }
 }
 
 impl SpreadBetResult {
+    /// Net profit on the bet (negative for a loss), or `None` if it isn't graded
+    pub fn profit(&self) -> Option<f64> {
+        graded_profit(self.bet_won, self.actual_payout, self.stake)
+    }
+
     pub fn format(&self) -> String {
         match (&self.game_result, &self.bet_won, &self.actual_payout) {
             (Some(game), Some(won), Some(payout)) => {
@@ -410,176 +1595,2059 @@ impl SpreadBetResult {
                 let payout_str = if *won {
                     format!("+${:.2}", payout)
                 } else {
-                    "-$1.00".to_string()
+                    format!("-${:.2}", self.stake)
                 };
 
+                let closing = match (self.closing_spread, self.closing_odds) {
+                    (Some(spread), Some(odds)) => Some(format!("{:+.1} ({:+})", spread, odds)),
+                    _ => None,
+                };
                 format!(
-                    "{} | {} {} | Score: {}-{} (margin: {:+})",
-                    self.bet.format(),
+                    "{} | {} {} | Score: {}-{} (margin: {:+}){}",
+                    self.bet.format_with_unit(self.stake),
                     result_str,
                     payout_str,
                     away_score,
                     home_score,
-                    margin
+                    margin,
+                    format_closing(closing, self.beat_close)
                 )
             }
-            _ => format!("{} | Game not found or incomplete", self.bet.format()),
+            _ => format!(
+                "{} | Game not found or incomplete",
+                self.bet.format_with_unit(self.stake)
+            ),
         }
     }
 }
 
+fn graded_profit(bet_won: Option<bool>, payout: Option<f64>, stake: f64) -> Option<f64> {
+    match (bet_won?, payout?) {
+        (true, payout) => Some(payout),
+        (false, _) => Some(-stake),
+    }
+}
+
+/// Win/loss record and stake-weighted return for a set of graded bets
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct GradingSummary {
+    pub wins: usize,
+    pub losses: usize,
+    pub total_staked: f64,
+    pub net_profit: f64,
+}
+
+impl GradingSummary {
+    /// Summarize graded bets given as `(stake, profit)` pairs
+    pub fn from_graded(graded: impl IntoIterator<Item = (f64, f64)>) -> Self {
+        graded
+            .into_iter()
+            .fold(Self::default(), |mut summary, (stake, profit)| {
+                if profit > 0.0 {
+                    summary.wins += 1;
+                } else {
+                    summary.losses += 1;
+                }
+                summary.total_staked += stake;
+                summary.net_profit += profit;
+                summary
+            })
+    }
+
+    pub fn bets(&self) -> usize {
+        self.wins + self.losses
+    }
+
+    /// Net profit per dollar staked, so larger stakes count for more
+    pub fn roi(&self) -> f64 {
+        if self.total_staked > 0.0 {
+            self.net_profit / self.total_staked
+        } else {
+            0.0
+        }
+    }
+}
+
+/// " | Close: ..." suffix for a graded bet, empty without closing data
+fn format_closing(closing: Option<String>, beat_close: Option<bool>) -> String {
+    match (closing, beat_close) {
+        (Some(closing), Some(beat)) => format!(
+            " | Close: {} ({})",
+            closing,
+            if beat { "beat" } else { "missed" }
+        ),
+        _ => String::new(),
+    }
+}
+
 /// Compare moneyline EV bet recommendations against actual game results
+/// Only completed games are graded; bets on games in progress are left ungraded (see
+/// `grade_live_moneyline_bets` for those). Closing odds are left empty; fill them in with
+/// `add_closing_lines`.
 pub fn compare_ev_bets_to_results(
     bets: &[EvBetRecommendation],
     game_results: &[GameResult],
 ) -> Vec<BetResult> {
-    // Create a lookup map for game results by team names
-    let mut results_map: HashMap<String, &GameResult> = HashMap::new();
-    for result in game_results {
-        let home_key = extract_school_name(&result.home_team);
-        let away_key = extract_school_name(&result.away_team);
-
-        let game_key = format!("{}_{}", home_key, away_key);
-        results_map.insert(game_key.clone(), result);
-
-        let reverse_key = format!("{}_{}", away_key, home_key);
-        results_map.insert(reverse_key, result);
-    }
+    let results = ResultMatcher::new(game_results);
 
     bets.iter()
         .map(|bet| {
-            let home_key = extract_school_name(&bet.home_team);
-            let away_key = extract_school_name(&bet.away_team);
-            let game_key = format!("{}_{}", home_key, away_key);
-
-            let game_result = results_map.get(&game_key).copied();
-
-            let (bet_won, actual_payout) = if let Some(result) = game_result {
-                if let (Some(home_points), Some(away_points)) =
-                    (result.home_points, result.away_points)
-                {
-                    let bet_team_key = extract_school_name(&bet.team);
-                    let home_team_key = extract_school_name(&result.home_team);
-
-                    let bet_won = if bet_team_key == home_team_key {
-                        home_points > away_points
-                    } else {
-                        away_points > home_points
-                    };
+            let game_result = results.find(&bet.home_team, &bet.away_team, bet.commence_time);
+            let stake = bet.stake.unwrap_or(FLAT_STAKE);
+
+            let (bet_won, actual_payout) =
+                if let Some(result) = game_result.filter(|result| result.completed) {
+                    if let (Some(home_points), Some(away_points)) =
+                        (result.home_points, result.away_points)
+                    {
+                        let bet_team_key = extract_school_name(&bet.team);
+                        let home_team_key = extract_school_name(&result.home_team);
+
+                        let bet_won = if bet_team_key == home_team_key {
+                            home_points > away_points
+                        } else {
+                            away_points > home_points
+                        };
 
-                    let payout = if bet_won {
-                        if bet.odds > 0 {
-                            (bet.odds as f64) / 100.0
+                        let payout = if bet_won {
+                            bet.profit_on_win(stake)
                         } else {
-                            100.0 / (-bet.odds as f64)
-                        }
-                    } else {
-                        0.0
-                    };
+                            0.0
+                        };
 
-                    (Some(bet_won), Some(payout))
+                        (Some(bet_won), Some(payout))
+                    } else {
+                        (None, None)
+                    }
                 } else {
                     (None, None)
-                }
-            } else {
-                (None, None)
-            };
+                };
 
             BetResult {
                 bet: bet.clone(),
                 game_result: game_result.cloned(),
                 bet_won,
                 actual_payout,
+                stake,
+                closing_odds: None,
+                beat_close: None,
+                closing_fair_prob: None,
             }
         })
         .collect()
 }
 
+/// Game results indexed by [`GameMatcher`], so a rematch (or last week's game between the
+/// same teams) isn't mistaken for the game a bet was on
+pub(crate) struct ResultMatcher<'a> {
+    matcher: GameMatcher,
+    results: &'a [GameResult],
+}
+
+impl<'a> ResultMatcher<'a> {
+    pub(crate) fn new(results: &'a [GameResult]) -> Self {
+        // Results without a readable kickoff are indexed at the epoch, out of every bet's
+        // window, so only the team-name fallback can find them
+        let matcher = GameMatcher::new(results.iter().map(|result| {
+            (
+                result.home_team.as_str(),
+                result.away_team.as_str(),
+                result.kickoff().unwrap_or_default(),
+            )
+        }));
+        Self { matcher, results }
+    }
+
+    /// The result of the game between these teams kicking off around `kickoff`
+    /// When either side has no kickoff, the teams alone have to pick out a single game.
+    pub(crate) fn find(
+        &self,
+        home_team: &str,
+        away_team: &str,
+        kickoff: Option<DateTime<Utc>>,
+    ) -> Option<&'a GameResult> {
+        if let Some(game_match) =
+            kickoff.and_then(|kickoff| self.matcher.find(home_team, away_team, kickoff))
+        {
+            return Some(&self.results[game_match.index]);
+        }
+        let result = &self.results[self.matcher.find_only(home_team, away_team)?.index];
+        (kickoff.is_none() || result.kickoff().is_none()).then_some(result)
+    }
+}
+
 /// Compare spread EV bet recommendations against actual game results
+/// Only completed games are graded, as with `compare_ev_bets_to_results`.
 pub fn compare_spread_ev_bets_to_results(
     bets: &[SpreadEvBetRecommendation],
     game_results: &[GameResult],
 ) -> Vec<SpreadBetResult> {
-    // Create a lookup map for game results by team names
-    let mut results_map: HashMap<String, &GameResult> = HashMap::new();
-    for result in game_results {
-        let home_key = extract_school_name(&result.home_team);
-        let away_key = extract_school_name(&result.away_team);
-
-        let game_key = format!("{}_{}", home_key, away_key);
-        results_map.insert(game_key.clone(), result);
-
-        let reverse_key = format!("{}_{}", away_key, home_key);
-        results_map.insert(reverse_key, result);
-    }
+    let results = ResultMatcher::new(game_results);
 
     bets.iter()
         .map(|bet| {
-            let home_key = extract_school_name(&bet.home_team);
-            let away_key = extract_school_name(&bet.away_team);
-            let game_key = format!("{}_{}", home_key, away_key);
-
-            let game_result = results_map.get(&game_key).copied();
-
-            let (bet_won, actual_payout) = if let Some(result) = game_result {
-                if let (Some(home_points), Some(away_points)) =
-                    (result.home_points, result.away_points)
-                {
-                    let bet_team_key = extract_school_name(&bet.team);
-                    let home_team_key = extract_school_name(&result.home_team);
-                    let actual_margin = home_points - away_points;
-
-                    // Spread betting logic:
-                    // - If betting on home team: home needs to win by more than the spread
-                    //   e.g., Home -7.5 means home must win by > 7.5 points
-                    //   e.g., Home +3.5 means home can lose by < 3.5 points (or win)
-                    // - If betting on away team: away needs to beat the spread
-                    //   e.g., Away +7.5 means away can lose by < 7.5 points (or win)
-                    //   e.g., Away -3.5 means away must win by > 3.5 points
-                    //
-                    // In spread betting, the line is always from the perspective of the team being bet on.
-                    // The bet wins if: (team_score + spread) > opponent_score
-                    // Which is equivalent to: (team_score - opponent_score) > -spread
-
-                    let bet_won = if bet_team_key == home_team_key {
-                        // Betting on home team
-                        // Home covers if: home_points + (-spread) > away_points
-                        // Which is: (home_points - away_points) > -spread
-                        // Or: actual_margin > -spread_line
-                        (actual_margin as f64) > -bet.spread_line
-                    } else {
-                        // Betting on away team
-                        // Away covers if: away_points + (-spread) > home_points
-                        // Which is: (away_points - home_points) > -spread
-                        // Or: -actual_margin > -spread_line
-                        // Or: actual_margin < spread_line
-                        (actual_margin as f64) < bet.spread_line
-                    };
-
-                    let payout = if bet_won {
-                        if bet.odds > 0 {
-                            (bet.odds as f64) / 100.0
+            let game_result = results.find(&bet.home_team, &bet.away_team, bet.commence_time);
+            let stake = bet.stake.unwrap_or(FLAT_STAKE);
+
+            let (bet_won, actual_payout) =
+                if let Some(result) = game_result.filter(|result| result.completed) {
+                    if let (Some(home_points), Some(away_points)) =
+                        (result.home_points, result.away_points)
+                    {
+                        let bet_team_key = extract_school_name(&bet.team);
+                        let home_team_key = extract_school_name(&result.home_team);
+                        let actual_margin = home_points - away_points;
+
+                        // Spread betting logic:
+                        // - If betting on home team: home needs to win by more than the spread
+                        //   e.g., Home -7.5 means home must win by > 7.5 points
+                        //   e.g., Home +3.5 means home can lose by < 3.5 points (or win)
+                        // - If betting on away team: away needs to beat the spread
+                        //   e.g., Away +7.5 means away can lose by < 7.5 points (or win)
+                        //   e.g., Away -3.5 means away must win by > 3.5 points
+                        //
+                        // In spread betting, the line is always from the perspective of the team being bet on.
+                        // The bet wins if: (team_score + spread) > opponent_score
+                        // Which is equivalent to: (team_score - opponent_score) > -spread
+
+                        let bet_won = if bet_team_key == home_team_key {
+                            // Betting on home team
+                            // Home covers if: home_points + (-spread) > away_points
+                            // Which is: (home_points - away_points) > -spread
+                            // Or: actual_margin > -spread_line
+                            (actual_margin as f64) > -bet.spread_line
                         } else {
-                            100.0 / (-bet.odds as f64)
-                        }
-                    } else {
-                        0.0
-                    };
+                            // Betting on away team
+                            // Away covers if: away_points + (-spread) > home_points
+                            // Which is: (away_points - home_points) > -spread
+                            // Or: -actual_margin > -spread_line
+                            // Or: actual_margin < spread_line
+                            (actual_margin as f64) < bet.spread_line
+                        };
+
+                        let payout = if bet_won {
+                            bet.profit_on_win(stake)
+                        } else {
+                            0.0
+                        };
 
-                    (Some(bet_won), Some(payout))
+                        (Some(bet_won), Some(payout))
+                    } else {
+                        (None, None)
+                    }
                 } else {
                     (None, None)
-                }
-            } else {
-                (None, None)
-            };
+                };
 
             SpreadBetResult {
                 bet: bet.clone(),
                 game_result: game_result.cloned(),
                 bet_won,
                 actual_payout,
+                stake,
+                closing_spread: None,
+                closing_odds: None,
+                beat_close: None,
             }
         })
         .collect()
 }
+
+/// Closing odds keyed by school keys in both home/away orders
+fn closing_odds_by_game(
+    closing_lines: &[(Game, Vec<BettingOdds>)],
+) -> HashMap<(String, String), &[BettingOdds]> {
+    let mut map = HashMap::new();
+    for (game, odds_list) in closing_lines {
+        let home_key = extract_school_name(&game.home_team);
+        let away_key = extract_school_name(&game.away_team);
+        map.insert((home_key.clone(), away_key.clone()), odds_list.as_slice());
+        map.insert((away_key, home_key), odds_list.as_slice());
+    }
+    map
+}
+
+/// The closing odds from the same bookmaker a bet was placed at
+fn closing_book_odds<'a>(
+    closing: &HashMap<(String, String), &'a [BettingOdds]>,
+    home_team: &str,
+    away_team: &str,
+    bookmaker: &str,
+) -> Option<&'a BettingOdds> {
+    let key = (
+        extract_school_name(home_team),
+        extract_school_name(away_team),
+    );
+    let book = Bookmaker::from_title(bookmaker);
+    closing.get(&key)?.iter().find(|odds| odds.book() == book)
+}
+
+/// Whether `odds` pays better than `closing_odds`
+fn beat_closing_price(odds: i32, closing_odds: i32) -> bool {
+    american_odds_to_probability(odds) < american_odds_to_probability(closing_odds)
+}
+
+/// Fill in each graded moneyline bet's closing odds and whether it beat them
+/// `closing_lines` should be the last odds snapshot taken before each game's kickoff
+/// (see `backtest::closing_lines`). The bet is compared to its own bookmaker's close;
+/// bets whose book or game isn't in the snapshot keep `None`. The market's fair closing
+/// probability (`fair_market_prob` over every book) is filled in for CLV.
+pub fn add_closing_lines(results: &mut [BetResult], closing_lines: &[(Game, Vec<BettingOdds>)]) {
+    let closing = closing_odds_by_game(closing_lines);
+    let mut fair_probs: HashMap<(String, String), HashMap<String, f64>> = HashMap::new();
+    for (game, odds_list) in closing_lines {
+        let Some((home_prob, away_prob)) = fair_market_prob(odds_list, game) else {
+            continue;
+        };
+        let home_key = extract_school_name(&game.home_team);
+        let away_key = extract_school_name(&game.away_team);
+        let probs = HashMap::from([(home_key.clone(), home_prob), (away_key.clone(), away_prob)]);
+        fair_probs.insert((home_key.clone(), away_key.clone()), probs.clone());
+        fair_probs.insert((away_key, home_key), probs);
+    }
+
+    for result in results {
+        let bet = &result.bet;
+        let team_key = extract_school_name(&bet.team);
+        let closing_odds =
+            closing_book_odds(&closing, &bet.home_team, &bet.away_team, &bet.bookmaker)
+                .and_then(|odds| {
+                    odds.moneyline
+                        .iter()
+                        .find(|moneyline| extract_school_name(&moneyline.team) == team_key)
+                })
+                .map(|moneyline| moneyline.price);
+
+        let game_key = (
+            extract_school_name(&bet.home_team),
+            extract_school_name(&bet.away_team),
+        );
+        result.closing_fair_prob = fair_probs
+            .get(&game_key)
+            .and_then(|probs| probs.get(&team_key))
+            .copied();
+        result.beat_close = closing_odds.map(|closing| beat_closing_price(bet.odds, closing));
+        result.closing_odds = closing_odds;
+    }
+}
+
+/// Fill in each graded spread bet's closing line and whether it beat it
+/// If the book offered several lines on the team at the close, the bet's own line is used
+/// when available, otherwise the first (main) one.
+pub fn add_spread_closing_lines(
+    results: &mut [SpreadBetResult],
+    closing_lines: &[(Game, Vec<BettingOdds>)],
+) {
+    let closing = closing_odds_by_game(closing_lines);
+
+    for result in results {
+        let bet = &result.bet;
+        let team_key = extract_school_name(&bet.team);
+        let team_spreads: Vec<_> =
+            closing_book_odds(&closing, &bet.home_team, &bet.away_team, &bet.bookmaker)
+                .map(|odds| {
+                    odds.spreads
+                        .iter()
+                        .filter(|spread| extract_school_name(&spread.team) == team_key)
+                        .collect()
+                })
+                .unwrap_or_default();
+        let closing_spread = team_spreads
+            .iter()
+            .find(|spread| spread.point == bet.spread_line)
+            .or(team_spreads.first());
+
+        result.beat_close = closing_spread.map(|spread| {
+            if spread.point == bet.spread_line {
+                beat_closing_price(bet.odds, spread.price)
+            } else {
+                bet.spread_line > spread.point
+            }
+        });
+        result.closing_spread = closing_spread.map(|spread| spread.point);
+        result.closing_odds = closing_spread.map(|spread| spread.price);
+    }
+}
+
+/// Default largest gap between moneyline- and spread-implied win probabilities before a
+/// book's lines on a game are flagged
+pub const DEFAULT_MAX_PROB_GAP: f64 = 0.15;
+
+/// Why a bookmaker's lines on a game look wrong
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AnomalyKind {
+    /// The moneyline favorite is the spread underdog
+    FavoriteMismatch,
+    /// The moneyline and spread imply win probabilities too far apart
+    ProbabilityGap,
+}
+
+/// A bookmaker whose moneyline and spread on a game disagree
+/// Usually a parse or feed error, occasionally a real mispricing; either way worth a look
+/// before betting the game.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataAnomaly {
+    pub game_id: String,
+    pub home_team: String,
+    pub away_team: String,
+    pub bookmaker: String,
+    pub kind: AnomalyKind,
+    /// Main spread line on the home team
+    pub home_spread: f64,
+    /// De-vigged home win probability from the moneyline
+    pub moneyline_home_prob: f64,
+    /// Home win probability implied by the spread under the spread model
+    pub spread_home_prob: f64,
+}
+
+impl DataAnomaly {
+    /// Format the anomaly as a readable string
+    pub fn format(&self) -> String {
+        format!(
+            "{} @ {} | {} | {:?} | Spread: {:+.1} ({:.1}% home) | ML: {:.1}% home",
+            self.away_team,
+            self.home_team,
+            self.bookmaker,
+            self.kind,
+            self.home_spread,
+            self.spread_home_prob * 100.0,
+            self.moneyline_home_prob * 100.0
+        )
+    }
+}
+
+/// Check each bookmaker's moneyline against its spread on the same game
+/// Flags books where the moneyline favorite is the spread underdog, or where the two imply
+/// home win probabilities more than `max_prob_gap` apart. Books missing either market are
+/// skipped.
+pub fn find_data_anomalies(
+    games_with_odds: &[(Game, Vec<BettingOdds>)],
+    max_prob_gap: f64,
+) -> Vec<DataAnomaly> {
+    let mut anomalies = Vec::new();
+
+    for (game, odds_list) in games_with_odds {
+        for odds in odds_list {
+            let price = |team: &str| {
+                odds.moneyline
+                    .iter()
+                    .find(|moneyline| moneyline.team == team)
+                    .map(|moneyline| moneyline.price)
+            };
+            let (Some(home_price), Some(away_price)) =
+                (price(&game.home_team), price(&game.away_team))
+            else {
+                continue;
+            };
+            let Some(home_spread) = odds
+                .spreads
+                .iter()
+                .find(|spread| spread.team == game.home_team)
+                .map(|spread| spread.point)
+            else {
+                continue;
+            };
+
+            let (moneyline_home_prob, _) = devig_two_way(home_price, away_price);
+            // A home line of -7 projects the home team to win by 7
+            let spread_home_prob =
+                calculate_spread_cover_probability(-home_spread, 0.0, SPREAD_STD_DEV);
+
+            let favorite_mismatch = (home_spread < 0.0 && moneyline_home_prob < 0.5)
+                || (home_spread > 0.0 && moneyline_home_prob > 0.5);
+            let kind = if favorite_mismatch {
+                AnomalyKind::FavoriteMismatch
+            } else if (moneyline_home_prob - spread_home_prob).abs() > max_prob_gap {
+                AnomalyKind::ProbabilityGap
+            } else {
+                continue;
+            };
+
+            anomalies.push(DataAnomaly {
+                game_id: game.id.clone(),
+                home_team: game.home_team.clone(),
+                away_team: game.away_team.clone(),
+                bookmaker: odds.bookmaker.clone(),
+                kind,
+                home_spread,
+                moneyline_home_prob,
+                spread_home_prob,
+            });
+        }
+    }
+
+    anomalies
+}
+
+/// How many EV bets were found at a bookmaker and how good they were on average
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct BookStats {
+    pub count: usize,
+    pub average_ev: f64,
+}
+
+/// Count and average EV of the given bets, grouped by normalized bookmaker
+fn stats_by_bookmaker<'a>(
+    bets: impl Iterator<Item = (&'a str, f64)>,
+) -> HashMap<Bookmaker, BookStats> {
+    let mut totals: HashMap<Bookmaker, (usize, f64)> = HashMap::new();
+    for (bookmaker, ev) in bets {
+        let entry = totals
+            .entry(Bookmaker::from_title(bookmaker))
+            .or_insert((0, 0.0));
+        entry.0 += 1;
+        entry.1 += ev;
+    }
+
+    totals
+        .into_iter()
+        .map(|(bookmaker, (count, total_ev))| {
+            (
+                bookmaker,
+                BookStats {
+                    count,
+                    average_ev: total_ev / count as f64,
+                },
+            )
+        })
+        .collect()
+}
+
+/// Moneyline EV bet count and average EV per bookmaker
+pub fn ev_by_bookmaker(bets: &[EvBetRecommendation]) -> HashMap<Bookmaker, BookStats> {
+    stats_by_bookmaker(
+        bets.iter()
+            .map(|bet| (bet.bookmaker.as_str(), bet.expected_value)),
+    )
+}
+
+/// Spread EV bet count and average EV per bookmaker
+pub fn spread_ev_by_bookmaker(bets: &[SpreadEvBetRecommendation]) -> HashMap<Bookmaker, BookStats> {
+    stats_by_bookmaker(
+        bets.iter()
+            .map(|bet| (bet.bookmaker.as_str(), bet.expected_value)),
+    )
+}
+
+/// Number of equal-width model probability buckets in a calibration report
+const CALIBRATION_BUCKETS: usize = 10;
+
+/// Predicted vs realized win rate for graded bets whose model probability fell in
+/// `[min_prob, max_prob)`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalibrationBucket {
+    pub min_prob: f64,
+    pub max_prob: f64,
+    pub bets: usize,
+    pub avg_model_prob: f64,
+    pub actual_win_rate: f64,
+}
+
+impl CalibrationBucket {
+    /// Realized minus predicted win rate (negative = model is overconfident)
+    pub fn gap(&self) -> f64 {
+        self.actual_win_rate - self.avg_model_prob
+    }
+}
+
+/// Bucket graded bets into 10% model probability bins and compare the average model
+/// probability in each bin with how often those bets actually won
+/// Ungraded bets and empty bins are left out.
+pub fn calibration_report(graded: &[BetResult]) -> Vec<CalibrationBucket> {
+    // (bets, sum of model probs, wins) per bin
+    let mut bins = vec![(0usize, 0.0, 0usize); CALIBRATION_BUCKETS];
+
+    for result in graded {
+        let Some(won) = result.bet_won else {
+            continue;
+        };
+        let index = ((result.bet.model_prob * CALIBRATION_BUCKETS as f64) as usize)
+            .min(CALIBRATION_BUCKETS - 1);
+        let bin = &mut bins[index];
+        bin.0 += 1;
+        bin.1 += result.bet.model_prob;
+        bin.2 += usize::from(won);
+    }
+
+    bins.into_iter()
+        .enumerate()
+        .filter(|(_, (bets, _, _))| *bets > 0)
+        .map(|(i, (bets, prob_sum, wins))| CalibrationBucket {
+            min_prob: i as f64 / CALIBRATION_BUCKETS as f64,
+            max_prob: (i + 1) as f64 / CALIBRATION_BUCKETS as f64,
+            bets,
+            avg_model_prob: prob_sum / bets as f64,
+            actual_win_rate: wins as f64 / bets as f64,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{MoneylineOdds, SpreadOdds};
+    use chrono::{Duration, TimeZone};
+
+    #[test]
+    fn test_extract_school_name() {
+        let cases = [
+            // Odds API names
+            ("Iowa Hawkeyes", "iowa"),
+            ("Ohio State Buckeyes", "ohio_st"),
+            ("Ohio Bobcats", "ohio"),
+            ("San Diego State Aztecs", "san_diego_st"),
+            ("San José State Spartans", "san_jose_st"),
+            ("Hawai'i Rainbow Warriors", "hawaii"),
+            ("Miami Hurricanes", "miami_fl"),
+            ("Miami (OH) RedHawks", "miami_oh"),
+            ("Miami RedHawks", "miami_oh"),
+            ("North Carolina Tar Heels", "north_carolina"),
+            ("NC State Wolfpack", "north_carolina_st"),
+            ("South Carolina Gamecocks", "south_carolina"),
+            ("East Carolina Pirates", "east_carolina"),
+            ("Coastal Carolina Chanticleers", "coastal_carolina"),
+            ("North Carolina A&T Aggies", "north_carolina_aandt"),
+            ("Ole Miss Rebels", "ole_miss"),
+            ("Mississippi State Bulldogs", "mississippi_st"),
+            ("Southern Mississippi Golden Eagles", "southern_mississippi"),
+            ("Southern Miss Golden Eagles", "southern_mississippi"),
+            ("South Florida Bulls", "south_florida"),
+            ("South Alabama Jaguars", "south_alabama"),
+            ("Western Michigan Broncos", "western_michigan"),
+            ("Northern Illinois Huskies", "northern_illinois"),
+            ("Middle Tennessee Blue Raiders", "middle_tennessee"),
+            ("Georgia Southern Eagles", "georgia_southern"),
+            ("Georgia Bulldogs", "georgia"),
+            ("Florida Atlantic Owls", "florida_atlantic"),
+            ("Florida International Panthers", "florida_international"),
+            ("Army Black Knights", "army"),
+            ("Navy Midshipmen", "navy"),
+            ("Air Force Falcons", "air_force"),
+            ("Texas A&M Aggies", "texas_aandm"),
+            ("Notre Dame Fighting Irish", "notre_dame"),
+            ("UL Monroe Warhawks", "ul_monroe"),
+            ("Louisiana Ragin' Cajuns", "louisiana"),
+            ("Louisiana Tech Bulldogs", "louisiana_tech"),
+            ("Appalachian State Mountaineers", "appalachian_st"),
+            ("Sam Houston State Bearkats", "sam_houston"),
+            ("UCF Knights", "ucf"),
+            ("UConn Huskies", "uconn"),
+            // Prediction Tracker names
+            ("Mississippi", "ole_miss"),
+            ("Southern Miss", "southern_mississippi"),
+            ("Miami", "miami_fl"),
+            ("Miami (FL)", "miami_fl"),
+            ("Miami Florida", "miami_fl"),
+            ("Miami (OH)", "miami_oh"),
+            ("Miami Ohio", "miami_oh"),
+            ("Miami-Ohio", "miami_oh"),
+            ("North Carolina State", "north_carolina_st"),
+            ("App State", "appalachian_st"),
+            ("Florida Intl", "florida_international"),
+            ("Louisiana-Monroe", "ul_monroe"),
+            ("Louisiana Monroe", "ul_monroe"),
+            ("Louisiana", "louisiana"),
+            ("Louisiana-Lafayette", "louisiana"),
+            ("UL Lafayette", "louisiana"),
+            ("Louisiana Tech", "louisiana_tech"),
+            ("San Jose State", "san_jose_st"),
+            ("San Jose St.", "san_jose_st"),
+            ("San Diego State", "san_diego_st"),
+            ("San Diego St", "san_diego_st"),
+            ("Sam Houston", "sam_houston"),
+            ("Kent", "kent_st"),
+            ("Air Force", "air_force"),
+        ];
+
+        for (team, expected) in cases {
+            assert_eq!(extract_school_name(team), expected, "{}", team);
+        }
+    }
+
+    #[test]
+    fn test_both_miamis_match_their_own_predictions() {
+        let games = [
+            (
+                create_game("Miami Hurricanes", "Florida State Seminoles"),
+                vec![create_moneyline_odds(
+                    "FanDuel",
+                    ("Miami Hurricanes", -200),
+                    ("Florida State Seminoles", 170),
+                )],
+            ),
+            (
+                create_game("Miami (OH) RedHawks", "Ohio Bobcats"),
+                vec![create_moneyline_odds(
+                    "FanDuel",
+                    ("Miami (OH) RedHawks", 120),
+                    ("Ohio Bobcats", -140),
+                )],
+            ),
+        ];
+        let predictions = vec![
+            create_prediction("Miami Florida", "Florida State", 0.7),
+            create_prediction("Miami Ohio", "Ohio", 0.4),
+        ];
+
+        let bets =
+            moneyline_bets_vs_predictions(games.iter(), &predictions, &BoostConfig::default());
+
+        let prob = |team: &str| bets.iter().find(|bet| bet.team == team).unwrap().model_prob;
+        assert_eq!(bets.len(), 4);
+        assert!((prob("Miami Hurricanes") - 0.7).abs() < 1e-9);
+        assert!((prob("Miami (OH) RedHawks") - 0.4).abs() < 1e-9);
+    }
+
+    fn create_game(home: &str, away: &str) -> Game {
+        Game {
+            id: "test_game".to_string(),
+            home_team: home.to_string(),
+            away_team: away.to_string(),
+            commence_time: Utc::now() + Duration::hours(1),
+            sport_title: "NCAAF".to_string(),
+            is_live: false,
+        }
+    }
+
+    fn create_prediction(home: &str, away: &str, home_win_prob: f64) -> GamePrediction {
+        GamePrediction {
+            home_team: home.to_string(),
+            away_team: away.to_string(),
+            home_win_prob,
+            away_win_prob: 1.0 - home_win_prob,
+            spread: 0.0,
+            opening_spread: None,
+            _prediction_avg: 0.0,
+            spread_derived: false,
+            home_cover_prob: None,
+            source: String::new(),
+        }
+    }
+
+    fn create_moneyline_odds(bookmaker: &str, home: (&str, i32), away: (&str, i32)) -> BettingOdds {
+        BettingOdds {
+            game_id: "test_game".to_string(),
+            bookmaker: bookmaker.to_string(),
+            last_update: Utc::now(),
+            moneyline: vec![
+                MoneylineOdds {
+                    team: home.0.to_string(),
+                    price: home.1,
+                },
+                MoneylineOdds {
+                    team: away.0.to_string(),
+                    price: away.1,
+                },
+            ],
+            spreads: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_find_top_ev_bets_vs_sharp() {
+        let game = create_game("Iowa Hawkeyes", "Nebraska Cornhuskers");
+        let odds = vec![
+            create_moneyline_odds(
+                "Pinnacle",
+                ("Iowa Hawkeyes", -150),
+                ("Nebraska Cornhuskers", 130),
+            ),
+            // Soft book hangs a much longer price on Nebraska than the sharp line implies
+            create_moneyline_odds(
+                "DraftKings",
+                ("Iowa Hawkeyes", -170),
+                ("Nebraska Cornhuskers", 160),
+            ),
+        ];
+
+        let bets = find_top_ev_bets_vs_sharp(
+            "pinnacle",
+            &[(game, odds)],
+            None,
+            None,
+            &EvFilter::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(bets.len(), 1);
+        assert_eq!(bets[0].team, "Nebraska Cornhuskers");
+        assert_eq!(bets[0].bookmaker, "DraftKings");
+        let (_, fair_nebraska) = devig_two_way(-150, 130);
+        assert!((bets[0].model_prob - fair_nebraska).abs() < 1e-9);
+        assert!(bets[0].expected_value > 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_find_top_ev_bets_vs_sharp_skips_games_without_sharp_line() {
+        let game = create_game("Iowa Hawkeyes", "Nebraska Cornhuskers");
+        let odds = vec![create_moneyline_odds(
+            "DraftKings",
+            ("Iowa Hawkeyes", -170),
+            ("Nebraska Cornhuskers", 160),
+        )];
+
+        let bets = find_top_ev_bets_vs_sharp(
+            "Pinnacle",
+            &[(game, odds)],
+            None,
+            None,
+            &EvFilter::default(),
+        )
+        .await
+        .unwrap();
+
+        assert!(bets.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_find_market_value_bets() {
+        let game = create_game("Duke Blue Devils", "North Carolina Tar Heels");
+        let mut odds = vec![
+            create_moneyline_odds(
+                "Pinnacle",
+                ("Duke Blue Devils", -150),
+                ("North Carolina Tar Heels", 130),
+            ),
+            create_moneyline_odds(
+                "FanDuel",
+                ("Duke Blue Devils", -155),
+                ("North Carolina Tar Heels", 135),
+            ),
+        ];
+
+        // Two books aren't enough of a market to call one of them off
+        let thin = find_market_value_bets(
+            &[(game.clone(), odds.clone())],
+            None,
+            None,
+            &EvFilter::default(),
+        )
+        .await
+        .unwrap();
+        assert!(thin.is_empty());
+
+        // A soft book well off the consensus on North Carolina
+        odds.push(create_moneyline_odds(
+            "DraftKings",
+            ("Duke Blue Devils", -180),
+            ("North Carolina Tar Heels", 165),
+        ));
+        let bets = find_market_value_bets(
+            &[(game.clone(), odds.clone())],
+            None,
+            None,
+            &EvFilter::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(bets.len(), 1);
+        assert_eq!(bets[0].team, "North Carolina Tar Heels");
+        assert_eq!(bets[0].bookmaker, "DraftKings");
+        let (_, fair) = fair_market_prob(&odds, &game).unwrap();
+        assert!((bets[0].model_prob - fair).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_overround_per_book() {
+        let game = create_game("Iowa Hawkeyes", "Nebraska Cornhuskers");
+        let mut one_sided = create_moneyline_odds(
+            "Bovada",
+            ("Iowa Hawkeyes", -150),
+            ("Nebraska Cornhuskers", 130),
+        );
+        one_sided.moneyline.pop();
+        let odds = vec![
+            create_moneyline_odds(
+                "DraftKings",
+                ("Iowa Hawkeyes", -110),
+                ("Nebraska Cornhuskers", -110),
+            ),
+            create_moneyline_odds(
+                "Pinnacle",
+                ("Iowa Hawkeyes", -105),
+                ("Nebraska Cornhuskers", -105),
+            ),
+            create_moneyline_odds(
+                "LowVig.ag",
+                ("Iowa Hawkeyes", 100),
+                ("Nebraska Cornhuskers", -110),
+            ),
+            one_sided,
+        ];
+
+        let overrounds = overround(&odds, &game);
+
+        assert_eq!(overrounds.len(), 3);
+        assert!((overrounds["DraftKings"] - 2.0 * 110.0 / 210.0).abs() < 1e-9);
+        assert!((overrounds["Pinnacle"] - 2.0 * 105.0 / 205.0).abs() < 1e-9);
+        assert!(!overrounds.contains_key("Bovada"));
+
+        // LowVig.ag's +100/-110 holds a little less than Pinnacle's -105/-105
+        let (book, lowest) = lowest_vig_book(&odds, &game).unwrap();
+        assert_eq!(book, "LowVig.ag");
+        assert!((lowest - (0.5 + 110.0 / 210.0)).abs() < 1e-9);
+        assert_eq!(lowest_vig_book(&odds[3..], &game), None);
+    }
+
+    #[test]
+    fn test_best_price_by_book() {
+        let odds = vec![
+            create_moneyline_odds(
+                "DraftKings",
+                ("Iowa Hawkeyes", -150),
+                ("Nebraska Cornhuskers", 130),
+            ),
+            create_moneyline_odds(
+                "FanDuel",
+                ("Iowa Hawkeyes", -140),
+                ("Nebraska Cornhuskers", 120),
+            ),
+        ];
+
+        let best = best_price_by_book(&odds);
+
+        let iowa = &best[&moneyline_key("Iowa Hawkeyes")];
+        assert_eq!(iowa.bookmaker, "FanDuel");
+        assert_eq!(iowa.price, -140);
+        let nebraska = &best[&moneyline_key("Nebraska Cornhuskers")];
+        assert_eq!(nebraska.bookmaker, "DraftKings");
+        assert_eq!(nebraska.price, 130);
+    }
+
+    #[test]
+    fn test_best_price_by_book_matches_school_names() {
+        // Kalshi names schools without the mascot
+        let odds = vec![
+            create_moneyline_odds(
+                "DraftKings",
+                ("Iowa Hawkeyes", -150),
+                ("Nebraska Cornhuskers", 130),
+            ),
+            create_moneyline_odds("Kalshi", ("Iowa", -135), ("Nebraska", 110)),
+        ];
+
+        let best = best_price_by_book(&odds);
+
+        let iowa = &best[&moneyline_key("Iowa Hawkeyes")];
+        assert_eq!((iowa.bookmaker, iowa.price), ("Kalshi", -135));
+        let nebraska = &best[&moneyline_key("Nebraska")];
+        assert_eq!((nebraska.bookmaker, nebraska.price), ("DraftKings", 130));
+    }
+
+    #[tokio::test]
+    async fn test_fair_prob_devigs_the_same_books_market() {
+        let game = create_game("Iowa Hawkeyes", "Nebraska Cornhuskers");
+        let mut one_sided = create_moneyline_odds(
+            "DraftKings",
+            ("Iowa Hawkeyes", -170),
+            ("Nebraska Cornhuskers", 140),
+        );
+        one_sided.moneyline.retain(|moneyline| moneyline.price > 0);
+        let odds = vec![
+            create_moneyline_odds(
+                "FanDuel",
+                ("Iowa Hawkeyes", -160),
+                ("Nebraska Cornhuskers", 150),
+            ),
+            one_sided,
+        ];
+        let predictions = vec![create_prediction("Iowa", "Nebraska", 0.5)];
+        let games = [(game, odds)];
+
+        let bets = find_top_ev_bets(
+            &games,
+            &predictions,
+            None,
+            None,
+            &EvFilter::default(),
+            &BoostConfig::default(),
+        )
+        .await
+        .unwrap();
+
+        let fanduel = bets.iter().find(|bet| bet.bookmaker == "FanDuel").unwrap();
+        let (fair_prob, _) = devig_two_way(150, -160);
+        assert_eq!(fanduel.fair_prob, Some(fair_prob));
+        assert!(fanduel.fair_prob.unwrap() < fanduel.implied_prob);
+        assert_eq!(fanduel.fair_edge(), Some(0.5 - fair_prob));
+        // DraftKings only lists Nebraska, so there's nothing to de-vig against
+        let draftkings = bets
+            .iter()
+            .find(|bet| bet.bookmaker == "DraftKings")
+            .unwrap();
+        assert_eq!(draftkings.fair_prob, None);
+    }
+
+    #[tokio::test]
+    async fn test_boost_turns_negative_ev_price_positive() {
+        assert_eq!(boost_odds(-110, 0.5), 136);
+        assert_eq!(boost_odds(-300, 0.5), -200);
+
+        let game = create_game("Iowa Hawkeyes", "Nebraska Cornhuskers");
+        let odds = vec![
+            create_moneyline_odds(
+                "FanDuel",
+                ("Iowa Hawkeyes", -110),
+                ("Nebraska Cornhuskers", -110),
+            ),
+            create_moneyline_odds(
+                "DraftKings",
+                ("Iowa Hawkeyes", -110),
+                ("Nebraska Cornhuskers", -110),
+            ),
+        ];
+        let predictions = vec![create_prediction("Iowa", "Nebraska", 0.5)];
+        let games = [(game, odds)];
+        let boost = |bookmaker: &str, opponent: Option<&str>| OddsBoost {
+            team: "Iowa".to_string(),
+            bookmaker: bookmaker.to_string(),
+            opponent: opponent.map(str::to_string),
+            profit_boost: 0.5,
+        };
+        let boosts = BoostConfig::new(vec![
+            boost("fanduel", Some("Nebraska")),
+            // Another game's boost doesn't apply
+            boost("DraftKings", Some("Ohio State")),
+        ]);
+
+        let unboosted = find_top_ev_bets(
+            &games,
+            &predictions,
+            None,
+            None,
+            &EvFilter::default(),
+            &BoostConfig::default(),
+        )
+        .await
+        .unwrap();
+        assert!(unboosted.is_empty());
+
+        let bets = find_top_ev_bets(
+            &games,
+            &predictions,
+            None,
+            None,
+            &EvFilter::default(),
+            &boosts,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(bets.len(), 1);
+        let bet = &bets[0];
+        assert_eq!((bet.bookmaker.as_str(), bet.odds), ("FanDuel", -110));
+        // The posted price's EV is reported as before, with the boosted EV alongside
+        assert_eq!(bet.expected_value, calculate_expected_value(0.5, -110));
+        assert!(bet.expected_value < 0.0);
+        let applied = bet.boost.unwrap();
+        assert_eq!(applied.odds, 136);
+        assert!((bet.effective_ev() - 0.18).abs() < 1e-9);
+        assert!((bet.profit_on_win(100.0) - 136.0).abs() < 1e-9);
+        assert!(bet.format().contains("Boosted +50%: +136, EV +18.00%"));
+    }
+
+    #[tokio::test]
+    async fn test_line_shop_keeps_only_best_priced_book() {
+        let game = create_game("Iowa Hawkeyes", "Nebraska Cornhuskers");
+        let odds = vec![
+            create_moneyline_odds(
+                "DraftKings",
+                ("Iowa Hawkeyes", -150),
+                ("Nebraska Cornhuskers", 130),
+            ),
+            create_moneyline_odds(
+                "FanDuel",
+                ("Iowa Hawkeyes", -160),
+                ("Nebraska Cornhuskers", 150),
+            ),
+        ];
+        let predictions = vec![create_prediction("Iowa", "Nebraska", 0.5)];
+        let games = [(game, odds)];
+
+        let all_bets = find_top_ev_bets(
+            &games,
+            &predictions,
+            None,
+            None,
+            &EvFilter::default(),
+            &BoostConfig::default(),
+        )
+        .await
+        .unwrap();
+        let nebraska_bets: Vec<_> = all_bets
+            .iter()
+            .filter(|bet| bet.team == "Nebraska Cornhuskers")
+            .collect();
+        assert_eq!(nebraska_bets.len(), 2);
+        assert!(nebraska_bets
+            .iter()
+            .all(|bet| bet.best_bookmaker == "FanDuel" && bet.best_odds == 150));
+
+        let shopped = find_top_ev_bets(
+            &games,
+            &predictions,
+            None,
+            Some(1),
+            &EvFilter::default(),
+            &BoostConfig::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(shopped.len(), 1);
+        assert_eq!(shopped[0].bookmaker, "FanDuel");
+        assert!(shopped[0].is_best_price);
+    }
+
+    #[test]
+    fn test_plausibility_score() {
+        assert_eq!(plausibility_score(0.05), 1.0);
+        assert_eq!(plausibility_score(PLAUSIBLE_EDGE), 1.0);
+        let midway = (PLAUSIBLE_EDGE + DEFAULT_MAX_PLAUSIBLE_EDGE) / 2.0;
+        assert!((plausibility_score(midway) - 0.5).abs() < 1e-9);
+        // Model 95% vs implied 40% is almost certainly a data error
+        assert_eq!(plausibility_score(0.95 - 0.40), 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_implausible_edges_rank_below_moderate_ones() {
+        let games = [
+            (
+                create_game("Iowa Hawkeyes", "Nebraska Cornhuskers"),
+                vec![create_moneyline_odds(
+                    "DraftKings",
+                    ("Iowa Hawkeyes", -110),
+                    ("Nebraska Cornhuskers", -110),
+                )],
+            ),
+            (
+                create_game("Ohio State Buckeyes", "Michigan Wolverines"),
+                vec![create_moneyline_odds(
+                    "DraftKings",
+                    ("Ohio State Buckeyes", -120),
+                    ("Michigan Wolverines", 150),
+                )],
+            ),
+        ];
+        let predictions = vec![
+            // Moderate 7 point edge on Iowa
+            create_prediction("Iowa", "Nebraska", 0.59),
+            // 55 point edge on Michigan: higher EV, but implausible
+            create_prediction("Ohio State", "Michigan", 0.05),
+        ];
+
+        let bets = find_top_ev_bets(
+            &games,
+            &predictions,
+            None,
+            None,
+            &EvFilter::default(),
+            &BoostConfig::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(bets.len(), 2);
+        assert_eq!(bets[0].team, "Iowa Hawkeyes");
+        assert!(bets[0].is_plausible());
+        assert_eq!(bets[1].team, "Michigan Wolverines");
+        assert!(!bets[1].is_plausible());
+        assert!(bets[1].expected_value > bets[0].expected_value);
+    }
+
+    #[tokio::test]
+    async fn test_min_ev_threshold() {
+        let games = [(
+            create_game("Iowa Hawkeyes", "Nebraska Cornhuskers"),
+            vec![create_moneyline_odds(
+                "FanDuel",
+                ("Iowa Hawkeyes", 100),
+                ("Nebraska Cornhuskers", -120),
+            )],
+        )];
+        // 6% EV on Iowa at even money
+        let predictions = vec![GamePrediction {
+            spread: 1.0,
+            ..create_prediction("Iowa", "Nebraska", 0.53)
+        }];
+
+        let above = find_top_ev_bets(
+            &games,
+            &predictions,
+            None,
+            None,
+            &EvFilter::new(0.05),
+            &BoostConfig::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(above.len(), 1);
+
+        let below = find_top_ev_bets(
+            &games,
+            &predictions,
+            None,
+            None,
+            &EvFilter::new(0.10),
+            &BoostConfig::default(),
+        )
+        .await
+        .unwrap();
+        assert!(below.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_max_edge_applies_before_top_n() {
+        let games = [
+            (
+                create_game("Iowa Hawkeyes", "Nebraska Cornhuskers"),
+                vec![create_moneyline_odds(
+                    "DraftKings",
+                    ("Iowa Hawkeyes", -110),
+                    ("Nebraska Cornhuskers", -110),
+                )],
+            ),
+            (
+                create_game("Ohio State Buckeyes", "Michigan Wolverines"),
+                vec![create_moneyline_odds(
+                    "DraftKings",
+                    ("Ohio State Buckeyes", -110),
+                    ("Michigan Wolverines", -110),
+                )],
+            ),
+        ];
+        let predictions = vec![
+            // About a 10 point edge on Iowa
+            create_prediction("Iowa", "Nebraska", 0.62),
+            // About a 5 point edge on Ohio State
+            create_prediction("Ohio State", "Michigan", 0.57),
+        ];
+        let find = |filter: EvFilter| {
+            let (games, predictions) = (&games, &predictions);
+            async move {
+                find_top_ev_bets(
+                    games,
+                    predictions,
+                    Some(1),
+                    None,
+                    &filter,
+                    &BoostConfig::default(),
+                )
+                .await
+                .unwrap()
+            }
+        };
+
+        let bets = find(EvFilter::default()).await;
+        assert_eq!(bets.len(), 1);
+        assert_eq!(bets[0].team, "Iowa Hawkeyes");
+
+        // Iowa's edge is over the cap, so Ohio State takes the only spot
+        let bets = find(EvFilter {
+            max_edge: Some(0.08),
+            ..EvFilter::default()
+        })
+        .await;
+        assert_eq!(bets.len(), 1);
+        assert_eq!(bets[0].team, "Ohio State Buckeyes");
+    }
+
+    #[tokio::test]
+    async fn test_conference_filter_applies_before_top_n() {
+        let games = [
+            (
+                create_game("Iowa Hawkeyes", "Nebraska Cornhuskers"),
+                vec![create_moneyline_odds(
+                    "DraftKings",
+                    ("Iowa Hawkeyes", -110),
+                    ("Nebraska Cornhuskers", -110),
+                )],
+            ),
+            (
+                create_game("Ohio State Buckeyes", "Michigan Wolverines"),
+                vec![create_moneyline_odds(
+                    "DraftKings",
+                    ("Ohio State Buckeyes", -110),
+                    ("Michigan Wolverines", -110),
+                )],
+            ),
+            (
+                create_game("Alabama Crimson Tide", "Auburn Tigers"),
+                vec![create_moneyline_odds(
+                    "DraftKings",
+                    ("Alabama Crimson Tide", -110),
+                    ("Auburn Tigers", -110),
+                )],
+            ),
+        ];
+        // Both Big Ten bets beat the only SEC one
+        let predictions = vec![
+            create_prediction("Iowa", "Nebraska", 0.62),
+            create_prediction("Ohio State", "Michigan", 0.60),
+            create_prediction("Alabama", "Auburn", 0.56),
+        ];
+        let filter = EvFilter {
+            conferences: vec!["sec".to_string()],
+            ..EvFilter::default()
+        };
+
+        let (bets, skipped) = find_top_ev_bets_with_diagnostics(
+            &games,
+            &predictions,
+            Some(2),
+            None,
+            &filter,
+            &BoostConfig::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(bets.len(), 1);
+        assert_eq!(bets[0].team, "Alabama Crimson Tide");
+        assert_eq!(skipped.len(), 2);
+        assert!(skipped
+            .iter()
+            .all(|skip| skip.reason == SkipReason::OutsideConferences));
+    }
+
+    #[tokio::test]
+    async fn test_max_books_per_bet_keeps_best_ev_books() {
+        let game = create_game("Iowa Hawkeyes", "Nebraska Cornhuskers");
+        let odds = vec![
+            create_moneyline_odds(
+                "BetMGM",
+                ("Iowa Hawkeyes", -150),
+                ("Nebraska Cornhuskers", 125),
+            ),
+            create_moneyline_odds(
+                "DraftKings",
+                ("Iowa Hawkeyes", -150),
+                ("Nebraska Cornhuskers", 130),
+            ),
+            create_moneyline_odds(
+                "FanDuel",
+                ("Iowa Hawkeyes", -160),
+                ("Nebraska Cornhuskers", 150),
+            ),
+        ];
+        let predictions = vec![create_prediction("Iowa", "Nebraska", 0.5)];
+        let games = [(game, odds)];
+
+        let all_books = find_top_ev_bets(
+            &games,
+            &predictions,
+            None,
+            None,
+            &EvFilter::default(),
+            &BoostConfig::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(all_books.len(), 3);
+
+        let capped = find_top_ev_bets(
+            &games,
+            &predictions,
+            None,
+            Some(2),
+            &EvFilter::default(),
+            &BoostConfig::default(),
+        )
+        .await
+        .unwrap();
+        let books: Vec<_> = capped.iter().map(|bet| bet.bookmaker.as_str()).collect();
+        assert_eq!(books, vec!["FanDuel", "DraftKings"]);
+    }
+
+    #[tokio::test]
+    async fn test_fbs_only_skips_games_with_fcs_teams() {
+        let games = [(
+            create_game("Florida Gators", "Florida A&M Rattlers"),
+            vec![create_moneyline_odds(
+                "DraftKings",
+                ("Florida Gators", -5000),
+                ("Florida A&M Rattlers", 2000),
+            )],
+        )];
+        let predictions = vec![GamePrediction {
+            spread: 30.0,
+            ..create_prediction("Florida", "Florida A&M", 0.9)
+        }];
+
+        let all_games = find_top_ev_bets(
+            &games,
+            &predictions,
+            None,
+            None,
+            &EvFilter::default(),
+            &BoostConfig::default(),
+        )
+        .await
+        .unwrap();
+        assert!(!all_games.is_empty());
+
+        let fbs_only = find_top_ev_bets(
+            &games,
+            &predictions,
+            None,
+            None,
+            &EvFilter {
+                fbs_only: true,
+                ..EvFilter::default()
+            },
+            &BoostConfig::default(),
+        )
+        .await
+        .unwrap();
+        assert!(fbs_only.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_diagnostics_explain_skipped_games() {
+        let game_with_odds = |home: &str, away: &str, prices: Option<(i32, i32)>| {
+            let odds = prices
+                .map(|(home_price, away_price)| {
+                    vec![create_moneyline_odds(
+                        "DraftKings",
+                        (home, home_price),
+                        (away, away_price),
+                    )]
+                })
+                .unwrap_or_default();
+            (create_game(home, away), odds)
+        };
+        let mut started = game_with_odds("Oregon Ducks", "Washington Huskies", Some((-110, -110)));
+        started.0.commence_time = Utc::now() - Duration::hours(1);
+        let games = [
+            game_with_odds(
+                "Ohio State Buckeyes",
+                "Michigan Wolverines",
+                Some((-110, 120)),
+            ),
+            game_with_odds("Iowa Hawkeyes", "Nebraska Cornhuskers", Some((-110, -110))),
+            game_with_odds("Purdue Boilermakers", "Indiana Hoosiers", Some((105, -125))),
+            game_with_odds(
+                "Duke Blue Devils",
+                "North Carolina Tar Heels",
+                Some((-110, -110)),
+            ),
+            game_with_odds("Texas Longhorns", "Oklahoma Sooners", None),
+            started,
+        ];
+        let predictions = vec![
+            create_prediction("Ohio State", "Michigan", 0.6),
+            create_prediction("Iowa", "Nebraska", 0.5),
+            create_prediction("Purdue", "Indiana", 0.5),
+            create_prediction("Texas", "Oklahoma", 0.5),
+            create_prediction("Oregon", "Washington", 0.9),
+        ];
+
+        let (bets, skipped) = find_top_ev_bets_with_diagnostics(
+            &games,
+            &predictions,
+            None,
+            None,
+            &EvFilter::new(0.05),
+            &BoostConfig::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(bets.len(), 1);
+        assert_eq!(bets[0].team, "Ohio State Buckeyes");
+        let reasons: HashMap<&str, &SkipReason> = skipped
+            .iter()
+            .map(|skip| (skip.game.home_team.as_str(), &skip.reason))
+            .collect();
+        assert_eq!(reasons.len(), 5);
+        assert_eq!(reasons["Oregon Ducks"], &SkipReason::Started);
+        assert_eq!(reasons["Duke Blue Devils"], &SkipReason::NoPrediction);
+        assert_eq!(reasons["Texas Longhorns"], &SkipReason::NoPricedTeams);
+        assert!(matches!(
+            reasons["Iowa Hawkeyes"],
+            SkipReason::NoPositiveEv { best_ev } if *best_ev < 0.0
+        ));
+        assert!(matches!(
+            reasons["Purdue Boilermakers"],
+            SkipReason::BelowMinEv { best_ev } if (*best_ev - 0.025).abs() < 1e-9
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_spread_ev_with_alternate_lines() {
+        let game = create_game("Iowa Hawkeyes", "Nebraska Cornhuskers");
+        let spread = |team: &str, point: f64, price: i32| SpreadOdds {
+            team: team.to_string(),
+            point,
+            price,
+        };
+        let odds = BettingOdds {
+            spreads: vec![
+                spread("Iowa Hawkeyes", -3.5, -110),
+                spread("Iowa Hawkeyes", -6.5, 130),
+                spread("Iowa Hawkeyes", -10.5, 200),
+                spread("Nebraska Cornhuskers", 3.5, -110),
+                spread("Nebraska Cornhuskers", 6.5, -150),
+            ],
+            ..create_moneyline_odds(
+                "DraftKings",
+                ("Iowa Hawkeyes", -250),
+                ("Nebraska Cornhuskers", 200),
+            )
+        };
+
+        let main_lines = main_spread_lines(&odds);
+        assert_eq!(main_lines["Iowa Hawkeyes"], -3.5);
+        assert_eq!(main_lines["Nebraska Cornhuskers"], 3.5);
+
+        let predictions = vec![GamePrediction {
+            spread: 7.0,
+            ..create_prediction("Iowa", "Nebraska", 0.7)
+        }];
+        let games = [(game, vec![odds])];
+
+        // Every Iowa line has value, each is priced separately
+        let bets = find_top_spread_ev_bets(
+            &games,
+            &predictions,
+            None,
+            None,
+            &EvFilter::default(),
+            SpreadModel::default(),
+        )
+        .await
+        .unwrap();
+        let lines: Vec<(f64, bool)> = bets
+            .iter()
+            .map(|bet| (bet.spread_line, bet.is_main_line))
+            .collect();
+        assert_eq!(lines, vec![(-6.5, false), (-3.5, true), (-10.5, false)]);
+
+        let best = best_line_per_team(bets);
+        assert_eq!(best.len(), 1);
+        assert_eq!(best[0].spread_line, -6.5);
+    }
+
+    #[tokio::test]
+    async fn test_spread_ev_uses_scraped_cover_prob() {
+        let game = create_game("Iowa Hawkeyes", "Nebraska Cornhuskers");
+        let spread = |team: &str, point: f64, price: i32| SpreadOdds {
+            team: team.to_string(),
+            point,
+            price,
+        };
+        let odds = BettingOdds {
+            spreads: vec![
+                spread("Iowa Hawkeyes", -3.5, -110),
+                spread("Iowa Hawkeyes", -6.5, 130),
+                spread("Nebraska Cornhuskers", 3.5, -110),
+            ],
+            ..create_moneyline_odds(
+                "DraftKings",
+                ("Iowa Hawkeyes", -180),
+                ("Nebraska Cornhuskers", 150),
+            )
+        };
+        // The page has Iowa by 3.5 but gives it a 60% chance to cover that line
+        let predictions = vec![GamePrediction {
+            spread: 3.5,
+            home_cover_prob: Some(0.6),
+            ..create_prediction("Iowa", "Nebraska", 0.62)
+        }];
+        let games = [(game, vec![odds])];
+        let filter = EvFilter::default();
+        let find = |scraped_cover| {
+            find_top_spread_ev_bets(
+                &games,
+                &predictions,
+                None,
+                None,
+                &filter,
+                SpreadModel {
+                    scraped_cover,
+                    ..SpreadModel::default()
+                },
+            )
+        };
+
+        // The normal model has the main line as a coin flip, so nothing clears the juice
+        assert!(find(false).await.unwrap().is_empty());
+
+        // The scraped probability only prices the line it was computed for
+        let bets = find(true).await.unwrap();
+        assert_eq!(bets.len(), 1);
+        assert_eq!(bets[0].spread_line, -3.5);
+        assert_eq!(bets[0].model_prob, 0.6);
+        assert_eq!(bets[0].scraped_cover_prob, Some(0.6));
+    }
+
+    fn create_graded_bet(model_prob: f64, bet_won: Option<bool>) -> BetResult {
+        BetResult {
+            bet: EvBetRecommendation {
+                home_team: "Iowa Hawkeyes".to_string(),
+                away_team: "Nebraska Cornhuskers".to_string(),
+                team: "Iowa Hawkeyes".to_string(),
+                bookmaker: "FanDuel".to_string(),
+                odds: 100,
+                model_prob,
+                implied_prob: 0.5,
+                fair_prob: None,
+                expected_value: 0.0,
+                edge: 0.0,
+                best_odds: 100,
+                best_bookmaker: "FanDuel".to_string(),
+                is_best_price: true,
+                plausibility_score: 1.0,
+                conference: "Big Ten".to_string(),
+                commence_time: None,
+                game_id: None,
+                stake: None,
+                adjustments: Vec::new(),
+                boost: None,
+            },
+            game_result: None,
+            bet_won,
+            actual_payout: bet_won.map(|won| if won { 1.0 } else { 0.0 }),
+            stake: FLAT_STAKE,
+            closing_odds: None,
+            beat_close: None,
+            closing_fair_prob: None,
+        }
+    }
+
+    #[test]
+    fn test_fair_market_prob() {
+        let game = create_game("Iowa Hawkeyes", "Nebraska Cornhuskers");
+        let odds = |bookmaker: &str, moneyline: Vec<(&str, i32)>| BettingOdds {
+            game_id: game.id.clone(),
+            bookmaker: bookmaker.to_string(),
+            last_update: Utc::now(),
+            moneyline: moneyline
+                .into_iter()
+                .map(|(team, price)| MoneylineOdds {
+                    team: team.to_string(),
+                    price,
+                })
+                .collect(),
+            spreads: vec![],
+        };
+
+        // One-sided markets don't count
+        let one_sided = odds("FanDuel", vec![("Iowa Hawkeyes", -150)]);
+        assert_eq!(
+            fair_market_prob(std::slice::from_ref(&one_sided), &game),
+            None
+        );
+
+        // Pinnacle's 60% counts three times as much as DraftKings' 50%
+        let odds_list = vec![
+            one_sided,
+            odds(
+                "Pinnacle",
+                vec![("Iowa Hawkeyes", -150), ("Nebraska Cornhuskers", 150)],
+            ),
+            odds(
+                "DraftKings",
+                vec![("Nebraska Cornhuskers", -110), ("Iowa Hawkeyes", -110)],
+            ),
+        ];
+        let (home, away) = fair_market_prob(&odds_list, &game).unwrap();
+        assert!((home - 0.575).abs() < 1e-9);
+        assert!((home + away - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_margin_std_dev() {
+        assert_eq!(MarginStdDev::default().std_dev(28.0, None), SPREAD_STD_DEV);
+
+        let linear = MarginStdDev::Linear {
+            base: 11.0,
+            per_spread_point: 0.1,
+            per_total_point: 0.02,
+        };
+        assert!((linear.std_dev(0.0, None) - 11.0).abs() < 1e-12);
+        // A 30-point favorite (either side) gets a wider margin distribution
+        assert!((linear.std_dev(-30.0, None) - 14.0).abs() < 1e-12);
+        assert!((linear.std_dev(-30.0, Some(50.0)) - 15.0).abs() < 1e-12);
+
+        // Wider variance pulls a big favorite's cover probability toward 50%
+        let constant = calculate_spread_cover_probability(30.0, -24.5, 12.0);
+        let scaled = calculate_spread_cover_probability(30.0, -24.5, linear.std_dev(30.0, None));
+        assert!(scaled < constant && scaled > 0.5);
+    }
+
+    #[test]
+    fn test_find_data_anomalies() {
+        let game = create_game("Iowa Hawkeyes", "Nebraska Cornhuskers");
+        let odds =
+            |bookmaker: &str, home_price: i32, away_price: i32, home_spread: f64| BettingOdds {
+                game_id: game.id.clone(),
+                bookmaker: bookmaker.to_string(),
+                last_update: Utc::now(),
+                moneyline: vec![
+                    MoneylineOdds {
+                        team: game.home_team.clone(),
+                        price: home_price,
+                    },
+                    MoneylineOdds {
+                        team: game.away_team.clone(),
+                        price: away_price,
+                    },
+                ],
+                spreads: vec![SpreadOdds {
+                    team: game.home_team.clone(),
+                    point: home_spread,
+                    price: -110,
+                }],
+            };
+        let games_with_odds = vec![(
+            game.clone(),
+            vec![
+                // Consistent: -7 with about a 72% favorite
+                odds("FanDuel", -280, 230, -7.0),
+                // Home and away prices swapped
+                odds("DraftKings", 230, -280, -7.0),
+                // Right favorite, but the moneyline is far too short for a field goal
+                odds("BetMGM", -1000, 650, -3.0),
+            ],
+        )];
+
+        let anomalies = find_data_anomalies(&games_with_odds, DEFAULT_MAX_PROB_GAP);
+
+        assert_eq!(anomalies.len(), 2);
+        assert_eq!(anomalies[0].bookmaker, "DraftKings");
+        assert_eq!(anomalies[0].kind, AnomalyKind::FavoriteMismatch);
+        assert_eq!(anomalies[1].bookmaker, "BetMGM");
+        assert_eq!(anomalies[1].kind, AnomalyKind::ProbabilityGap);
+    }
+
+    #[test]
+    fn test_add_closing_lines() {
+        let game = create_game("Iowa Hawkeyes", "Nebraska Cornhuskers");
+        let closing = BettingOdds {
+            game_id: game.id.clone(),
+            bookmaker: "FanDuel".to_string(),
+            last_update: Utc::now(),
+            moneyline: vec![MoneylineOdds {
+                team: "Iowa Hawkeyes".to_string(),
+                price: -120,
+            }],
+            spreads: vec![SpreadOdds {
+                team: "Iowa Hawkeyes".to_string(),
+                point: -7.5,
+                price: -110,
+            }],
+        };
+        let closing_lines = vec![(game, vec![closing])];
+
+        // Bet at +100, closed at -120: beat the close
+        let mut results = vec![create_graded_bet(0.6, Some(true))];
+        add_closing_lines(&mut results, &closing_lines);
+        assert_eq!(results[0].closing_odds, Some(-120));
+        assert_eq!(results[0].beat_close, Some(true));
+
+        // A book that wasn't in the closing snapshot has no closing data
+        results[0].bet.bookmaker = "DraftKings".to_string();
+        add_closing_lines(&mut results, &closing_lines);
+        assert_eq!(results[0].closing_odds, None);
+        assert_eq!(results[0].beat_close, None);
+
+        // Iowa -6.5 closed at -7.5: beat the close by a point
+        let bet: SpreadEvBetRecommendation = serde_json::from_value(serde_json::json!({
+            "home_team": "Iowa Hawkeyes",
+            "away_team": "Nebraska Cornhuskers",
+            "team": "Iowa Hawkeyes",
+            "spread_line": -6.5,
+            "bookmaker": "FanDuel",
+            "odds": -115,
+            "model_spread": -10.0,
+            "model_prob": 0.6,
+            "implied_prob": 0.535,
+            "expected_value": 0.12,
+            "edge": 0.065,
+        }))
+        .unwrap();
+        let mut spread_results = compare_spread_ev_bets_to_results(&[bet], &[]);
+        add_spread_closing_lines(&mut spread_results, &closing_lines);
+        assert_eq!(spread_results[0].closing_spread, Some(-7.5));
+        assert_eq!(spread_results[0].closing_odds, Some(-110));
+        assert_eq!(spread_results[0].beat_close, Some(true));
+    }
+
+    #[test]
+    fn test_grading_uses_recorded_stakes() {
+        let result: GameResult = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "season": 2025,
+            "week": 10,
+            "seasonType": "regular",
+            "startDate": "2025-11-01T19:30:00.000Z",
+            "startTimeTBD": false,
+            "completed": true,
+            "neutralSite": false,
+            "conferenceGame": true,
+            "homeId": 1,
+            "homeTeam": "Iowa",
+            "homePoints": 24,
+            "awayId": 2,
+            "awayTeam": "Nebraska",
+            "awayPoints": 14,
+        }))
+        .unwrap();
+        let base = create_graded_bet(0.6, None).bet;
+        let bets = vec![
+            // $50 on Iowa at +100 wins $50
+            EvBetRecommendation {
+                stake: Some(50.0),
+                ..base.clone()
+            },
+            // $200 on Nebraska loses $200
+            EvBetRecommendation {
+                team: "Nebraska Cornhuskers".to_string(),
+                stake: Some(200.0),
+                ..base.clone()
+            },
+            // No recorded stake: graded as a flat bet
+            base,
+        ];
+
+        let results = compare_ev_bets_to_results(&bets, &[result]);
+
+        assert_eq!(results[0].profit(), Some(50.0));
+        assert_eq!(results[1].profit(), Some(-200.0));
+        assert!(results[1].format().contains("LOST -$200.00"));
+        assert_eq!(results[2].stake, FLAT_STAKE);
+        assert_eq!(results[2].profit(), Some(1.0));
+
+        // Two of three bets won, but the big loss makes the stake-weighted ROI negative
+        let summary = GradingSummary::from_graded(
+            results
+                .iter()
+                .filter_map(|result| Some((result.stake, result.profit()?))),
+        );
+        assert_eq!((summary.wins, summary.losses), (2, 1));
+        assert_eq!(summary.total_staked, 251.0);
+        assert!((summary.roi() - -149.0 / 251.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_grading_skips_games_in_progress() {
+        // Iowa leads at halftime, but the game isn't over
+        let result: GameResult = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "completed": false,
+            "homeTeam": "Iowa",
+            "homePoints": 17,
+            "awayTeam": "Nebraska",
+            "awayPoints": 3,
+        }))
+        .unwrap();
+        let bet = create_graded_bet(0.6, None).bet;
+
+        let results = compare_ev_bets_to_results(&[bet], std::slice::from_ref(&result));
+        assert!(results[0].game_result.is_some());
+        assert_eq!(results[0].bet_won, None);
+        assert_eq!(results[0].profit(), None);
+
+        let spread_bet: SpreadEvBetRecommendation = serde_json::from_value(serde_json::json!({
+            "home_team": "Iowa Hawkeyes",
+            "away_team": "Nebraska Cornhuskers",
+            "team": "Iowa Hawkeyes",
+            "spread_line": -6.5,
+            "bookmaker": "FanDuel",
+            "odds": -110,
+            "model_spread": -10.0,
+            "model_prob": 0.6,
+            "implied_prob": 0.524,
+            "expected_value": 0.1,
+            "edge": 0.076,
+        }))
+        .unwrap();
+        let spread_results = compare_spread_ev_bets_to_results(&[spread_bet], &[result]);
+        assert_eq!(spread_results[0].bet_won, None);
+        assert_eq!(spread_results[0].actual_payout, None);
+    }
+
+    #[test]
+    fn test_grading_matches_the_game_a_bet_was_on() {
+        let result = |start_date: &str, home_points: i32, away_points: i32| -> GameResult {
+            serde_json::from_value(serde_json::json!({
+                "id": 1,
+                "startDate": start_date,
+                "completed": true,
+                "homeTeam": "Iowa",
+                "homePoints": home_points,
+                "awayTeam": "Nebraska",
+                "awayPoints": away_points,
+            }))
+            .unwrap()
+        };
+        // Iowa lost the first meeting and won the rematch a week later
+        let results = [
+            result("2025-11-22T20:30:00.000Z", 10, 20),
+            result("2025-11-29T20:30:00.000Z", 24, 14),
+        ];
+        let kickoff = |day: u32| Some(Utc.with_ymd_and_hms(2025, 11, day, 20, 30, 0).unwrap());
+        let bet = |commence_time| EvBetRecommendation {
+            commence_time,
+            ..create_graded_bet(0.6, None).bet
+        };
+
+        let graded = compare_ev_bets_to_results(&[bet(kickoff(22)), bet(kickoff(29))], &results);
+        assert_eq!(graded[0].bet_won, Some(false));
+        assert_eq!(graded[1].bet_won, Some(true));
+
+        // Without a kickoff, two games between the teams can't be told apart
+        let graded = compare_ev_bets_to_results(&[bet(None)], &results);
+        assert!(graded[0].game_result.is_none());
+
+        let spread_bet: SpreadEvBetRecommendation = serde_json::from_value(serde_json::json!({
+            "home_team": "Iowa Hawkeyes",
+            "away_team": "Nebraska Cornhuskers",
+            "team": "Iowa Hawkeyes",
+            "spread_line": -6.5,
+            "bookmaker": "FanDuel",
+            "odds": -110,
+            "model_spread": -10.0,
+            "model_prob": 0.6,
+            "implied_prob": 0.524,
+            "expected_value": 0.1,
+            "edge": 0.076,
+            "commence_time": "2025-11-29T20:30:00Z",
+        }))
+        .unwrap();
+        let graded = compare_spread_ev_bets_to_results(&[spread_bet], &results);
+        assert_eq!(graded[0].bet_won, Some(true));
+    }
+
+    #[test]
+    fn test_calibration_report() {
+        let graded = vec![
+            create_graded_bet(0.72, Some(true)),
+            create_graded_bet(0.68, Some(false)),
+            create_graded_bet(0.70, Some(false)),
+            create_graded_bet(0.71, Some(true)),
+            create_graded_bet(0.45, Some(true)),
+            create_graded_bet(1.0, Some(true)),
+            create_graded_bet(0.55, None),
+        ];
+
+        let report = calibration_report(&graded);
+
+        assert_eq!(report.len(), 4);
+        assert_eq!((report[0].min_prob, report[0].bets), (0.4, 1));
+        assert_eq!((report[1].min_prob, report[1].bets), (0.6, 1));
+
+        let seventies = &report[2];
+        assert_eq!(seventies.bets, 3);
+        assert!((seventies.avg_model_prob - 0.71).abs() < 1e-9);
+        assert!((seventies.actual_win_rate - 2.0 / 3.0).abs() < 1e-9);
+        assert!(seventies.gap() < 0.0);
+
+        // A 100% model probability lands in the top bin
+        assert_eq!((report[3].min_prob, report[3].bets), (0.9, 1));
+    }
+
+    #[test]
+    fn test_ev_by_bookmaker() {
+        let bet = |bookmaker: &str, expected_value: f64| EvBetRecommendation {
+            bookmaker: bookmaker.to_string(),
+            expected_value,
+            ..create_graded_bet(0.5, None).bet
+        };
+        // Spelling differences in the title still group under one book
+        let bets = vec![
+            bet("FanDuel", 0.02),
+            bet("fanduel", 0.06),
+            bet("DraftKings", 0.03),
+        ];
+
+        let stats = ev_by_bookmaker(&bets);
+
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats[&Bookmaker::FanDuel].count, 2);
+        assert!((stats[&Bookmaker::FanDuel].average_ev - 0.04).abs() < 1e-9);
+        assert_eq!(stats[&Bookmaker::DraftKings].count, 1);
+    }
+
+    #[test]
+    fn test_tied_bets_sort_deterministically() {
+        let bet = |bookmaker: &str, team: &str, edge: f64| EvBetRecommendation {
+            bookmaker: bookmaker.to_string(),
+            team: team.to_string(),
+            expected_value: 0.05,
+            edge,
+            plausibility_score: 1.0,
+            ..create_graded_bet(0.5, None).bet
+        };
+        let bets = vec![
+            bet("FanDuel", "Iowa", 0.03),
+            bet("DraftKings", "Nebraska", 0.03),
+            bet("FanDuel", "Nebraska", 0.04),
+            bet("DraftKings", "Iowa", 0.03),
+        ];
+        let order = |bets: Vec<EvBetRecommendation>| -> Vec<(String, String)> {
+            top_positive_ev_bets(bets, None, None, &EvFilter::default())
+                .into_iter()
+                .map(|bet| (bet.bookmaker, bet.team))
+                .collect()
+        };
+
+        let expected = vec![
+            ("FanDuel".to_string(), "Nebraska".to_string()),
+            ("DraftKings".to_string(), "Iowa".to_string()),
+            ("DraftKings".to_string(), "Nebraska".to_string()),
+            ("FanDuel".to_string(), "Iowa".to_string()),
+        ];
+        assert_eq!(order(bets.clone()), expected);
+        assert_eq!(order(bets.into_iter().rev().collect()), expected);
+    }
+
+    #[test]
+    fn test_apply_adjustments() {
+        let mut predictions = vec![GamePrediction {
+            spread: 7.0,
+            _prediction_avg: 7.0,
+            ..create_prediction("Iowa Hawkeyes", "Nebraska Cornhuskers", 0.7)
+        }];
+        let adjustments = vec![
+            PredictionAdjustment {
+                team: "Iowa".to_string(),
+                spread_delta: -3.0,
+                prob_delta: -0.05,
+                note: "QB out".to_string(),
+            },
+            PredictionAdjustment {
+                team: "Nebraska".to_string(),
+                spread_delta: -1.5,
+                prob_delta: 0.0,
+                note: "Snow".to_string(),
+            },
+            PredictionAdjustment {
+                team: "Ohio State".to_string(),
+                spread_delta: 10.0,
+                prob_delta: 0.2,
+                note: "Not playing".to_string(),
+            },
+        ];
+
+        let notes = apply_adjustments(&mut predictions, &adjustments);
+
+        // Iowa loses 3 points and Nebraska 1.5, which gives 1.5 back to the home side
+        assert!((predictions[0].spread - 5.5).abs() < 1e-9);
+        assert!((predictions[0].home_win_prob - 0.65).abs() < 1e-9);
+        assert!((predictions[0].away_win_prob - 0.35).abs() < 1e-9);
+
+        // The odds feed may list the game the other way around
+        let recorded = adjustment_notes_for(&notes, "Nebraska Cornhuskers", "Iowa Hawkeyes");
+        assert_eq!(
+            recorded,
+            vec![
+                "Iowa: QB out (-3.0 pts, -5.0%)",
+                "Nebraska: Snow (-1.5 pts, +0.0%)"
+            ]
+        );
+        assert!(adjustment_notes_for(&notes, "Ohio State", "Michigan").is_empty());
+    }
+}
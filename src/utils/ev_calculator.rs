@@ -12,26 +12,50 @@ pub fn american_odds_to_probability(odds: i32) -> f64 {
     }
 }
 
-/// Convert probability to American odds
-pub fn _probability_to_american_odds(prob: f64) -> i32 {
+/// Convert probability to American odds, rounded to the nearest whole price
+/// The inverse of `american_odds_to_probability`; even money comes back as -100.
+pub fn probability_to_american_odds(prob: f64) -> i32 {
     if prob >= 0.5 {
         // Favorite (negative odds)
-        -((prob / (1.0 - prob)) * 100.0) as i32
+        -((prob / (1.0 - prob)) * 100.0).round() as i32
     } else {
         // Underdog (positive odds)
-        (((1.0 - prob) / prob) * 100.0) as i32
+        (((1.0 - prob) / prob) * 100.0).round() as i32
     }
 }
 
+/// Profit (excluding the returned stake) from a winning bet of `stake` at the given odds
+pub fn profit_on_win(odds: i32, stake: f64) -> f64 {
+    if odds > 0 {
+        stake * odds as f64 / 100.0
+    } else {
+        stake * 100.0 / odds.abs() as f64
+    }
+}
+
+/// American odds paying `profit_boost` more of the winnings (0.5 for a 50% profit boost),
+/// rounded to a whole price the way books display boosted odds
+pub fn boost_odds(odds: i32, profit_boost: f64) -> i32 {
+    let profit = profit_on_win(odds, 1.0) * (1.0 + profit_boost);
+    if profit >= 1.0 {
+        (profit * 100.0).round() as i32
+    } else {
+        -(100.0 / profit).round() as i32
+    }
+}
+
+/// Share of the bankroll the Kelly criterion stakes on a bet, or 0 for a bet with no edge
+/// f = (b * p - q) / b, where b is the profit per $1 staked
+pub fn kelly_fraction(model_prob: f64, odds: i32) -> f64 {
+    let b = profit_on_win(odds, 1.0);
+    ((b * model_prob - (1.0 - model_prob)) / b).max(0.0)
+}
+
 /// Calculate expected value for a bet
 /// EV = (probability of winning * amount won per bet) - (probability of losing * amount lost per bet)
 /// Returns EV as a percentage of the bet amount
 pub fn calculate_expected_value(model_prob: f64, odds: i32) -> f64 {
-    let win_amount = if odds > 0 {
-        odds as f64 / 100.0
-    } else {
-        100.0 / odds.abs() as f64
-    };
+    let win_amount = profit_on_win(odds, 1.0);
 
     let lose_amount = 1.0; // You lose your bet amount
     let prob_lose = 1.0 - model_prob;
@@ -40,6 +64,17 @@ pub fn calculate_expected_value(model_prob: f64, odds: i32) -> f64 {
     (model_prob * win_amount) - (prob_lose * lose_amount)
 }
 
+/// Remove the bookmaker's vig from a two-way market
+/// Normalizes both implied probabilities so they sum to 1, returning the fair
+/// probability of each side in the same order as the odds were given
+pub fn devig_two_way(odds_a: i32, odds_b: i32) -> (f64, f64) {
+    let prob_a = american_odds_to_probability(odds_a);
+    let prob_b = american_odds_to_probability(odds_b);
+    let total = prob_a + prob_b;
+
+    (prob_a / total, prob_b / total)
+}
+
 /// Calculate the probability of covering a spread
 /// Uses a normal distribution approximation based on the predicted spread
 ///
@@ -72,6 +107,252 @@ pub fn calculate_spread_cover_probability(model_spread: f64, bet_spread: f64, st
     1.0 - normal_cdf(z)
 }
 
+/// How a game's final score is modeled when pricing spreads and totals
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScoreModel {
+    /// Margin and total are each normal around the projection with a fixed std dev
+    Normal { std_dev: f64 },
+    /// Each team's score is an independent Poisson around its projected points
+    /// Handles low-scoring games and the tails of basketball totals better than a
+    /// single normal. Pushes on whole-number lines count as not covering.
+    Poisson,
+}
+
+impl ScoreModel {
+    /// Probability that a team projected to win by `projected_margin` (negative = projected
+    /// to lose) covers `bet_spread`, in a game with `projected_total` combined points
+    /// The normal model ignores the total.
+    pub fn cover_probability(
+        &self,
+        projected_margin: f64,
+        projected_total: f64,
+        bet_spread: f64,
+    ) -> f64 {
+        match *self {
+            ScoreModel::Normal { std_dev } => {
+                calculate_spread_cover_probability(projected_margin, bet_spread, std_dev)
+            }
+            ScoreModel::Poisson => {
+                let (team_mean, opponent_mean) = team_means(projected_margin, projected_total);
+                // Covers when margin + spread > 0
+                joint_score_probability(team_mean, opponent_mean, |team, opponent| {
+                    (team as f64 - opponent as f64) + bet_spread > 0.0
+                })
+            }
+        }
+    }
+
+    /// Probability that the combined score goes over `line`
+    pub fn over_probability(&self, projected_margin: f64, projected_total: f64, line: f64) -> f64 {
+        match *self {
+            ScoreModel::Normal { std_dev } => 1.0 - normal_cdf((line - projected_total) / std_dev),
+            ScoreModel::Poisson => {
+                let (team_mean, opponent_mean) = team_means(projected_margin, projected_total);
+                joint_score_probability(team_mean, opponent_mean, |team, opponent| {
+                    (team + opponent) as f64 > line
+                })
+            }
+        }
+    }
+}
+
+/// How much more often CFB games end on key margins than a smooth normal curve predicts
+/// Rough multipliers from historical final margins; field goals and touchdowns make 3 and 7
+/// far more common than their neighbors.
+const KEY_NUMBER_WEIGHTS: &[(i32, f64)] = &[(3, 1.7), (7, 1.4), (10, 1.15), (14, 1.2)];
+
+/// Largest final margin considered when summing over whole-point margins
+const MAX_MARGIN: i32 = 100;
+
+/// Relative likelihood that a team's final margin is exactly `margin` points
+/// The normal density over `[margin - 0.5, margin + 0.5]`, scaled up on key numbers. Not
+/// normalized; `spread_outcome` divides by the total over all margins.
+fn margin_weight(projected_margin: f64, margin: i32, std_dev: f64) -> f64 {
+    let lower = normal_cdf((margin as f64 - 0.5 - projected_margin) / std_dev);
+    let upper = normal_cdf((margin as f64 + 0.5 - projected_margin) / std_dev);
+    let key_weight = KEY_NUMBER_WEIGHTS
+        .iter()
+        .find(|(key, _)| *key == margin.abs())
+        .map_or(1.0, |(_, weight)| *weight);
+
+    (upper - lower) * key_weight
+}
+
+/// Chances that a spread bet wins, pushes or loses
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpreadOutcome {
+    pub win: f64,
+    pub push: f64,
+    pub loss: f64,
+}
+
+impl SpreadOutcome {
+    /// Expected profit per $1 staked; a push returns the stake
+    pub fn expected_value(&self, odds: i32) -> f64 {
+        self.win * profit_on_win(odds, 1.0) - self.loss
+    }
+}
+
+/// Win, push and loss probabilities for a spread bet, with final margins in whole points
+/// `projected_margin` is the bet team's projected margin (positive = team wins) and `line`
+/// is its spread (e.g. -3 must win by more than 3). Whole-number lines push when the
+/// margin lands on them.
+pub fn spread_outcome(projected_margin: f64, line: f64, std_dev: f64) -> SpreadOutcome {
+    let mut outcome = SpreadOutcome {
+        win: 0.0,
+        push: 0.0,
+        loss: 0.0,
+    };
+
+    for margin in -MAX_MARGIN..=MAX_MARGIN {
+        let weight = margin_weight(projected_margin, margin, std_dev);
+        let result = margin as f64 + line;
+        if result.abs() < 1e-9 {
+            outcome.push += weight;
+        } else if result > 0.0 {
+            outcome.win += weight;
+        } else {
+            outcome.loss += weight;
+        }
+    }
+
+    let total = outcome.win + outcome.push + outcome.loss;
+    SpreadOutcome {
+        win: outcome.win / total,
+        push: outcome.push / total,
+        loss: outcome.loss / total,
+    }
+}
+
+/// What buying a half point is worth on a spread bet
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HookValue {
+    pub line: f64,
+    pub hook_line: f64,
+    /// How much less likely the bet is to lose after buying the hook
+    pub prob_gain: f64,
+    pub ev: f64,
+    pub hook_ev: f64,
+}
+
+impl HookValue {
+    /// EV gained (or lost, if negative) per $1 by paying for the half point
+    pub fn ev_gain(&self) -> f64 {
+        self.hook_ev - self.ev
+    }
+}
+
+/// Value of buying a half point, moving a team's line from `line` to `line + 0.5`
+/// `odds` is the price at the original line and `hook_odds` the (usually worse) price after
+/// buying the hook. Moving across 3 or 7 turns many more losses into pushes, or pushes
+/// into wins, than moving across other numbers, so the extra juice is easier to justify.
+pub fn half_point_value(
+    projected_margin: f64,
+    line: f64,
+    std_dev: f64,
+    odds: i32,
+    hook_odds: i32,
+) -> HookValue {
+    let hook_line = line + 0.5;
+    let before = spread_outcome(projected_margin, line, std_dev);
+    let after = spread_outcome(projected_margin, hook_line, std_dev);
+
+    HookValue {
+        line,
+        hook_line,
+        prob_gain: before.loss - after.loss,
+        ev: before.expected_value(odds),
+        hook_ev: after.expected_value(hook_odds),
+    }
+}
+
+/// Chance that bets on both sides of a game win: the team's final margin covers `line`
+/// and the opponent's covers `opponent_line`
+/// Only possible when the lines leave a gap between them (a middle), e.g. -6.5 against
+/// +7.5 hits when the team wins by exactly 7.
+pub fn middle_probability(
+    projected_margin: f64,
+    line: f64,
+    opponent_line: f64,
+    std_dev: f64,
+) -> f64 {
+    let (mut middle, mut total) = (0.0, 0.0);
+    for margin in -MAX_MARGIN..=MAX_MARGIN {
+        let weight = margin_weight(projected_margin, margin, std_dev);
+        total += weight;
+        if margin as f64 + line > 1e-9 && opponent_line - margin as f64 > 1e-9 {
+            middle += weight;
+        }
+    }
+    middle / total
+}
+
+/// Split a projected margin and total into each team's projected points
+fn team_means(projected_margin: f64, projected_total: f64) -> (f64, f64) {
+    (
+        ((projected_total + projected_margin) / 2.0).max(0.0),
+        ((projected_total - projected_margin) / 2.0).max(0.0),
+    )
+}
+
+/// Poisson probabilities for 0, 1, 2, ... points, cut off once the tail is negligible
+fn poisson_pmf(mean: f64) -> Vec<f64> {
+    let max_points = (mean + 12.0 * mean.sqrt()).ceil() as usize + 10;
+    let mut pmf = Vec::with_capacity(max_points + 1);
+    let mut p = (-mean).exp();
+    pmf.push(p);
+    for k in 1..=max_points {
+        p *= mean / k as f64;
+        pmf.push(p);
+    }
+    pmf
+}
+
+/// Probability of all final scores (team, opponent) matching `event`, with each score an
+/// independent Poisson
+fn joint_score_probability(
+    team_mean: f64,
+    opponent_mean: f64,
+    event: impl Fn(usize, usize) -> bool,
+) -> f64 {
+    let team_pmf = poisson_pmf(team_mean);
+    let opponent_pmf = poisson_pmf(opponent_mean);
+
+    let mut prob = 0.0;
+    for (team, p_team) in team_pmf.iter().enumerate() {
+        for (opponent, p_opponent) in opponent_pmf.iter().enumerate() {
+            if event(team, opponent) {
+                prob += p_team * p_opponent;
+            }
+        }
+    }
+    prob
+}
+
+/// Spread (positive = team favored) that gives a team `win_prob` under the normal margin
+/// model, the inverse of `calculate_spread_cover_probability` at a line of 0
+/// Probabilities of 0 or 1 are clamped so the spread stays finite.
+pub fn win_prob_to_spread(win_prob: f64, std_dev: f64) -> f64 {
+    let target = win_prob.clamp(1e-6, 1.0 - 1e-6);
+    // Bisect on the same CDF approximation the cover model uses so the two round-trip
+    let (mut low, mut high) = (-10.0, 10.0);
+    for _ in 0..100 {
+        let mid = (low + high) / 2.0;
+        if normal_cdf(mid) < target {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    std_dev * (low + high) / 2.0
+}
+
+/// Chance a team wins outright given its spread (positive = team favored) under the normal
+/// margin model, the inverse of `win_prob_to_spread`
+pub fn spread_to_win_prob(spread: f64, std_dev: f64) -> f64 {
+    normal_cdf(spread / std_dev)
+}
+
 /// Approximation of the standard normal cumulative distribution function
 /// Using the error function approximation
 fn normal_cdf(x: f64) -> f64 {
@@ -99,6 +380,32 @@ fn erf(x: f64) -> f64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn prop_american_odds_round_trip(
+            odds in prop_oneof![-10_000i32..=-100, 101i32..=10_000]
+        ) {
+            prop_assert_eq!(
+                probability_to_american_odds(american_odds_to_probability(odds)),
+                odds
+            );
+        }
+
+        #[test]
+        fn prop_win_prob_spread_round_trip(prob in 0.01f64..0.99, std_dev in 8.0f64..20.0) {
+            let round_trip = spread_to_win_prob(win_prob_to_spread(prob, std_dev), std_dev);
+            prop_assert!((round_trip - prob).abs() < 1e-6);
+        }
+
+        #[test]
+        fn prop_probability_round_trip(prob in 0.01f64..0.99) {
+            // Whole-number prices are coarsest near even money, about 0.25% per point
+            let round_trip = american_odds_to_probability(probability_to_american_odds(prob));
+            prop_assert!((round_trip - prob).abs() < 0.003);
+        }
+    }
 
     #[test]
     fn test_american_odds_to_probability() {
@@ -113,6 +420,27 @@ mod tests {
         assert!((prob - 0.5).abs() < 0.01);
     }
 
+    #[test]
+    fn test_profit_on_win() {
+        assert!((profit_on_win(150, 100.0) - 150.0).abs() < 1e-9);
+        assert!((profit_on_win(-110, 110.0) - 100.0).abs() < 1e-9);
+        assert!((profit_on_win(-200, 1.0) - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_devig_two_way() {
+        // -110/-110 is a coin flip once the vig is removed
+        let (a, b) = devig_two_way(-110, -110);
+        assert!((a - 0.5).abs() < 1e-9);
+        assert!((b - 0.5).abs() < 1e-9);
+
+        // Fair probabilities always sum to 1 and keep the favorite ahead
+        let (fav, dog) = devig_two_way(-200, 170);
+        assert!((fav + dog - 1.0).abs() < 1e-9);
+        assert!(fav > american_odds_to_probability(170));
+        assert!(fav < american_odds_to_probability(-200));
+    }
+
     #[test]
     fn test_calculate_expected_value() {
         // Positive EV scenario: 60% win probability on +150 odds
@@ -124,6 +452,32 @@ mod tests {
         assert!(ev < 0.0);
     }
 
+    #[test]
+    fn test_win_prob_spread_conversions() {
+        // A pick'em is a coin flip
+        assert!(win_prob_to_spread(0.5, 12.0).abs() < 1e-6);
+        assert!((spread_to_win_prob(0.0, 12.0) - 0.5).abs() < 1e-9);
+
+        // A 7-point favorite wins about 72% of the time at a 12-point std dev, and about
+        // 76% at 10
+        assert!((spread_to_win_prob(7.0, 12.0) - 0.720).abs() < 0.001);
+        assert!((spread_to_win_prob(7.0, 10.0) - 0.758).abs() < 0.001);
+        assert!((win_prob_to_spread(0.758, 10.0) - 7.0).abs() < 0.05);
+
+        // The underdog's side mirrors the favorite's
+        assert!(
+            (spread_to_win_prob(-7.0, 12.0) - (1.0 - spread_to_win_prob(7.0, 12.0))).abs() < 1e-6
+        );
+        assert!((win_prob_to_spread(0.28, 12.0) + win_prob_to_spread(0.72, 12.0)).abs() < 1e-6);
+
+        // Same as covering a line of 0 in the cover model
+        assert!(
+            (spread_to_win_prob(3.5, 12.0) - calculate_spread_cover_probability(3.5, 0.0, 12.0))
+                .abs()
+                < 1e-9
+        );
+    }
+
     #[test]
     fn test_calculate_spread_cover_probability() {
         // If model predicts home team wins by 10, and spread is -7, should have high probability
@@ -156,4 +510,64 @@ mod tests {
         assert!((prob - 0.5).abs() < 0.1);
         println!("Prob: {}", prob);
     }
+
+    #[test]
+    fn test_score_model_normal_matches_spread_cover_probability() {
+        let model = ScoreModel::Normal { std_dev: 12.0 };
+        let prob = model.cover_probability(10.0, 50.0, -7.0);
+        assert!((prob - calculate_spread_cover_probability(10.0, -7.0, 12.0)).abs() < 1e-12);
+
+        // Projection equal to the total line is a coin flip
+        assert!((model.over_probability(0.0, 55.5, 55.5) - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_score_model_poisson() {
+        // Poisson probabilities over all scores sum to 1
+        let total: f64 = poisson_pmf(28.0).iter().sum();
+        assert!((total - 1.0).abs() < 1e-9);
+
+        let model = ScoreModel::Poisson;
+
+        // Evenly matched teams on a pick'em half-point line split the outcomes
+        let prob = model.cover_probability(0.0, 50.0, 0.5);
+        let opposite = model.cover_probability(0.0, 50.0, -0.5);
+        assert!((prob + opposite - 1.0).abs() < 1e-9);
+        assert!(prob > 0.5);
+
+        // Favorites cover short lines more often than long ones
+        let short = model.cover_probability(10.0, 50.0, -3.5);
+        let long = model.cover_probability(10.0, 50.0, -17.5);
+        assert!(short > 0.5 && long < 0.5);
+
+        // Totals track the projected points: a line below the projection goes over
+        assert!(model.over_probability(0.0, 140.0, 130.5) > 0.75);
+        assert!(model.over_probability(0.0, 140.0, 160.5) < 0.1);
+    }
+
+    #[test]
+    fn test_spread_outcome_pushes_on_whole_lines() {
+        let outcome = spread_outcome(3.0, -3.0, 14.0);
+        assert!((outcome.win + outcome.push + outcome.loss - 1.0).abs() < 1e-9);
+        assert!(outcome.push > 0.04);
+
+        let half_point = spread_outcome(3.0, -3.5, 14.0);
+        assert_eq!(half_point.push, 0.0);
+    }
+
+    #[test]
+    fn test_half_point_value_is_largest_on_key_numbers() {
+        // Buying -3.5 to -3 and -5.5 to -5 for a team projected to win by 4
+        let key = half_point_value(4.0, -3.5, 14.0, -110, -130);
+        let non_key = half_point_value(4.0, -5.5, 14.0, -110, -130);
+
+        assert!(key.prob_gain > 1.5 * non_key.prob_gain);
+        assert!(key.ev_gain() > non_key.ev_gain());
+        assert!((key.ev_gain() - (key.hook_ev - key.ev)).abs() < 1e-12);
+
+        // Crossing 7 is worth more than crossing 8
+        let seven = half_point_value(4.0, 6.5, 14.0, -110, -110);
+        let eight = half_point_value(4.0, 7.5, 14.0, -110, -110);
+        assert!(seven.prob_gain > eight.prob_gain);
+    }
 }
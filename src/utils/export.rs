@@ -0,0 +1,350 @@
+use crate::error::{CfbBettingError, Result};
+use crate::utils::arbitrage::{MoneylineArbitrage, SpreadArbitrage};
+use crate::utils::ev_analysis::{EvBetRecommendation, SpreadEvBetRecommendation};
+use crate::utils::feed::{betting_data_feed, FeedWeights, Opportunity};
+use crate::BettingData;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tracing::info;
+
+/// Marks a bet with positive expected value
+const POSITIVE_EV_EMOJI: &str = "🟢";
+
+/// Marks a bet at or below break-even
+const NON_POSITIVE_EV_EMOJI: &str = "⚪";
+
+/// Marks an arbitrage (always a locked-in profit)
+const ARBITRAGE_EMOJI: &str = "💰";
+
+/// Shown in place of an empty list
+const NO_PICKS: &str = "_None found._";
+
+/// Service name used in errors
+const WEBHOOK: &str = "Webhook";
+
+/// Which opportunities are worth a webhook ping
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WebhookConfig {
+    /// Most opportunities to post at once
+    pub top_n: usize,
+    /// Minimum EV for an EV bet, as a fraction (0.03 = 3%)
+    pub min_ev: f64,
+    /// Minimum guaranteed profit for an arbitrage, in percent
+    pub min_arb_profit: f64,
+}
+
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        Self {
+            top_n: 10,
+            min_ev: 0.03,
+            min_arb_profit: 1.0,
+        }
+    }
+}
+
+impl WebhookConfig {
+    /// Whether an opportunity clears the EV or profit threshold for its kind
+    fn is_worthwhile(&self, opportunity: &Opportunity) -> bool {
+        match opportunity {
+            Opportunity::MoneylineBet(bet) => bet.expected_value >= self.min_ev,
+            Opportunity::SpreadBet(bet) => bet.expected_value >= self.min_ev,
+            Opportunity::MoneylineArb(arb) => arb.profit_percentage >= self.min_arb_profit,
+            Opportunity::SpreadArb(arb) => arb.profit_percentage >= self.min_arb_profit,
+        }
+    }
+}
+
+fn ev_emoji(expected_value: f64) -> &'static str {
+    if expected_value > 0.0 {
+        POSITIVE_EV_EMOJI
+    } else {
+        NON_POSITIVE_EV_EMOJI
+    }
+}
+
+/// Join bullet lines, or say there's nothing to post
+fn bullet_list(lines: Vec<String>) -> String {
+    if lines.is_empty() {
+        NO_PICKS.to_string()
+    } else {
+        lines.join("\n")
+    }
+}
+
+/// " | stake $X" when the bet has been sized
+fn format_stake(stake: Option<f64>) -> String {
+    stake.map_or(String::new(), |stake| format!(" | stake ${:.2}", stake))
+}
+
+/// Moneyline EV bets as a Markdown bullet list for pasting into Discord or Slack
+pub fn format_as_markdown(bets: &[EvBetRecommendation]) -> String {
+    bullet_list(
+        bets.iter()
+            .map(|bet| {
+                format!(
+                    "- {} **{} ML ({:+})** @ {} | EV {:+.1}% | edge {:+.1}%{} | _{} @ {}_",
+                    ev_emoji(bet.expected_value),
+                    bet.team,
+                    bet.odds,
+                    bet.bookmaker,
+                    bet.expected_value * 100.0,
+                    bet.edge * 100.0,
+                    format_stake(bet.stake),
+                    bet.away_team,
+                    bet.home_team
+                )
+            })
+            .collect(),
+    )
+}
+
+/// Spread EV bets as a Markdown bullet list; see [`format_as_markdown`]
+pub fn format_spread_bets_as_markdown(bets: &[SpreadEvBetRecommendation]) -> String {
+    bullet_list(
+        bets.iter()
+            .map(|bet| {
+                format!(
+                    "- {} **{} {:+.1} ({:+})** @ {} | EV {:+.1}% | edge {:+.1}%{} | _{} @ {}_",
+                    ev_emoji(bet.expected_value),
+                    bet.team,
+                    bet.spread_line,
+                    bet.odds,
+                    bet.bookmaker,
+                    bet.expected_value * 100.0,
+                    bet.edge * 100.0,
+                    format_stake(bet.stake),
+                    bet.away_team,
+                    bet.home_team
+                )
+            })
+            .collect(),
+    )
+}
+
+/// Moneyline arbitrage as a Markdown bullet list, one line per opportunity
+pub fn format_moneyline_arbs_as_markdown(arbs: &[MoneylineArbitrage]) -> String {
+    bullet_list(
+        arbs.iter()
+            .map(|arb| {
+                format!(
+                    "- {} **{:.2}%** | {} ({:+}) @ {} [{:.1}%] + {} ({:+}) @ {} [{:.1}%]",
+                    ARBITRAGE_EMOJI,
+                    arb.profit_percentage,
+                    arb.home_team,
+                    arb.home_odds,
+                    arb.home_bookmaker,
+                    arb.home_stake_percentage,
+                    arb.away_team,
+                    arb.away_odds,
+                    arb.away_bookmaker,
+                    arb.away_stake_percentage
+                )
+            })
+            .collect(),
+    )
+}
+
+/// Spread arbitrage as a Markdown bullet list, one line per opportunity
+pub fn format_spread_arbs_as_markdown(arbs: &[SpreadArbitrage]) -> String {
+    bullet_list(
+        arbs.iter()
+            .map(|arb| {
+                format!(
+                    "- {} **{:.2}%** | {} {:+.1} ({:+}) @ {} [{:.1}%] + {} {:+.1} ({:+}) @ {} [{:.1}%]",
+                    ARBITRAGE_EMOJI,
+                    arb.profit_percentage,
+                    arb.side1_team,
+                    arb.side1_spread,
+                    arb.side1_odds,
+                    arb.side1_bookmaker,
+                    arb.side1_stake_percentage,
+                    arb.side2_team,
+                    arb.side2_spread,
+                    arb.side2_odds,
+                    arb.side2_bookmaker,
+                    arb.side2_stake_percentage
+                )
+            })
+            .collect(),
+    )
+}
+
+/// The best opportunities that clear the webhook thresholds, at most `top_n` of them
+pub fn webhook_opportunities(data: &BettingData, config: &WebhookConfig) -> Vec<Opportunity> {
+    betting_data_feed(data, &FeedWeights::default())
+        .into_iter()
+        .filter(|opportunity| config.is_worthwhile(opportunity))
+        .take(config.top_n)
+        .collect()
+}
+
+/// Markdown message listing the opportunities, best first
+pub fn format_webhook_message(opportunities: &[Opportunity]) -> String {
+    let lines: Vec<String> = opportunities
+        .iter()
+        .map(|opportunity| {
+            let (emoji, value) = match opportunity {
+                Opportunity::MoneylineBet(bet) => (
+                    ev_emoji(bet.expected_value),
+                    format!("EV {:+.1}%", bet.expected_value * 100.0),
+                ),
+                Opportunity::SpreadBet(bet) => (
+                    ev_emoji(bet.expected_value),
+                    format!("EV {:+.1}%", bet.expected_value * 100.0),
+                ),
+                Opportunity::MoneylineArb(arb) => (
+                    ARBITRAGE_EMOJI,
+                    format!("profit {:.2}%", arb.profit_percentage),
+                ),
+                Opportunity::SpreadArb(arb) => (
+                    ARBITRAGE_EMOJI,
+                    format!("profit {:.2}%", arb.profit_percentage),
+                ),
+            };
+            format!(
+                "- {} **{}** | {} | {} | _{}_",
+                emoji,
+                opportunity.kind(),
+                opportunity.description(),
+                value,
+                opportunity.matchup()
+            )
+        })
+        .collect();
+
+    format!(
+        "**Top {} betting opportunities**\n{}",
+        opportunities.len(),
+        bullet_list(lines)
+    )
+}
+
+/// Post the best opportunities to a Discord or Slack incoming webhook
+/// The message goes in both `content` (Discord) and `text` (Slack). Nothing is posted
+/// when no opportunity clears the thresholds. Returns how many were posted.
+pub async fn post_to_webhook(
+    url: &str,
+    data: &BettingData,
+    config: &WebhookConfig,
+) -> Result<usize> {
+    let opportunities = webhook_opportunities(data, config);
+    if opportunities.is_empty() {
+        info!("No opportunities above the webhook thresholds");
+        return Ok(0);
+    }
+
+    let message = format_webhook_message(&opportunities);
+    let response = crate::http_client_builder()
+        .build()
+        .map_err(|e| CfbBettingError::api(WEBHOOK, e))?
+        .post(url)
+        .json(&json!({ "content": message, "text": message }))
+        .send()
+        .await
+        .map_err(|e| CfbBettingError::api(WEBHOOK, e))?;
+    if !response.status().is_success() {
+        return Err(CfbBettingError::api_status(WEBHOOK, response.status()));
+    }
+
+    info!(
+        count = opportunities.len(),
+        "Posted opportunities to webhook"
+    );
+    Ok(opportunities.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_format_as_markdown() {
+        let bet: EvBetRecommendation = serde_json::from_value(json!({
+            "home_team": "Iowa Hawkeyes",
+            "away_team": "Nebraska Cornhuskers",
+            "team": "Nebraska Cornhuskers",
+            "bookmaker": "FanDuel",
+            "odds": 150,
+            "model_prob": 0.45,
+            "implied_prob": 0.4,
+            "expected_value": 0.125,
+            "edge": 0.05,
+        }))
+        .unwrap();
+
+        assert_eq!(
+            format_as_markdown(std::slice::from_ref(&bet)),
+            "- 🟢 **Nebraska Cornhuskers ML (+150)** @ FanDuel | EV +12.5% | edge +5.0% \
+             | _Nebraska Cornhuskers @ Iowa Hawkeyes_"
+        );
+
+        let sized = EvBetRecommendation {
+            stake: Some(25.0),
+            ..bet
+        };
+        assert!(format_as_markdown(&[sized]).contains("| stake $25.00 |"));
+        assert_eq!(format_as_markdown(&[]), NO_PICKS);
+    }
+
+    #[test]
+    fn test_webhook_opportunities_respect_thresholds() {
+        let bet = |team: &str, expected_value: f64| {
+            json!({
+                "home_team": "Iowa Hawkeyes",
+                "away_team": "Nebraska Cornhuskers",
+                "team": team,
+                "bookmaker": "FanDuel",
+                "odds": 150,
+                "model_prob": 0.45,
+                "implied_prob": 0.4,
+                "expected_value": expected_value,
+                "edge": 0.05,
+            })
+        };
+        let arb = |profit_percentage: f64| {
+            json!({
+                "home_team": "Duke Blue Devils",
+                "away_team": "UNC Tar Heels",
+                "home_bookmaker": "FanDuel",
+                "away_bookmaker": "DraftKings",
+                "home_odds": 105,
+                "away_odds": 102,
+                "profit_percentage": profit_percentage,
+                "home_stake_percentage": 50.0,
+                "away_stake_percentage": 50.0,
+            })
+        };
+        let data: BettingData = serde_json::from_value(json!({
+            "cfb_moneyline_bets": [bet("Nebraska Cornhuskers", 0.08), bet("Iowa Hawkeyes", 0.01)],
+            "cfb_spread_bets": [],
+            "cfb_moneyline_arbs": [],
+            "cfb_spread_arbs": [],
+            "cbb_moneyline_arbs": [arb(3.5), arb(0.4)],
+            "cbb_spread_arbs": [],
+            "cfb_game_results": [],
+            "cbb_game_results": [],
+            "cfb_moneyline_bet_results": [],
+            "cfb_spread_bet_results": [],
+        }))
+        .unwrap();
+
+        let opportunities = webhook_opportunities(&data, &WebhookConfig::default());
+
+        // The 1% EV bet and the 0.4% arb fall below the defaults
+        assert_eq!(opportunities.len(), 2);
+        assert!(matches!(opportunities[0], Opportunity::MoneylineBet(_)));
+        assert!(matches!(opportunities[1], Opportunity::MoneylineArb(_)));
+
+        let message = format_webhook_message(&opportunities);
+        assert!(message.starts_with("**Top 2 betting opportunities**\n- 🟢 **Moneyline EV**"));
+        assert!(message.contains("profit 3.50%"));
+
+        let top_one = WebhookConfig {
+            top_n: 1,
+            ..WebhookConfig::default()
+        };
+        assert_eq!(webhook_opportunities(&data, &top_one).len(), 1);
+    }
+}
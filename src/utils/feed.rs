@@ -0,0 +1,205 @@
+use crate::utils::arbitrage::{MoneylineArbitrage, SpreadArbitrage};
+use crate::utils::ev_analysis::{EvBetRecommendation, SpreadEvBetRecommendation};
+use crate::BettingData;
+use serde::{Deserialize, Serialize};
+
+/// How much each kind of opportunity's percentage counts towards its feed score
+/// A weight of 2.0 on arbitrage ranks a 1% arb level with a 2% EV bet.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FeedWeights {
+    pub moneyline_ev: f64,
+    pub spread_ev: f64,
+    pub arbitrage: f64,
+}
+
+impl Default for FeedWeights {
+    fn default() -> Self {
+        Self {
+            moneyline_ev: 1.0,
+            spread_ev: 1.0,
+            arbitrage: 1.0,
+        }
+    }
+}
+
+/// A single actionable bet or arbitrage
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Opportunity {
+    MoneylineBet(EvBetRecommendation),
+    SpreadBet(SpreadEvBetRecommendation),
+    MoneylineArb(MoneylineArbitrage),
+    SpreadArb(SpreadArbitrage),
+}
+
+impl Opportunity {
+    /// Common ranking score: EV% for bets, profit% for arbs, times the kind's weight
+    pub fn score(&self, weights: &FeedWeights) -> f64 {
+        match self {
+            Opportunity::MoneylineBet(bet) => bet.expected_value * 100.0 * weights.moneyline_ev,
+            Opportunity::SpreadBet(bet) => bet.expected_value * 100.0 * weights.spread_ev,
+            Opportunity::MoneylineArb(arb) => arb.profit_percentage * weights.arbitrage,
+            Opportunity::SpreadArb(arb) => arb.profit_percentage * weights.arbitrage,
+        }
+    }
+
+    /// Short label for the kind of opportunity
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Opportunity::MoneylineBet(_) => "Moneyline EV",
+            Opportunity::SpreadBet(_) => "Spread EV",
+            Opportunity::MoneylineArb(_) => "Moneyline Arb",
+            Opportunity::SpreadArb(_) => "Spread Arb",
+        }
+    }
+
+    /// The game, as "Away @ Home"
+    pub fn matchup(&self) -> String {
+        let (home, away) = match self {
+            Opportunity::MoneylineBet(bet) => (&bet.home_team, &bet.away_team),
+            Opportunity::SpreadBet(bet) => (&bet.home_team, &bet.away_team),
+            Opportunity::MoneylineArb(arb) => (&arb.home_team, &arb.away_team),
+            Opportunity::SpreadArb(arb) => (&arb.home_team, &arb.away_team),
+        };
+        format!("{} @ {}", away, home)
+    }
+
+    /// What to bet and where
+    pub fn description(&self) -> String {
+        match self {
+            Opportunity::MoneylineBet(bet) => {
+                format!("{} ML ({:+}) on {}", bet.team, bet.odds, bet.bookmaker)
+            }
+            Opportunity::SpreadBet(bet) => format!(
+                "{} {:+.1} ({:+}) on {}",
+                bet.team, bet.spread_line, bet.odds, bet.bookmaker
+            ),
+            Opportunity::MoneylineArb(arb) => format!(
+                "{} ({:+}) on {} / {} ({:+}) on {}",
+                arb.home_team,
+                arb.home_odds,
+                arb.home_bookmaker,
+                arb.away_team,
+                arb.away_odds,
+                arb.away_bookmaker
+            ),
+            Opportunity::SpreadArb(arb) => format!(
+                "{} {:+.1} ({:+}) on {} / {} {:+.1} ({:+}) on {}",
+                arb.side1_team,
+                arb.side1_spread,
+                arb.side1_odds,
+                arb.side1_bookmaker,
+                arb.side2_team,
+                arb.side2_spread,
+                arb.side2_odds,
+                arb.side2_bookmaker
+            ),
+        }
+    }
+}
+
+/// Merge EV bets and arbitrage opportunities into one list, highest score first
+pub fn build_feed(
+    moneyline_bets: &[EvBetRecommendation],
+    spread_bets: &[SpreadEvBetRecommendation],
+    moneyline_arbs: &[MoneylineArbitrage],
+    spread_arbs: &[SpreadArbitrage],
+    weights: &FeedWeights,
+) -> Vec<Opportunity> {
+    let mut feed: Vec<Opportunity> = moneyline_bets
+        .iter()
+        .cloned()
+        .map(Opportunity::MoneylineBet)
+        .chain(spread_bets.iter().cloned().map(Opportunity::SpreadBet))
+        .chain(
+            moneyline_arbs
+                .iter()
+                .cloned()
+                .map(Opportunity::MoneylineArb),
+        )
+        .chain(spread_arbs.iter().cloned().map(Opportunity::SpreadArb))
+        .collect();
+
+    feed.sort_by(|a, b| b.score(weights).total_cmp(&a.score(weights)));
+    feed
+}
+
+/// Feed of every CFB and CBB opportunity in the loaded betting data
+pub fn betting_data_feed(data: &BettingData, weights: &FeedWeights) -> Vec<Opportunity> {
+    let moneyline_arbs: Vec<MoneylineArbitrage> = data
+        .cfb_moneyline_arbs
+        .iter()
+        .chain(&data.cbb_moneyline_arbs)
+        .cloned()
+        .collect();
+    let spread_arbs: Vec<SpreadArbitrage> = data
+        .cfb_spread_arbs
+        .iter()
+        .chain(&data.cbb_spread_arbs)
+        .cloned()
+        .collect();
+
+    build_feed(
+        &data.cfb_moneyline_bets,
+        &data.cfb_spread_bets,
+        &moneyline_arbs,
+        &spread_arbs,
+        weights,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::data::load_from_cache;
+    use crate::OFFLINE_FIXTURE_FILE;
+
+    #[test]
+    fn test_build_feed_interleaves_by_score() {
+        let data: BettingData = load_from_cache(OFFLINE_FIXTURE_FILE).unwrap();
+        let weights = FeedWeights::default();
+
+        let feed = betting_data_feed(&data, &weights);
+
+        assert_eq!(
+            feed.len(),
+            data.cfb_moneyline_bets.len()
+                + data.cfb_spread_bets.len()
+                + data.cfb_moneyline_arbs.len()
+                + data.cfb_spread_arbs.len()
+                + data.cbb_moneyline_arbs.len()
+                + data.cbb_spread_arbs.len()
+        );
+        assert!(feed
+            .windows(2)
+            .all(|pair| pair[0].score(&weights) >= pair[1].score(&weights)));
+    }
+
+    #[test]
+    fn test_feed_weights() {
+        let data: BettingData = load_from_cache(OFFLINE_FIXTURE_FILE).unwrap();
+        let arb = Opportunity::MoneylineArb(data.cfb_moneyline_arbs[0].clone());
+        let bet = Opportunity::MoneylineBet(data.cfb_moneyline_bets[0].clone());
+
+        // Heavily weighting arbitrage puts it ahead of every bet
+        let weights = FeedWeights {
+            arbitrage: 1000.0,
+            ..FeedWeights::default()
+        };
+        let feed = build_feed(
+            &data.cfb_moneyline_bets[..1],
+            &[],
+            &data.cfb_moneyline_arbs[..1],
+            &[],
+            &weights,
+        );
+
+        assert!(matches!(feed[0], Opportunity::MoneylineArb(_)));
+        assert!(
+            (arb.score(&weights) - data.cfb_moneyline_arbs[0].profit_percentage * 1000.0).abs()
+                < 1e-9
+        );
+        assert!(
+            (bet.score(&weights) - data.cfb_moneyline_bets[0].expected_value * 100.0).abs() < 1e-9
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use crate::error::Result;
+use std::future::Future;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+/// Run `fetch` for every key with at most `max_in_flight` running at once
+/// A failed fetch doesn't abort the batch; every key comes back with its own result, in
+/// the order given.
+pub async fn fetch_concurrently<K, T, F, Fut>(
+    keys: Vec<K>,
+    max_in_flight: usize,
+    fetch: F,
+) -> Vec<(K, Result<T>)>
+where
+    K: Clone,
+    T: Send + 'static,
+    F: Fn(K) -> Fut,
+    Fut: Future<Output = Result<T>> + Send + 'static,
+{
+    let semaphore = Arc::new(Semaphore::new(max_in_flight.max(1)));
+    let mut tasks = JoinSet::new();
+    for (index, key) in keys.iter().enumerate() {
+        let semaphore = Arc::clone(&semaphore);
+        let request = fetch(key.clone());
+        tasks.spawn(async move {
+            let _permit = semaphore
+                .acquire_owned()
+                .await
+                .expect("fetch semaphore is never closed");
+            (index, request.await)
+        });
+    }
+
+    let mut results: Vec<Option<Result<T>>> = keys.iter().map(|_| None).collect();
+    while let Some(joined) = tasks.join_next().await {
+        match joined {
+            Ok((index, result)) => results[index] = Some(result),
+            Err(error) => std::panic::resume_unwind(error.into_panic()),
+        }
+    }
+
+    keys.into_iter()
+        .zip(results)
+        .map(|(key, result)| (key, result.expect("every fetch task finished")))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::CfbBettingError;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_fetch_concurrently_bounds_in_flight_and_keeps_failures() {
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+
+        let results = fetch_concurrently((0..12).collect(), 3, |id: u32| {
+            let in_flight = Arc::clone(&in_flight);
+            let peak = Arc::clone(&peak);
+            async move {
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                if id == 4 {
+                    Err(CfbBettingError::api_status(
+                        "Test",
+                        reqwest::StatusCode::NOT_FOUND,
+                    ))
+                } else {
+                    Ok(id * 10)
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(peak.load(Ordering::SeqCst), 3);
+        assert_eq!(results.len(), 12);
+        for (id, result) in &results {
+            match result {
+                Ok(value) => assert_eq!(*value, id * 10),
+                Err(_) => assert_eq!(*id, 4),
+            }
+        }
+    }
+}
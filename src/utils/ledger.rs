@@ -0,0 +1,351 @@
+use crate::error::Result;
+use crate::utils::data::{load_from_cache, save_to_cache};
+use crate::utils::ev_analysis::{BetResult, SpreadBetResult};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// One settled bet in the season ledger
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LedgerEntry {
+    /// Identifies the bet, so grading it again replaces the entry instead of adding another
+    pub key: String,
+    /// "Moneyline" or "Spread"
+    pub market: String,
+    /// e.g. "Iowa Hawkeyes ML (+150) @ FanDuel"
+    pub description: String,
+    /// "Away @ Home"
+    pub matchup: String,
+    pub season: i32,
+    pub week: i32,
+    /// Kickoff as CFBD reports it (RFC 3339), used to put the bets in order
+    pub start_date: String,
+    /// Amount wagered, in units
+    pub stake: f64,
+    /// Net result in units (negative for a loss)
+    pub units: f64,
+    pub won: bool,
+}
+
+/// Record and return for one week of the season
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct WeekSummary {
+    pub season: i32,
+    pub week: i32,
+    pub wins: usize,
+    pub losses: usize,
+    /// Units wagered
+    pub staked: f64,
+    /// Net units won
+    pub units: f64,
+}
+
+impl WeekSummary {
+    pub fn bets(&self) -> usize {
+        self.wins + self.losses
+    }
+
+    /// Net units per unit staked
+    pub fn roi(&self) -> f64 {
+        if self.staked > 0.0 {
+            self.units / self.staked
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Running total after one settled bet, for charting the equity curve
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EquityPoint {
+    /// 1 for the first settled bet
+    pub bet_number: usize,
+    pub start_date: String,
+    /// Cumulative net units through this bet
+    pub units: f64,
+}
+
+/// Every settled bet of the season, kept across runs in a JSON file
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Ledger {
+    /// Settled bets in kickoff order
+    pub entries: Vec<LedgerEntry>,
+}
+
+impl Ledger {
+    /// Load a ledger, or start an empty one if the file doesn't exist yet
+    pub fn load(path: &str) -> Result<Self> {
+        if Path::new(path).exists() {
+            load_from_cache(path)
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    pub fn save(&self, path: &str) -> Result<()> {
+        save_to_cache(self, path)
+    }
+
+    /// Add every graded bet, replacing any earlier entry for the same bet.
+    ///
+    /// Bets whose game hasn't finished are left out. `unit_size` converts stakes and
+    /// profits to units (1.0 when stakes are already flat units). Returns how many bets
+    /// were new to the ledger.
+    pub fn record(
+        &mut self,
+        moneyline_results: &[BetResult],
+        spread_results: &[SpreadBetResult],
+        unit_size: f64,
+    ) -> usize {
+        let settled = moneyline_results
+            .iter()
+            .filter_map(|result| {
+                let game = result.game_result.as_ref()?;
+                let bet = &result.bet;
+                let game_key = bet
+                    .game_id
+                    .clone()
+                    .unwrap_or_else(|| format!("{}-{}", game.season, game.id));
+                Some(LedgerEntry {
+                    key: format!("moneyline|{}|{}|{}", game_key, bet.team, bet.bookmaker),
+                    market: "Moneyline".to_string(),
+                    description: format!("{} ML ({:+}) @ {}", bet.team, bet.odds, bet.bookmaker),
+                    matchup: format!("{} @ {}", bet.away_team, bet.home_team),
+                    season: game.season,
+                    week: game.week,
+                    start_date: game.start_date.clone(),
+                    stake: result.stake / unit_size,
+                    units: result.profit()? / unit_size,
+                    won: result.bet_won?,
+                })
+            })
+            .chain(spread_results.iter().filter_map(|result| {
+                let game = result.game_result.as_ref()?;
+                let bet = &result.bet;
+                let game_key = bet
+                    .game_id
+                    .clone()
+                    .unwrap_or_else(|| format!("{}-{}", game.season, game.id));
+                Some(LedgerEntry {
+                    key: format!(
+                        "spread|{}|{}|{}|{}",
+                        game_key, bet.team, bet.spread_line, bet.bookmaker
+                    ),
+                    market: "Spread".to_string(),
+                    description: format!(
+                        "{} {:+.1} ({:+}) @ {}",
+                        bet.team, bet.spread_line, bet.odds, bet.bookmaker
+                    ),
+                    matchup: format!("{} @ {}", bet.away_team, bet.home_team),
+                    season: game.season,
+                    week: game.week,
+                    start_date: game.start_date.clone(),
+                    stake: result.stake / unit_size,
+                    units: result.profit()? / unit_size,
+                    won: result.bet_won?,
+                })
+            }));
+
+        let mut added = 0;
+        for entry in settled {
+            match self
+                .entries
+                .iter_mut()
+                .find(|existing| existing.key == entry.key)
+            {
+                Some(existing) => *existing = entry,
+                None => {
+                    self.entries.push(entry);
+                    added += 1;
+                }
+            }
+        }
+        self.entries
+            .sort_by(|a, b| a.start_date.cmp(&b.start_date).then(a.key.cmp(&b.key)));
+        added
+    }
+
+    pub fn wins(&self) -> usize {
+        self.entries.iter().filter(|entry| entry.won).count()
+    }
+
+    pub fn losses(&self) -> usize {
+        self.entries.len() - self.wins()
+    }
+
+    /// Net units won over the season
+    pub fn total_units(&self) -> f64 {
+        self.entries.iter().map(|entry| entry.units).sum()
+    }
+
+    /// Units wagered over the season
+    pub fn total_staked(&self) -> f64 {
+        self.entries.iter().map(|entry| entry.stake).sum()
+    }
+
+    /// Net units per unit staked
+    pub fn roi(&self) -> f64 {
+        let staked = self.total_staked();
+        if staked > 0.0 {
+            self.total_units() / staked
+        } else {
+            0.0
+        }
+    }
+
+    /// Longest runs of consecutive wins and consecutive losses, in kickoff order
+    pub fn longest_streaks(&self) -> (usize, usize) {
+        let (mut longest_win, mut longest_loss) = (0, 0);
+        let (mut wins, mut losses) = (0, 0);
+        for entry in &self.entries {
+            if entry.won {
+                wins += 1;
+                losses = 0;
+            } else {
+                losses += 1;
+                wins = 0;
+            }
+            longest_win = longest_win.max(wins);
+            longest_loss = longest_loss.max(losses);
+        }
+        (longest_win, longest_loss)
+    }
+
+    /// Record and return for each week, in season order
+    pub fn weeks(&self) -> Vec<WeekSummary> {
+        let mut weeks: BTreeMap<(i32, i32), WeekSummary> = BTreeMap::new();
+        for entry in &self.entries {
+            let week = weeks
+                .entry((entry.season, entry.week))
+                .or_insert_with(|| WeekSummary {
+                    season: entry.season,
+                    week: entry.week,
+                    ..WeekSummary::default()
+                });
+            if entry.won {
+                week.wins += 1;
+            } else {
+                week.losses += 1;
+            }
+            week.staked += entry.stake;
+            week.units += entry.units;
+        }
+        weeks.into_values().collect()
+    }
+
+    /// Cumulative units after each settled bet
+    pub fn equity_curve(&self) -> Vec<EquityPoint> {
+        self.entries
+            .iter()
+            .scan(0.0, |units, entry| {
+                *units += entry.units;
+                Some((entry, *units))
+            })
+            .enumerate()
+            .map(|(i, (entry, units))| EquityPoint {
+                bet_number: i + 1,
+                start_date: entry.start_date.clone(),
+                units,
+            })
+            .collect()
+    }
+}
+
+/// Add newly graded bets to the ledger file (creating it if needed) and save it
+pub fn update_ledger(
+    path: &str,
+    moneyline_results: &[BetResult],
+    spread_results: &[SpreadBetResult],
+    unit_size: f64,
+) -> Result<Ledger> {
+    let mut ledger = Ledger::load(path)?;
+    ledger.record(moneyline_results, spread_results, unit_size);
+    ledger.save(path)?;
+    Ok(ledger)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::game_results_api::GameResult;
+    use serde_json::json;
+
+    fn graded_bet(team: &str, odds: i32, week: i32, won: bool) -> BetResult {
+        let game: GameResult = serde_json::from_value(json!({
+            "id": week,
+            "season": 2025,
+            "week": week,
+            "seasonType": "regular",
+            "startDate": format!("2025-09-{:02}T19:30:00.000Z", week * 7),
+            "startTimeTBD": false,
+            "completed": true,
+            "neutralSite": false,
+            "conferenceGame": true,
+            "homeId": 1,
+            "homeTeam": "Iowa",
+            "homePoints": 24,
+            "awayId": 2,
+            "awayTeam": "Nebraska",
+            "awayPoints": 14,
+        }))
+        .unwrap();
+        serde_json::from_value(json!({
+            "bet": {
+                "home_team": "Iowa Hawkeyes",
+                "away_team": "Nebraska Cornhuskers",
+                "team": team,
+                "bookmaker": "FanDuel",
+                "odds": odds,
+                "model_prob": 0.5,
+                "implied_prob": 0.5,
+                "expected_value": 0.05,
+                "edge": 0.02,
+                "game_id": format!("game-{}", week),
+            },
+            "game_result": game,
+            "bet_won": won,
+            "actual_payout": if won { odds as f64 / 100.0 } else { 0.0 },
+            "stake": 1.0,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_ledger_tracks_units_streaks_and_weeks() {
+        let mut ledger = Ledger::default();
+        // Week 2 is recorded first; the ledger still orders bets by kickoff
+        ledger.record(&[graded_bet("Iowa Hawkeyes", 150, 2, false)], &[], 1.0);
+        let added = ledger.record(
+            &[
+                graded_bet("Iowa Hawkeyes", 120, 1, true),
+                graded_bet("Nebraska Cornhuskers", 200, 1, true),
+                graded_bet("Iowa Hawkeyes", 150, 2, false),
+                graded_bet("Iowa Hawkeyes", 100, 3, false),
+            ],
+            &[],
+            1.0,
+        );
+
+        // Regrading the week 2 bet replaced it
+        assert_eq!(added, 3);
+        assert_eq!(ledger.entries.len(), 4);
+        assert_eq!((ledger.wins(), ledger.losses()), (2, 2));
+        assert!((ledger.total_units() - 1.2).abs() < 1e-9);
+        assert!((ledger.roi() - 0.3).abs() < 1e-9);
+        assert_eq!(ledger.longest_streaks(), (2, 2));
+
+        let weeks = ledger.weeks();
+        assert_eq!(weeks.len(), 3);
+        assert_eq!((weeks[0].week, weeks[0].wins), (1, 2));
+        assert!((weeks[0].units - 3.2).abs() < 1e-9);
+
+        let curve: Vec<f64> = ledger
+            .equity_curve()
+            .iter()
+            .map(|point| point.units)
+            .collect();
+        assert_eq!(curve.len(), 4);
+        assert!((curve[1] - 3.2).abs() < 1e-9);
+        assert!((curve[3] - 1.2).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,405 @@
+use crate::models::{BettingOdds, Game};
+use crate::scrapers::prediction_tracker::GamePrediction;
+use crate::utils::ev_calculator::devig_two_way;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Whether a bookmaker's line existed in both snapshots or only one of them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BookChange {
+    Moved,
+    Added,
+    Removed,
+}
+
+/// How one bookmaker's line on a game moved between two odds snapshots
+/// All values are from the home team's perspective
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LineMove {
+    pub game_id: String,
+    pub home_team: String,
+    pub away_team: String,
+    pub bookmaker: String,
+    pub change: BookChange,
+    pub moneyline_before: Option<i32>,
+    pub moneyline_after: Option<i32>,
+    pub spread_before: Option<f64>,
+    pub spread_after: Option<f64>,
+    /// De-vigged home win probability
+    pub fair_prob_before: Option<f64>,
+    pub fair_prob_after: Option<f64>,
+}
+
+impl LineMove {
+    /// Change in the home moneyline price
+    pub fn moneyline_delta(&self) -> Option<i32> {
+        Some(self.moneyline_after? - self.moneyline_before?)
+    }
+
+    /// Change in the home spread (negative = home became a bigger favorite)
+    pub fn spread_delta(&self) -> Option<f64> {
+        Some(self.spread_after? - self.spread_before?)
+    }
+
+    /// Change in the de-vigged home win probability
+    pub fn fair_prob_delta(&self) -> Option<f64> {
+        Some(self.fair_prob_after? - self.fair_prob_before?)
+    }
+
+    /// Format the line move as a readable string
+    pub fn format(&self) -> String {
+        let matchup = format!("{} @ {}", self.away_team, self.home_team);
+        match self.change {
+            BookChange::Added => format!("{} | {} | added", matchup, self.bookmaker),
+            BookChange::Removed => format!("{} | {} | removed", matchup, self.bookmaker),
+            BookChange::Moved => {
+                let moneyline = match (self.moneyline_before, self.moneyline_after) {
+                    (Some(before), Some(after)) => format!("ML: {:+} -> {:+}", before, after),
+                    _ => "ML: n/a".to_string(),
+                };
+                let spread = match (self.spread_before, self.spread_after) {
+                    (Some(before), Some(after)) => {
+                        format!("Spread: {:+.1} -> {:+.1}", before, after)
+                    }
+                    _ => "Spread: n/a".to_string(),
+                };
+                let fair_prob = match self.fair_prob_delta() {
+                    Some(delta) => format!("No-vig: {:+.1}%", delta * 100.0),
+                    None => "No-vig: n/a".to_string(),
+                };
+                format!(
+                    "{} | {} | {} | {} | {}",
+                    matchup, self.bookmaker, moneyline, spread, fair_prob
+                )
+            }
+        }
+    }
+
+    /// Size of the move used for ranking: no-vig probability change first, then spread change
+    fn magnitude(&self) -> (f64, f64) {
+        (
+            self.fair_prob_delta().map_or(0.0, f64::abs),
+            self.spread_delta().map_or(0.0, f64::abs),
+        )
+    }
+}
+
+/// Home moneyline, home spread and de-vigged home win probability for one book
+fn home_line(game: &Game, odds: &BettingOdds) -> (Option<i32>, Option<f64>, Option<f64>) {
+    let home_price = odds
+        .moneyline
+        .iter()
+        .find(|ml| ml.team == game.home_team)
+        .map(|ml| ml.price);
+    let away_price = odds
+        .moneyline
+        .iter()
+        .find(|ml| ml.team == game.away_team)
+        .map(|ml| ml.price);
+    let home_spread = odds
+        .spreads
+        .iter()
+        .find(|spread| spread.team == game.home_team)
+        .map(|spread| spread.point);
+
+    let fair_prob = match (home_price, away_price) {
+        (Some(home), Some(away)) => Some(devig_two_way(home, away).0),
+        _ => None,
+    };
+
+    (home_price, home_spread, fair_prob)
+}
+
+/// Compare two odds snapshots and report every per-book line move
+/// Games are matched by id. Books present in only one snapshot are reported as
+/// added or removed. Moves are sorted largest first (by de-vigged probability change,
+/// then spread change), with added/removed books last.
+pub fn compare_snapshots(
+    before: &[(Game, Vec<BettingOdds>)],
+    after: &[(Game, Vec<BettingOdds>)],
+) -> Vec<LineMove> {
+    let before_by_id: HashMap<&str, &(Game, Vec<BettingOdds>)> = before
+        .iter()
+        .map(|entry| (entry.0.id.as_str(), entry))
+        .collect();
+
+    let mut moves = Vec::new();
+
+    for (game, after_odds) in after {
+        let Some((before_game, before_odds)) = before_by_id.get(game.id.as_str()) else {
+            continue;
+        };
+
+        let line_move = |bookmaker: &str, change| LineMove {
+            game_id: game.id.clone(),
+            home_team: game.home_team.clone(),
+            away_team: game.away_team.clone(),
+            bookmaker: bookmaker.to_string(),
+            change,
+            moneyline_before: None,
+            moneyline_after: None,
+            spread_before: None,
+            spread_after: None,
+            fair_prob_before: None,
+            fair_prob_after: None,
+        };
+
+        for new_odds in after_odds {
+            let (moneyline_after, spread_after, fair_prob_after) = home_line(game, new_odds);
+            let mut entry = match before_odds
+                .iter()
+                .find(|old| old.bookmaker == new_odds.bookmaker)
+            {
+                Some(old_odds) => {
+                    let (moneyline_before, spread_before, fair_prob_before) =
+                        home_line(before_game, old_odds);
+                    LineMove {
+                        moneyline_before,
+                        spread_before,
+                        fair_prob_before,
+                        ..line_move(&new_odds.bookmaker, BookChange::Moved)
+                    }
+                }
+                None => line_move(&new_odds.bookmaker, BookChange::Added),
+            };
+            entry.moneyline_after = moneyline_after;
+            entry.spread_after = spread_after;
+            entry.fair_prob_after = fair_prob_after;
+            moves.push(entry);
+        }
+
+        for old_odds in before_odds {
+            if !after_odds
+                .iter()
+                .any(|new| new.bookmaker == old_odds.bookmaker)
+            {
+                let (moneyline_before, spread_before, fair_prob_before) =
+                    home_line(before_game, old_odds);
+                moves.push(LineMove {
+                    moneyline_before,
+                    spread_before,
+                    fair_prob_before,
+                    ..line_move(&old_odds.bookmaker, BookChange::Removed)
+                });
+            }
+        }
+    }
+
+    moves.sort_by(|a, b| {
+        (a.change != BookChange::Moved)
+            .cmp(&(b.change != BookChange::Moved))
+            .then_with(|| {
+                b.magnitude()
+                    .partial_cmp(&a.magnitude())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+    });
+
+    moves
+}
+
+/// Smallest move from the opening line (in points) reported as a steam move by default
+pub const DEFAULT_STEAM_THRESHOLD: f64 = 3.0;
+
+/// A game whose market line has moved significantly since it opened
+/// Lines are from the home team's perspective (positive = home favored)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SteamMove {
+    pub home_team: String,
+    pub away_team: String,
+    pub opening_spread: f64,
+    pub current_spread: f64,
+    /// Prediction average the market is moving toward or away from
+    pub model_spread: f64,
+}
+
+impl SteamMove {
+    /// Points the line has moved since open (positive = toward the home team)
+    pub fn move_points(&self) -> f64 {
+        self.current_spread - self.opening_spread
+    }
+
+    /// Whether the market has moved closer to the model than where it opened
+    pub fn toward_model(&self) -> bool {
+        (self.current_spread - self.model_spread).abs()
+            < (self.opening_spread - self.model_spread).abs()
+    }
+
+    /// Format the steam move as a readable string
+    pub fn format(&self) -> String {
+        let team = if self.move_points() > 0.0 {
+            &self.home_team
+        } else {
+            &self.away_team
+        };
+        format!(
+            "{} @ {} | Open: {:+.1} -> Now: {:+.1} ({:.1} pts toward {}) | Model: {:+.1} | Moving {} model",
+            self.away_team,
+            self.home_team,
+            self.opening_spread,
+            self.current_spread,
+            self.move_points().abs(),
+            team,
+            self.model_spread,
+            if self.toward_model() { "toward" } else { "away from" }
+        )
+    }
+}
+
+/// Games whose line has moved at least `min_move` points from the opening line,
+/// largest moves first
+/// Predictions without an opening line are skipped.
+pub fn find_steam_moves(predictions: &[GamePrediction], min_move: f64) -> Vec<SteamMove> {
+    let mut moves: Vec<SteamMove> = predictions
+        .iter()
+        .filter_map(|pred| {
+            Some(SteamMove {
+                home_team: pred.home_team.clone(),
+                away_team: pred.away_team.clone(),
+                opening_spread: pred.opening_spread?,
+                current_spread: pred.spread,
+                model_spread: pred._prediction_avg,
+            })
+        })
+        .filter(|steam| steam.move_points().abs() >= min_move)
+        .collect();
+
+    moves.sort_by(|a, b| {
+        b.move_points()
+            .abs()
+            .partial_cmp(&a.move_points().abs())
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    moves
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{MoneylineOdds, SpreadOdds};
+    use chrono::Utc;
+
+    fn create_game(id: &str) -> Game {
+        Game {
+            id: id.to_string(),
+            home_team: "Iowa Hawkeyes".to_string(),
+            away_team: "Nebraska Cornhuskers".to_string(),
+            commence_time: Utc::now(),
+            sport_title: "NCAAF".to_string(),
+            is_live: false,
+        }
+    }
+
+    fn create_odds(bookmaker: &str, home_price: i32, away_price: i32, spread: f64) -> BettingOdds {
+        BettingOdds {
+            game_id: "game".to_string(),
+            bookmaker: bookmaker.to_string(),
+            last_update: Utc::now(),
+            moneyline: vec![
+                MoneylineOdds {
+                    team: "Iowa Hawkeyes".to_string(),
+                    price: home_price,
+                },
+                MoneylineOdds {
+                    team: "Nebraska Cornhuskers".to_string(),
+                    price: away_price,
+                },
+            ],
+            spreads: vec![SpreadOdds {
+                team: "Iowa Hawkeyes".to_string(),
+                point: spread,
+                price: -110,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_compare_snapshots_reports_moves_largest_first() {
+        let before = vec![(
+            create_game("game"),
+            vec![
+                create_odds("DraftKings", -150, 130, -3.5),
+                create_odds("FanDuel", -150, 130, -3.5),
+                create_odds("BetMGM", -150, 130, -3.5),
+            ],
+        )];
+        let after = vec![(
+            create_game("game"),
+            vec![
+                create_odds("DraftKings", -160, 140, -3.5),
+                create_odds("FanDuel", -220, 180, -6.5),
+                create_odds("Caesars", -155, 135, -4.0),
+            ],
+        )];
+
+        let moves = compare_snapshots(&before, &after);
+
+        assert_eq!(moves.len(), 4);
+        assert_eq!(moves[0].bookmaker, "FanDuel");
+        assert_eq!(moves[0].moneyline_delta(), Some(-70));
+        assert_eq!(moves[0].spread_delta(), Some(-3.0));
+        assert!(moves[0].fair_prob_delta().unwrap() > 0.0);
+        assert_eq!(moves[1].bookmaker, "DraftKings");
+
+        let changes: Vec<_> = moves[2..]
+            .iter()
+            .map(|m| (&*m.bookmaker, m.change))
+            .collect();
+        assert!(changes.contains(&("Caesars", BookChange::Added)));
+        assert!(changes.contains(&("BetMGM", BookChange::Removed)));
+    }
+
+    #[test]
+    fn test_compare_snapshots_skips_unmatched_games() {
+        let before = vec![(
+            create_game("old"),
+            vec![create_odds("FanDuel", -150, 130, -3.5)],
+        )];
+        let after = vec![(
+            create_game("new"),
+            vec![create_odds("FanDuel", -200, 170, -5.5)],
+        )];
+
+        assert!(compare_snapshots(&before, &after).is_empty());
+    }
+
+    fn create_prediction(
+        home: &str,
+        opening: Option<f64>,
+        current: f64,
+        model: f64,
+    ) -> GamePrediction {
+        GamePrediction {
+            home_team: home.to_string(),
+            away_team: "Nebraska".to_string(),
+            spread: current,
+            opening_spread: opening,
+            home_win_prob: 0.5,
+            away_win_prob: 0.5,
+            _prediction_avg: model,
+            spread_derived: false,
+            home_cover_prob: None,
+            source: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_find_steam_moves() {
+        let predictions = vec![
+            create_prediction("Iowa", Some(3.0), 4.0, 6.0),
+            create_prediction("Purdue", Some(-3.0), -7.5, 2.0),
+            create_prediction("Illinois", Some(1.0), 5.0, 6.0),
+            create_prediction("Indiana", None, 10.0, 6.0),
+        ];
+
+        let moves = find_steam_moves(&predictions, DEFAULT_STEAM_THRESHOLD);
+
+        assert_eq!(moves.len(), 2);
+        assert_eq!(moves[0].home_team, "Purdue");
+        assert_eq!(moves[0].move_points(), -4.5);
+        assert!(!moves[0].toward_model());
+        assert_eq!(moves[1].home_team, "Illinois");
+        assert!(moves[1].toward_model());
+    }
+}
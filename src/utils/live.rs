@@ -0,0 +1,377 @@
+use crate::api::game_results_api::GameResult;
+use crate::utils::ev_analysis::{
+    extract_school_name, EvBetRecommendation, ResultMatcher, SpreadEvBetRecommendation,
+    SPREAD_STD_DEV,
+};
+use crate::utils::ev_calculator::calculate_spread_cover_probability;
+use serde::{Deserialize, Serialize};
+
+/// Quarters in a regulation college football game
+const REGULATION_QUARTERS: f64 = 4.0;
+
+/// Most of the game an unfinished game is treated as having played, so there's always some
+/// uncertainty left until the result is final (also covers overtime)
+const MAX_LIVE_ELAPSED: f64 = 0.98;
+
+/// Where an open bet stands right now
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LiveStatus {
+    Winning,
+    Losing,
+    /// Exactly on the line (or tied, for a moneyline)
+    Even,
+}
+
+/// An open bet in a game that has started, graded against the current score
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiveBet {
+    pub home_team: String,
+    pub away_team: String,
+    pub team: String,
+    pub bookmaker: String,
+    pub odds: i32,
+    /// Spread line for spread bets, `None` for moneylines
+    pub spread_line: Option<f64>,
+    pub home_points: i32,
+    pub away_points: i32,
+    /// Estimated share of the game played, from 0.0 to 1.0
+    pub fraction_elapsed: f64,
+    pub completed: bool,
+    /// Model win/cover probability before kickoff
+    pub pregame_prob: f64,
+    /// Win/cover probability given the current score and time left
+    pub live_prob: f64,
+    pub status: LiveStatus,
+}
+
+impl LiveBet {
+    /// Format the live bet as a readable string
+    pub fn format(&self) -> String {
+        let bet = match self.spread_line {
+            Some(line) => format!("{} {:+.1}", self.team, line),
+            None => format!("{} ML", self.team),
+        };
+        format!(
+            "{} @ {} | {} ({:+}) on {} | Score: {}-{} ({:.0}% played) | {:?} | Live: {:.1}% (pregame {:.1}%)",
+            self.away_team,
+            self.home_team,
+            bet,
+            self.odds,
+            self.bookmaker,
+            self.away_points,
+            self.home_points,
+            self.fraction_elapsed * 100.0,
+            self.status,
+            self.live_prob * 100.0,
+            self.pregame_prob * 100.0
+        )
+    }
+}
+
+/// Estimated share of the game played, or `None` if it hasn't started
+/// Uses the number of quarters in the line scores and assumes the current quarter is half
+/// over; without line scores a started game is assumed to be in the first quarter.
+pub fn fraction_elapsed(result: &GameResult) -> Option<f64> {
+    if result.completed {
+        return Some(1.0);
+    }
+    result.home_points?;
+
+    let quarters = result
+        .home_line_scores
+        .as_ref()
+        .map_or(0, |scores| scores.len())
+        .max(1);
+    Some(((quarters as f64 - 0.5) / REGULATION_QUARTERS).min(MAX_LIVE_ELAPSED))
+}
+
+/// Probability that a team covers `spread_line` given the score so far
+/// The rest of the game is modeled like the pregame spread model, scaled to the time left:
+/// the team is expected to add `pregame_margin` times the share remaining to its
+/// `current_margin`, with the std dev shrinking with the square root of the time left.
+pub fn live_cover_probability(
+    pregame_margin: f64,
+    current_margin: f64,
+    fraction_elapsed: f64,
+    spread_line: f64,
+    std_dev: f64,
+) -> f64 {
+    let remaining = (1.0 - fraction_elapsed).max(0.0);
+    if remaining == 0.0 {
+        return if current_margin + spread_line > 0.0 {
+            1.0
+        } else {
+            0.0
+        };
+    }
+
+    calculate_spread_cover_probability(
+        current_margin + pregame_margin * remaining,
+        spread_line,
+        std_dev * remaining.sqrt(),
+    )
+}
+
+/// Projected margin that gives a team `win_prob` under the spread model
+/// Found by bisection since the normal CDF has no closed-form inverse.
+fn margin_for_win_prob(win_prob: f64, std_dev: f64) -> f64 {
+    let (mut low, mut high) = (-100.0, 100.0);
+    for _ in 0..60 {
+        let mid = (low + high) / 2.0;
+        if calculate_spread_cover_probability(mid, 0.0, std_dev) < win_prob {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    (low + high) / 2.0
+}
+
+/// The parts of a moneyline or spread bet needed to grade it live
+struct OpenBet<'a> {
+    home_team: &'a str,
+    away_team: &'a str,
+    team: &'a str,
+    bookmaker: &'a str,
+    odds: i32,
+    spread_line: Option<f64>,
+    pregame_prob: f64,
+    /// Model margin for the team bet on, before kickoff
+    pregame_team_margin: f64,
+}
+
+/// Grade one bet against a game in progress (or finished)
+fn live_bet(result: &GameResult, bet: OpenBet) -> Option<LiveBet> {
+    let elapsed = fraction_elapsed(result)?;
+    let home_points = result.home_points.unwrap_or(0);
+    let away_points = result.away_points.unwrap_or(0);
+
+    let is_home_team = extract_school_name(bet.team) == extract_school_name(&result.home_team);
+    let current_margin = if is_home_team {
+        home_points - away_points
+    } else {
+        away_points - home_points
+    } as f64;
+
+    let line = bet.spread_line.unwrap_or(0.0);
+    let live_prob = live_cover_probability(
+        bet.pregame_team_margin,
+        current_margin,
+        elapsed,
+        line,
+        SPREAD_STD_DEV,
+    );
+    let status = match current_margin + line {
+        margin if margin > 0.0 => LiveStatus::Winning,
+        margin if margin < 0.0 => LiveStatus::Losing,
+        _ => LiveStatus::Even,
+    };
+
+    Some(LiveBet {
+        home_team: bet.home_team.to_string(),
+        away_team: bet.away_team.to_string(),
+        team: bet.team.to_string(),
+        bookmaker: bet.bookmaker.to_string(),
+        odds: bet.odds,
+        spread_line: bet.spread_line,
+        home_points,
+        away_points,
+        fraction_elapsed: elapsed,
+        completed: result.completed,
+        pregame_prob: bet.pregame_prob,
+        live_prob,
+        status,
+    })
+}
+
+/// Grade moneyline bets whose games have started against the current scores
+/// The pregame margin is backed out of the bet's model win probability. Results are matched
+/// by teams and kickoff, so a rematch isn't graded as the game bet on.
+pub fn grade_live_moneyline_bets(
+    bets: &[EvBetRecommendation],
+    results: &[GameResult],
+) -> Vec<LiveBet> {
+    let results = ResultMatcher::new(results);
+
+    bets.iter()
+        .filter_map(|bet| {
+            live_bet(
+                results.find(&bet.home_team, &bet.away_team, bet.commence_time)?,
+                OpenBet {
+                    home_team: &bet.home_team,
+                    away_team: &bet.away_team,
+                    team: &bet.team,
+                    bookmaker: &bet.bookmaker,
+                    odds: bet.odds,
+                    spread_line: None,
+                    pregame_prob: bet.model_prob,
+                    pregame_team_margin: margin_for_win_prob(bet.model_prob, SPREAD_STD_DEV),
+                },
+            )
+        })
+        .collect()
+}
+
+/// Grade spread bets whose games have started against the current scores
+/// Results are matched like `grade_live_moneyline_bets`.
+pub fn grade_live_spread_bets(
+    bets: &[SpreadEvBetRecommendation],
+    results: &[GameResult],
+) -> Vec<LiveBet> {
+    let results = ResultMatcher::new(results);
+
+    bets.iter()
+        .filter_map(|bet| {
+            // The model spread is from the home team's perspective
+            let is_home_team =
+                extract_school_name(&bet.team) == extract_school_name(&bet.home_team);
+            let pregame_team_margin = if is_home_team {
+                bet.model_spread
+            } else {
+                -bet.model_spread
+            };
+            live_bet(
+                results.find(&bet.home_team, &bet.away_team, bet.commence_time)?,
+                OpenBet {
+                    home_team: &bet.home_team,
+                    away_team: &bet.away_team,
+                    team: &bet.team,
+                    bookmaker: &bet.bookmaker,
+                    odds: bet.odds,
+                    spread_line: Some(bet.spread_line),
+                    pregame_prob: bet.model_prob,
+                    pregame_team_margin,
+                },
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn create_result(
+        home_points: i32,
+        away_points: i32,
+        quarters: usize,
+        completed: bool,
+    ) -> GameResult {
+        serde_json::from_value(json!({
+            "id": 1,
+            "season": 2025,
+            "week": 3,
+            "seasonType": "regular",
+            "startDate": "2025-09-13T19:30:00.000Z",
+            "startTimeTBD": false,
+            "completed": completed,
+            "neutralSite": false,
+            "conferenceGame": true,
+            "homeId": 1,
+            "homeTeam": "Iowa",
+            "homePoints": home_points,
+            "homeLineScores": vec![0.0; quarters],
+            "awayId": 2,
+            "awayTeam": "Nebraska",
+            "awayPoints": away_points,
+            "awayLineScores": vec![0.0; quarters],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_live_cover_probability() {
+        // At kickoff the live probability matches the pregame model
+        let pregame = calculate_spread_cover_probability(7.0, -3.5, SPREAD_STD_DEV);
+        assert!(
+            (live_cover_probability(7.0, 0.0, 0.0, -3.5, SPREAD_STD_DEV) - pregame).abs() < 1e-9
+        );
+
+        // The same lead is worth more the later it is
+        let early = live_cover_probability(0.0, 10.0, 0.25, 0.0, SPREAD_STD_DEV);
+        let late = live_cover_probability(0.0, 10.0, 0.9, 0.0, SPREAD_STD_DEV);
+        assert!(early > 0.5 && late > early);
+
+        // Once the game is over it's certain
+        assert_eq!(
+            live_cover_probability(0.0, 4.0, 1.0, -3.5, SPREAD_STD_DEV),
+            1.0
+        );
+        assert_eq!(
+            live_cover_probability(0.0, 3.0, 1.0, -3.5, SPREAD_STD_DEV),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_margin_for_win_prob() {
+        assert!(margin_for_win_prob(0.5, SPREAD_STD_DEV).abs() < 1e-6);
+        let margin = margin_for_win_prob(0.75, SPREAD_STD_DEV);
+        let prob = calculate_spread_cover_probability(margin, 0.0, SPREAD_STD_DEV);
+        assert!(margin > 0.0 && (prob - 0.75).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_fraction_elapsed() {
+        assert_eq!(fraction_elapsed(&create_result(0, 0, 0, true)), Some(1.0));
+        assert_eq!(
+            fraction_elapsed(&create_result(7, 3, 2, false)),
+            Some(0.375)
+        );
+        assert_eq!(
+            fraction_elapsed(&create_result(24, 24, 5, false)),
+            Some(MAX_LIVE_ELAPSED)
+        );
+    }
+
+    #[test]
+    fn test_grade_live_spread_bets() {
+        let bet: SpreadEvBetRecommendation = serde_json::from_value(json!({
+            "home_team": "Iowa Hawkeyes",
+            "away_team": "Nebraska Cornhuskers",
+            "team": "Nebraska Cornhuskers",
+            "spread_line": 6.5,
+            "bookmaker": "FanDuel",
+            "odds": -110,
+            "model_spread": 3.0,
+            "model_prob": 0.6,
+            "implied_prob": 0.524,
+            "expected_value": 0.15,
+            "edge": 0.076,
+        }))
+        .unwrap();
+
+        // Nebraska +6.5 down 10 in the third quarter is losing
+        let live = grade_live_spread_bets(
+            std::slice::from_ref(&bet),
+            &[create_result(17, 7, 3, false)],
+        );
+        assert_eq!(live.len(), 1);
+        assert_eq!(live[0].status, LiveStatus::Losing);
+        assert!(live[0].live_prob < bet.model_prob);
+        assert!(!live[0].completed);
+
+        // Down 3 it's winning
+        let live = grade_live_spread_bets(
+            std::slice::from_ref(&bet),
+            &[create_result(10, 7, 3, false)],
+        );
+        assert_eq!(live[0].status, LiveStatus::Winning);
+        assert!(live[0].live_prob > 0.5);
+
+        // With a kickoff, last year's meeting isn't graded as this game
+        let bet = SpreadEvBetRecommendation {
+            commence_time: "2025-09-13T19:30:00Z".parse().ok(),
+            ..bet
+        };
+        let last_year = GameResult {
+            start_date: "2024-09-14T19:30:00.000Z".to_string(),
+            ..create_result(40, 0, 4, true)
+        };
+        let live = grade_live_spread_bets(&[bet], &[last_year, create_result(10, 7, 3, false)]);
+        assert_eq!(live.len(), 1);
+        assert_eq!(live[0].status, LiveStatus::Winning);
+        assert!(!live[0].completed);
+    }
+}
@@ -1,4 +1,18 @@
+pub mod accuracy;
 pub mod arbitrage;
+pub mod backtest;
+pub mod closing;
+pub mod conferences;
 pub mod data;
 pub mod ev_analysis;
 pub mod ev_calculator;
+pub mod export;
+pub mod feed;
+pub mod http;
+pub mod ledger;
+pub mod line_movement;
+pub mod live;
+pub mod monitoring;
+pub mod parlay;
+pub mod staking;
+pub mod team_names;
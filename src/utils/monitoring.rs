@@ -0,0 +1,43 @@
+use crate::error::Result;
+use crate::BettingData;
+use metrics::{counter, gauge};
+
+/// Fetches from each data source, labeled by `source` and `outcome` ("success" or "failure")
+pub const FETCHES_TOTAL: &str = "cfb_betting_fetches_total";
+
+/// Odds API credits left, as reported with the last Odds API response
+pub const ODDS_API_CREDITS_REMAINING: &str = "cfb_betting_odds_api_credits_remaining";
+
+/// Unix time of the last successful betting data refresh
+pub const LAST_REFRESH_TIMESTAMP: &str = "cfb_betting_last_refresh_timestamp_seconds";
+
+/// EV bets and arbitrage opportunities in the current betting data, labeled by `kind`
+pub const OPPORTUNITIES: &str = "cfb_betting_opportunities";
+
+/// Count a fetch from `source` as a success or failure
+/// Without a metrics recorder installed (the CLI) this does nothing.
+pub fn record_fetch<T>(source: &str, result: &Result<T>) {
+    let outcome = if result.is_ok() { "success" } else { "failure" };
+    counter!(FETCHES_TOTAL, "source" => source.to_string(), "outcome" => outcome).increment(1);
+}
+
+/// Record the Odds API credit balance
+pub fn record_odds_api_credits(remaining: u32) {
+    gauge!(ODDS_API_CREDITS_REMAINING).set(remaining as f64);
+}
+
+/// Record the opportunity counts in freshly loaded betting data and when it was loaded
+pub fn record_betting_data(data: &BettingData) {
+    let counts = [
+        ("cfb_moneyline_ev", data.cfb_moneyline_bets.len()),
+        ("cfb_spread_ev", data.cfb_spread_bets.len()),
+        ("cfb_moneyline_arb", data.cfb_moneyline_arbs.len()),
+        ("cfb_spread_arb", data.cfb_spread_arbs.len()),
+        ("cbb_moneyline_arb", data.cbb_moneyline_arbs.len()),
+        ("cbb_spread_arb", data.cbb_spread_arbs.len()),
+    ];
+    for (kind, count) in counts {
+        gauge!(OPPORTUNITIES, "kind" => kind).set(count as f64);
+    }
+    gauge!(LAST_REFRESH_TIMESTAMP).set(chrono::Utc::now().timestamp() as f64);
+}
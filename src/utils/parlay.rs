@@ -0,0 +1,177 @@
+use crate::utils::ev_analysis::{EvBetRecommendation, SpreadEvBetRecommendation};
+use crate::utils::ev_calculator::profit_on_win;
+use serde::{Deserialize, Serialize};
+
+/// Default correlation between a moneyline and a total on the same game
+/// Favorites tend to win in games that go over (they're scoring), and underdogs tend to win
+/// low-scoring games that stay under.
+pub const DEFAULT_MONEYLINE_TOTAL_CORRELATION: f64 = 0.15;
+
+/// One leg of a parlay
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParlayLeg {
+    pub description: String,
+    pub bookmaker: String,
+    pub odds: i32,
+    pub model_prob: f64,
+}
+
+impl From<&EvBetRecommendation> for ParlayLeg {
+    fn from(bet: &EvBetRecommendation) -> Self {
+        Self {
+            description: format!("{} ML", bet.team),
+            bookmaker: bet.bookmaker.clone(),
+            odds: bet.odds,
+            model_prob: bet.model_prob,
+        }
+    }
+}
+
+impl From<&SpreadEvBetRecommendation> for ParlayLeg {
+    fn from(bet: &SpreadEvBetRecommendation) -> Self {
+        Self {
+            description: format!("{} {:+.1}", bet.team, bet.spread_line),
+            bookmaker: bet.bookmaker.clone(),
+            odds: bet.odds,
+            model_prob: bet.model_prob,
+        }
+    }
+}
+
+impl ParlayLeg {
+    /// Payout per $1 including the stake
+    fn decimal_odds(&self) -> f64 {
+        1.0 + profit_on_win(self.odds, 1.0)
+    }
+}
+
+/// A two-leg parlay priced with correlated leg outcomes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParlayRecommendation {
+    pub legs: [ParlayLeg; 2],
+    pub correlation: f64,
+    /// Probability both legs win, accounting for correlation
+    pub joint_prob: f64,
+    /// Probability both legs win if they were independent
+    pub independent_prob: f64,
+    /// Payout per $1 including the stake, assuming the book multiplies the leg prices
+    pub decimal_odds: f64,
+    pub expected_value: f64,
+}
+
+impl ParlayRecommendation {
+    /// Format the parlay as a readable string
+    pub fn format(&self) -> String {
+        format!(
+            "{} ({:+}) + {} ({:+}) | Parlay: {:.2}x | Joint prob: {:.1}% (independent {:.1}%, corr {:+.2}) | EV: {:+.2}%",
+            self.legs[0].description,
+            self.legs[0].odds,
+            self.legs[1].description,
+            self.legs[1].odds,
+            self.decimal_odds,
+            self.joint_prob * 100.0,
+            self.independent_prob * 100.0,
+            self.correlation,
+            self.expected_value * 100.0
+        )
+    }
+}
+
+/// Default correlation for a moneyline leg paired with a total on the same game
+/// Positive for favorite + over and underdog + under, negative for the other pairings.
+pub fn moneyline_total_correlation(team_is_favorite: bool, over: bool) -> f64 {
+    if team_is_favorite == over {
+        DEFAULT_MONEYLINE_TOTAL_CORRELATION
+    } else {
+        -DEFAULT_MONEYLINE_TOTAL_CORRELATION
+    }
+}
+
+/// Probability that two correlated win/lose outcomes both win
+/// Uses the correlation of the two Bernoulli outcomes, clamped to the range of joint
+/// probabilities that are actually possible for the given leg probabilities.
+pub fn correlated_joint_probability(prob_a: f64, prob_b: f64, correlation: f64) -> f64 {
+    let covariance = correlation * (prob_a * (1.0 - prob_a) * prob_b * (1.0 - prob_b)).sqrt();
+    let lower = (prob_a + prob_b - 1.0).max(0.0);
+    let upper = prob_a.min(prob_b);
+
+    (prob_a * prob_b + covariance).clamp(lower, upper)
+}
+
+/// Estimate the EV of a two-leg parlay whose legs are correlated (e.g. same-game parlays)
+/// `correlation` is between -1 and 1; 0 prices the legs as independent.
+pub fn evaluate_parlay(
+    leg_a: impl Into<ParlayLeg>,
+    leg_b: impl Into<ParlayLeg>,
+    correlation: f64,
+) -> ParlayRecommendation {
+    let leg_a = leg_a.into();
+    let leg_b = leg_b.into();
+
+    let joint_prob = correlated_joint_probability(leg_a.model_prob, leg_b.model_prob, correlation);
+    let decimal_odds = leg_a.decimal_odds() * leg_b.decimal_odds();
+
+    ParlayRecommendation {
+        independent_prob: leg_a.model_prob * leg_b.model_prob,
+        legs: [leg_a, leg_b],
+        correlation,
+        joint_prob,
+        decimal_odds,
+        expected_value: joint_prob * decimal_odds - 1.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_leg(description: &str, odds: i32, model_prob: f64) -> ParlayLeg {
+        ParlayLeg {
+            description: description.to_string(),
+            bookmaker: "FanDuel".to_string(),
+            odds,
+            model_prob,
+        }
+    }
+
+    #[test]
+    fn test_correlated_joint_probability() {
+        // No correlation is the naive product
+        assert!((correlated_joint_probability(0.6, 0.5, 0.0) - 0.3).abs() < 1e-9);
+
+        // Positive correlation raises the joint probability, negative lowers it
+        assert!(correlated_joint_probability(0.6, 0.5, 0.2) > 0.3);
+        assert!(correlated_joint_probability(0.6, 0.5, -0.2) < 0.3);
+
+        // Perfect correlation can't exceed the less likely leg
+        assert!((correlated_joint_probability(0.6, 0.5, 1.0) - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_evaluate_parlay() {
+        let favorite = create_leg("Iowa Hawkeyes ML", -150, 0.62);
+        let over = create_leg("Over 44.5", -110, 0.52);
+        let correlation = moneyline_total_correlation(true, true);
+
+        let parlay = evaluate_parlay(favorite.clone(), over.clone(), correlation);
+        let independent = evaluate_parlay(favorite, over, 0.0);
+
+        assert!(parlay.joint_prob > parlay.independent_prob);
+        assert!((independent.joint_prob - independent.independent_prob).abs() < 1e-12);
+        assert!(parlay.expected_value > independent.expected_value);
+
+        // (1 + 100/150) * (1 + 100/110)
+        assert!((parlay.decimal_odds - (5.0 / 3.0) * (21.0 / 11.0)).abs() < 1e-9);
+        assert!(
+            (parlay.expected_value - (parlay.joint_prob * parlay.decimal_odds - 1.0)).abs() < 1e-12
+        );
+    }
+
+    #[test]
+    fn test_moneyline_total_correlation() {
+        assert!(moneyline_total_correlation(true, true) > 0.0);
+        assert!(moneyline_total_correlation(false, false) > 0.0);
+        assert!(moneyline_total_correlation(true, false) < 0.0);
+        assert!(moneyline_total_correlation(false, true) < 0.0);
+    }
+}
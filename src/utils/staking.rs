@@ -0,0 +1,134 @@
+use crate::utils::ev_analysis::{
+    extract_school_name, EvBetRecommendation, SpreadEvBetRecommendation,
+};
+use crate::utils::ev_calculator::kelly_fraction;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Share of the full Kelly stake to bet; full Kelly is too aggressive given model error
+pub const DEFAULT_KELLY_MULTIPLIER: f64 = 0.25;
+
+/// Most of the bankroll to have riding on any one game, across all bets on it
+pub const DEFAULT_MAX_GAME_EXPOSURE: f64 = 0.05;
+
+/// How recommended bets are sized against a bankroll
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct KellySizing {
+    pub bankroll: f64,
+    /// Fraction of the full Kelly stake to bet, e.g. 0.25 for quarter Kelly
+    pub kelly_multiplier: f64,
+    /// Cap on the total stake per game, as a fraction of the bankroll
+    pub max_game_exposure: f64,
+}
+
+impl KellySizing {
+    /// Quarter Kelly with a 5% per-game cap
+    pub fn new(bankroll: f64) -> Self {
+        Self {
+            bankroll,
+            kelly_multiplier: DEFAULT_KELLY_MULTIPLIER,
+            max_game_exposure: DEFAULT_MAX_GAME_EXPOSURE,
+        }
+    }
+
+    /// Stake for a single bet, before the per-game cap
+    pub fn kelly_stake(&self, model_prob: f64, odds: i32) -> f64 {
+        self.bankroll * self.kelly_multiplier * kelly_fraction(model_prob, odds)
+    }
+}
+
+/// Set each bet's `stake` from Kelly sizing, then cap the total stake on each game
+/// Moneyline and spread bets on the same game (matched by school keys) share one cap; when
+/// a game's bets add up to more than `max_game_exposure` of the bankroll, they are scaled
+/// down proportionally so their total equals the cap.
+pub fn size_stakes(
+    moneyline_bets: &mut [EvBetRecommendation],
+    spread_bets: &mut [SpreadEvBetRecommendation],
+    sizing: &KellySizing,
+) {
+    let game_key = |home_team: &str, away_team: &str| {
+        (
+            extract_school_name(home_team),
+            extract_school_name(away_team),
+        )
+    };
+
+    let mut stakes: Vec<((String, String), &mut Option<f64>)> = moneyline_bets
+        .iter_mut()
+        .map(|bet| {
+            bet.stake = Some(sizing.kelly_stake(bet.model_prob, bet.effective_odds()));
+            (game_key(&bet.home_team, &bet.away_team), &mut bet.stake)
+        })
+        .chain(spread_bets.iter_mut().map(|bet| {
+            bet.stake = Some(sizing.kelly_stake(bet.model_prob, bet.odds));
+            (game_key(&bet.home_team, &bet.away_team), &mut bet.stake)
+        }))
+        .collect();
+
+    let mut game_totals: HashMap<(String, String), f64> = HashMap::new();
+    for (key, stake) in &stakes {
+        *game_totals.entry(key.clone()).or_default() += stake.unwrap_or(0.0);
+    }
+
+    let max_stake = sizing.bankroll * sizing.max_game_exposure;
+    for (key, stake) in &mut stakes {
+        let total = game_totals[key];
+        if total > max_stake {
+            **stake = stake.map(|stake| stake * max_stake / total);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_size_stakes_caps_exposure_per_game() {
+        let moneyline = |team: &str, home: &str, away: &str| -> EvBetRecommendation {
+            serde_json::from_value(json!({
+                "home_team": home,
+                "away_team": away,
+                "team": team,
+                "bookmaker": "FanDuel",
+                "odds": 100,
+                "model_prob": 0.6,
+                "implied_prob": 0.5,
+                "expected_value": 0.2,
+                "edge": 0.1,
+            }))
+            .unwrap()
+        };
+        let mut moneyline_bets = vec![
+            moneyline("Iowa Hawkeyes", "Iowa Hawkeyes", "Nebraska Cornhuskers"),
+            moneyline("Purdue", "Purdue", "Indiana"),
+        ];
+        let mut spread_bets: Vec<SpreadEvBetRecommendation> = vec![serde_json::from_value(json!({
+            "home_team": "Iowa",
+            "away_team": "Nebraska",
+            "team": "Iowa",
+            "spread_line": -3.5,
+            "bookmaker": "DraftKings",
+            "odds": 100,
+            "model_spread": 7.0,
+            "model_prob": 0.6,
+            "implied_prob": 0.5,
+            "expected_value": 0.2,
+            "edge": 0.1,
+        }))
+        .unwrap()];
+
+        // Full Kelly at even money with a 60% edge is 20% of the bankroll; quarter Kelly is 5%
+        let sizing = KellySizing::new(1000.0);
+        assert!((sizing.kelly_stake(0.6, 100) - 50.0).abs() < 1e-9);
+
+        size_stakes(&mut moneyline_bets, &mut spread_bets, &sizing);
+
+        // Two $50 bets on Iowa-Nebraska are scaled to $25 each to fit the $50 cap
+        assert!((moneyline_bets[0].stake.unwrap() - 25.0).abs() < 1e-9);
+        assert!((spread_bets[0].stake.unwrap() - 25.0).abs() < 1e-9);
+        // The only bet on Purdue-Indiana is already within the cap
+        assert!((moneyline_bets[1].stake.unwrap() - 50.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,250 @@
+use crate::models::Game;
+use crate::utils::conferences::conference_for_team;
+use crate::utils::ev_analysis::extract_school_name;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Logo and color table, embedded at build time
+/// Covers the power-conference programs plus a few others; anything missing just shows
+/// its name.
+const TEAM_METADATA_CSV: &str = include_str!("../../data/team_metadata.csv");
+
+/// How many calendar days apart two sources can date the same game (time zones and
+/// late-night kickoffs put some games on different days)
+const MATCH_WINDOW_DAYS: i64 = 1;
+
+/// ESPN's CDN path for team logos, by ESPN team ID
+const LOGO_URL_BASE: &str = "https://a.espncdn.com/i/teamlogos/ncaa/500";
+
+/// Display details for a team in the web UI
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TeamMeta {
+    pub school: String,
+    pub logo_url: String,
+    /// Hex color, e.g. "#BB0000"
+    pub primary_color: String,
+    pub conference: Option<&'static str>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TeamMetadataRecord {
+    school: String,
+    espn_id: u32,
+    primary_color: String,
+}
+
+/// The embedded table, keyed by school key
+fn metadata_table() -> &'static HashMap<String, TeamMeta> {
+    static TABLE: OnceLock<HashMap<String, TeamMeta>> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        csv::Reader::from_reader(TEAM_METADATA_CSV.as_bytes())
+            .deserialize::<TeamMetadataRecord>()
+            .map(|record| record.expect("embedded team metadata is valid CSV"))
+            .map(|record| {
+                let meta = TeamMeta {
+                    logo_url: format!("{}/{}.png", LOGO_URL_BASE, record.espn_id),
+                    primary_color: record.primary_color,
+                    conference: conference_for_team(&record.school),
+                    school: record.school,
+                };
+                (extract_school_name(&meta.school), meta)
+            })
+            .collect()
+    })
+}
+
+/// Logo, color and conference for a team, or `None` if it isn't in the table
+/// Accepts any name `extract_school_name` understands, e.g. "Ohio State Buckeyes" or
+/// "Ohio State".
+pub fn team_metadata(canonical: &str) -> Option<TeamMeta> {
+    metadata_table()
+        .get(&extract_school_name(canonical))
+        .cloned()
+}
+
+/// A game found by [`GameMatcher::find`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameMatch {
+    /// Position of the matched game in the list the matcher was built from
+    pub index: usize,
+    /// Whether the two sources list the home and away teams the other way around
+    pub swapped: bool,
+}
+
+#[derive(Debug, Clone)]
+struct MatchCandidate {
+    index: usize,
+    start: DateTime<Utc>,
+    swapped: bool,
+}
+
+/// Matches games across sources (the Odds API, Kalshi, CFBD results) that each use their
+/// own game ids
+/// Games match on canonical school names in either home/away order and kickoff dates at
+/// most a day apart; when several fit, the closest kickoff wins.
+#[derive(Debug, Clone, Default)]
+pub struct GameMatcher {
+    games: HashMap<(String, String), Vec<MatchCandidate>>,
+    len: usize,
+}
+
+impl GameMatcher {
+    /// Index games from one source, given as (home team, away team, kickoff)
+    pub fn new<'a>(games: impl IntoIterator<Item = (&'a str, &'a str, DateTime<Utc>)>) -> Self {
+        let mut matcher = Self::default();
+        for (home_team, away_team, start) in games {
+            matcher.push(home_team, away_team, start);
+        }
+        matcher
+    }
+
+    /// Index one more game; its index is the number of games indexed before it
+    pub fn push(&mut self, home_team: &str, away_team: &str, start: DateTime<Utc>) {
+        let index = self.len;
+        let home_key = extract_school_name(home_team);
+        let away_key = extract_school_name(away_team);
+        for (key, swapped) in [
+            ((home_key.clone(), away_key.clone()), false),
+            ((away_key, home_key), true),
+        ] {
+            self.games.entry(key).or_default().push(MatchCandidate {
+                index,
+                start,
+                swapped,
+            });
+        }
+        self.len += 1;
+    }
+
+    /// Index Odds API or Kalshi games
+    pub fn from_games(games: &[Game]) -> Self {
+        Self::new(games.iter().map(|game| {
+            (
+                game.home_team.as_str(),
+                game.away_team.as_str(),
+                game.commence_time,
+            )
+        }))
+    }
+
+    /// The indexed game between these teams closest to `start`, if one is within a day
+    pub fn find(
+        &self,
+        home_team: &str,
+        away_team: &str,
+        start: DateTime<Utc>,
+    ) -> Option<GameMatch> {
+        let key = (
+            extract_school_name(home_team),
+            extract_school_name(away_team),
+        );
+        self.games
+            .get(&key)?
+            .iter()
+            .filter(|candidate| {
+                (candidate.start.date_naive() - start.date_naive())
+                    .num_days()
+                    .abs()
+                    <= MATCH_WINDOW_DAYS
+            })
+            .min_by_key(|candidate| (candidate.start - start).abs())
+            .map(|candidate| GameMatch {
+                index: candidate.index,
+                swapped: candidate.swapped,
+            })
+    }
+
+    /// The indexed game between these teams for a listing with no kickoff time, as long as
+    /// there's only one to choose from
+    pub fn find_only(&self, home_team: &str, away_team: &str) -> Option<GameMatch> {
+        let key = (
+            extract_school_name(home_team),
+            extract_school_name(away_team),
+        );
+        match self.games.get(&key)?.as_slice() {
+            [candidate] => Some(GameMatch {
+                index: candidate.index,
+                swapped: candidate.swapped,
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_game_matcher_tolerates_swaps_and_date_drift() {
+        let kickoff =
+            |day: u32, hour: u32| Utc.with_ymd_and_hms(2025, 11, day, hour, 0, 0).unwrap();
+        // Odds API listing; Iowa and Nebraska meet twice, a week apart
+        let matcher = GameMatcher::new([
+            ("Iowa Hawkeyes", "Nebraska Cornhuskers", kickoff(22, 20)),
+            (
+                "Ohio State Buckeyes",
+                "Michigan Wolverines",
+                kickoff(29, 17),
+            ),
+            ("Nebraska Cornhuskers", "Iowa Hawkeyes", kickoff(29, 1)),
+        ]);
+
+        // CFBD lists the late kickoff on the previous (US) day
+        assert_eq!(
+            matcher.find("Iowa", "Nebraska", kickoff(21, 23)),
+            Some(GameMatch {
+                index: 0,
+                swapped: false
+            })
+        );
+        // Home and away the other way around, off by a day
+        assert_eq!(
+            matcher.find("Michigan", "Ohio State", kickoff(28, 17)),
+            Some(GameMatch {
+                index: 1,
+                swapped: true
+            })
+        );
+        // Both Iowa-Nebraska games fit the teams; the kickoff picks the right one
+        assert_eq!(
+            matcher
+                .find("Iowa", "Nebraska", kickoff(28, 19))
+                .map(|found| found.index),
+            Some(2)
+        );
+        assert_eq!(
+            matcher.find("Ohio State", "Michigan", kickoff(26, 17)),
+            None
+        );
+        assert_eq!(matcher.find("Purdue", "Indiana", kickoff(29, 17)), None);
+    }
+
+    #[test]
+    fn test_team_metadata() {
+        let ohio_state = team_metadata("Ohio State Buckeyes").unwrap();
+        assert_eq!(ohio_state.school, "Ohio State");
+        assert_eq!(
+            ohio_state.logo_url,
+            "https://a.espncdn.com/i/teamlogos/ncaa/500/194.png"
+        );
+        assert_eq!(ohio_state.conference, Some("Big Ten"));
+
+        // Full Odds API names and short names find the same entry
+        assert_eq!(team_metadata("Miami Hurricanes"), team_metadata("Miami"));
+        assert_eq!(team_metadata("Miami (OH) RedHawks"), None);
+        assert_eq!(team_metadata("Montana Grizzlies"), None);
+    }
+
+    #[test]
+    fn test_team_metadata_table_has_unique_keys() {
+        let rows = TEAM_METADATA_CSV.lines().skip(1).count();
+        assert_eq!(metadata_table().len(), rows);
+        assert!(metadata_table()
+            .values()
+            .all(|meta| meta.primary_color.starts_with('#') && meta.conference.is_some()));
+    }
+}